@@ -0,0 +1,194 @@
+//! Chain
+//!
+//! A combinator for multi-step calculations, where the result of one `Calculate` is fed into the next.
+use crate::domain::errors::CalcError;
+use crate::domain::traits::{Calculate, Rounding};
+use crate::math::float::Float;
+use alloc::vec::Vec;
+
+/// The start of a chain of calculations.
+///
+/// Each step is given the unrounded result of the step before it, the same value a `Constructor` choice resolves to, and returns the next calculation. The chain implements `Calculate` with the result, `precision()` and `terms()` of its last step.
+///
+/// # Example
+///
+/// ```
+/// use billios::domain::chain::Chain;
+/// use billios::domain::traits::Calculate;
+/// use billios::field_test::{DryDensity, SandUsed, WetDensity};
+/// use billios::field_test::types::MoistureContentChoice;
+///
+/// let sand_used = SandUsed::new(14.65, 8.75, None);
+///
+/// let dry_density = Chain::new(sand_used)
+///   .then(|su| WetDensity::new(4.65, su.into(), None))
+///   .then(|wd| DryDensity::new(wd.into(), MoistureContentChoice::Value(0.1)));
+///
+/// assert_eq!(Ok(161.), dry_density.calculate());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Chain<A> {
+  step: A,
+}
+
+impl<A> Chain<A> {
+  /// Start a new chain from the calculation `first`.
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn new(first: A) -> Self {
+    Self { step: first }
+  }
+
+  /// Add a step to the chain, which builds the next calculation from the result of this one.
+  pub fn then<T, B, F>(self, next: F) -> Chain<Then<A, F>>
+  where
+    T: Float,
+    A: Calculate<T>,
+    B: Calculate<T>,
+    F: Fn(T) -> B,
+  {
+    Chain::new(Then { first: self.step, next })
+  }
+
+  /// Getter for the last step of the chain.
+  pub fn get_step(&self) -> &A {
+    &self.step
+  }
+}
+
+impl<T: Float, A: Calculate<T>> Rounding<T> for Chain<A> {}
+
+impl<T: Float, A: Calculate<T>> Calculate<T> for Chain<A> {
+  fn calculate_raw(&self) -> Result<T, CalcError> {
+    self.step.calculate_raw()
+  }
+
+  fn precision(&self) -> i32 {
+    self.step.precision()
+  }
+
+  fn round_result(&self, raw: T, n: i32) -> Result<T, CalcError> {
+    self.step.round_result(raw, n)
+  }
+
+  fn unit_label(&self) -> &'static str {
+    self.step.unit_label()
+  }
+
+  fn calculate(&self) -> Result<T, CalcError> {
+    self.step.calculate()
+  }
+
+  fn terms(&self) -> Result<Vec<(&'static str, T)>, CalcError> {
+    self.step.terms()
+  }
+}
+
+/// A step of a `Chain`, built by `Chain::then()`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Then<A, F> {
+  first: A,
+  next: F,
+}
+
+impl<A, F> Then<A, F> {
+  /// Build the calculation of this step, from the unrounded result of the step before it.
+  fn step<T, B>(&self) -> Result<B, CalcError>
+  where
+    T: Float,
+    A: Calculate<T>,
+    F: Fn(T) -> B,
+  {
+    Ok((self.next)(self.first.calculate_raw()?))
+  }
+}
+
+impl<T, A, B, F> Rounding<T> for Then<A, F>
+where
+  T: Float,
+  A: Calculate<T>,
+  B: Calculate<T>,
+  F: Fn(T) -> B,
+{
+}
+
+impl<T, A, B, F> Calculate<T> for Then<A, F>
+where
+  T: Float,
+  A: Calculate<T>,
+  B: Calculate<T>,
+  F: Fn(T) -> B,
+{
+  fn calculate_raw(&self) -> Result<T, CalcError> {
+    self.step()?.calculate_raw()
+  }
+
+  /// The precision of the last step. If an earlier step fails there is no last step to ask, so the precision of the step before it is used.
+  fn precision(&self) -> i32 {
+    match self.step() {
+      Ok(step) => step.precision(),
+      Err(_) => self.first.precision(),
+    }
+  }
+
+  fn round_result(&self, raw: T, n: i32) -> Result<T, CalcError> {
+    self.step()?.round_result(raw, n)
+  }
+
+  /// The unit of the last step, or of the step before it if an earlier step fails, the same as `precision()`.
+  fn unit_label(&self) -> &'static str {
+    match self.step() {
+      Ok(step) => step.unit_label(),
+      Err(_) => self.first.unit_label(),
+    }
+  }
+
+  fn calculate(&self) -> Result<T, CalcError> {
+    self.step()?.calculate()
+  }
+
+  fn terms(&self) -> Result<Vec<(&'static str, T)>, CalcError> {
+    self.step()?.terms()
+  }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::field_test::{Compaction, DryDensity, SandUsed, WetDensity};
+  use crate::field_test::types::{DryDensityChoice, MoistureContentChoice, SandUsedChoice, WetDensityChoice};
+
+  #[test]
+  fn test_chain() {
+    let sand_used = SandUsed::new(14.65, 8.75, None);
+
+    let chain = Chain::new(sand_used)
+      .then(|su| WetDensity::new(4.65, su.into(), None))
+      .then(|wd| DryDensity::new(wd.into(), MoistureContentChoice::Value(0.1)))
+      .then(|dd| Compaction::new(dd.into(), 135.6));
+
+    let wet_density = WetDensity::new(4.65, SandUsedChoice::Constructor(sand_used), None);
+    let dry_density = DryDensity::new(WetDensityChoice::Constructor(wet_density), MoistureContentChoice::Value(0.1));
+    let compaction = Compaction::new(DryDensityChoice::Constructor(dry_density), 135.6);
+
+    assert_eq!(compaction.calculate(), chain.calculate());
+    assert_eq!(compaction.calculate_raw(), chain.calculate_raw());
+    assert_eq!(compaction.precision(), chain.precision());
+    assert_eq!("%", chain.unit_label());
+    assert_eq!(compaction.calculate_detailed(), chain.calculate_detailed());
+    assert_eq!(Ok(2.31), Chain::new(sand_used).calculate());
+  }
+
+  #[test]
+  fn test_chain_error() {
+    let chain = Chain::new(SandUsed::new(14.65, 8.75, Some(5.9)))
+      .then(|su| WetDensity::new(4.65, su.into(), None))
+      .then(|wd| DryDensity::new(wd.into(), MoistureContentChoice::Value(0.1)));
+
+    assert_eq!(Err(CalcError::DivisionByZero), chain.calculate());
+    assert_eq!(Err(CalcError::DivisionByZero), chain.terms());
+    assert_eq!(4, chain.precision());
+    assert_eq!("pcf", chain.unit_label());
+  }
+}
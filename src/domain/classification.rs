@@ -0,0 +1,517 @@
+//! Classification
+//!
+//! Soil classification using the Unified Soil Classification System (USCS), following ASTM D2487.
+//!
+//! # Example
+//!
+//! ```
+//! use billios::domain::classification::{classify, CoarseGrading, UscsClass};
+//!
+//! // A fine grained soil only needs the Atterberg limits.
+//! assert_eq!(Some(UscsClass::Cl), classify(62., 45., 23., None));
+//!
+//! // A coarse grained soil also needs its grading.
+//! let grading = CoarseGrading::new(70., 22., Some(8.), Some(1.5));
+//! assert_eq!(Some(UscsClass::GwGc), classify(8., 45., 23., Some(grading)));
+//! assert_eq!("GW-GC", UscsClass::GwGc.symbol());
+//! ```
+use core::fmt;
+
+/// The minimum percent passing the #200 sieve for a soil to be fine grained.
+pub const FINE_GRAINED_THRESHOLD: f64 = 50.;
+
+/// The percent passing the #200 sieve below which a coarse grained soil is "clean".
+pub const CLEAN_FINES_THRESHOLD: f64 = 5.;
+
+/// The percent passing the #200 sieve above which a coarse grained soil is classified by its fines alone.
+pub const DIRTY_FINES_THRESHOLD: f64 = 12.;
+
+/// The percent finer than 0.02 mm below which a soil is not frost susceptible, after Casagrande (1932), as adopted by the U.S. Army Corps of Engineers (TM 5-818-2).
+pub const FROST_NON_SUSCEPTIBLE_PERCENT: f64 = 3.;
+
+/// The percent finer than 0.02 mm above which even a uniformly graded soil is frost susceptible, after Casagrande (1932).
+pub const FROST_SUSCEPTIBLE_PERCENT: f64 = 10.;
+
+/// The liquid limit at, or above, which a fine grained soil is of high plasticity.
+pub const HIGH_PLASTICITY_LIQUID_LIMIT: f64 = 50.;
+
+/// The plasticity index at, or above, which the swell potential is medium, after Seed, Woodward and Lundgren (1962).
+pub const SWELL_MEDIUM_PLASTICITY_INDEX: f64 = 10.;
+
+/// The plasticity index at, or above, which the swell potential is high.
+pub const SWELL_HIGH_PLASTICITY_INDEX: f64 = 20.;
+
+/// The plasticity index above which the swell potential is very high.
+pub const SWELL_VERY_HIGH_PLASTICITY_INDEX: f64 = 35.;
+
+/// A Unified Soil Classification System group symbol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UscsClass {
+  /// `GW` - Well graded gravel.
+  Gw,
+  /// `GP` - Poorly graded gravel.
+  Gp,
+  /// `GM` - Silty gravel.
+  Gm,
+  /// `GC` - Clayey gravel.
+  Gc,
+  /// `GC-GM` - Silty, clayey gravel.
+  GcGm,
+  /// `GW-GM` - Well graded gravel with silt.
+  GwGm,
+  /// `GW-GC` - Well graded gravel with clay.
+  GwGc,
+  /// `GP-GM` - Poorly graded gravel with silt.
+  GpGm,
+  /// `GP-GC` - Poorly graded gravel with clay.
+  GpGc,
+  /// `SW` - Well graded sand.
+  Sw,
+  /// `SP` - Poorly graded sand.
+  Sp,
+  /// `SM` - Silty sand.
+  Sm,
+  /// `SC` - Clayey sand.
+  Sc,
+  /// `SC-SM` - Silty, clayey sand.
+  ScSm,
+  /// `SW-SM` - Well graded sand with silt.
+  SwSm,
+  /// `SW-SC` - Well graded sand with clay.
+  SwSc,
+  /// `SP-SM` - Poorly graded sand with silt.
+  SpSm,
+  /// `SP-SC` - Poorly graded sand with clay.
+  SpSc,
+  /// `CL` - Lean clay.
+  Cl,
+  /// `CL-ML` - Silty clay.
+  ClMl,
+  /// `ML` - Silt.
+  Ml,
+  /// `CH` - Fat clay.
+  Ch,
+  /// `MH` - Elastic silt.
+  Mh,
+}
+
+impl UscsClass {
+  /// The group symbol, for example `"CL-ML"`.
+  pub fn symbol(&self) -> &'static str {
+    match self {
+      UscsClass::Gw => "GW",
+      UscsClass::Gp => "GP",
+      UscsClass::Gm => "GM",
+      UscsClass::Gc => "GC",
+      UscsClass::GcGm => "GC-GM",
+      UscsClass::GwGm => "GW-GM",
+      UscsClass::GwGc => "GW-GC",
+      UscsClass::GpGm => "GP-GM",
+      UscsClass::GpGc => "GP-GC",
+      UscsClass::Sw => "SW",
+      UscsClass::Sp => "SP",
+      UscsClass::Sm => "SM",
+      UscsClass::Sc => "SC",
+      UscsClass::ScSm => "SC-SM",
+      UscsClass::SwSm => "SW-SM",
+      UscsClass::SwSc => "SW-SC",
+      UscsClass::SpSm => "SP-SM",
+      UscsClass::SpSc => "SP-SC",
+      UscsClass::Cl => "CL",
+      UscsClass::ClMl => "CL-ML",
+      UscsClass::Ml => "ML",
+      UscsClass::Ch => "CH",
+      UscsClass::Mh => "MH",
+    }
+  }
+
+  /// Returns `true` for a fine grained group (`CL`, `CL-ML`, `ML`, `CH`, `MH`).
+  pub fn is_fine_grained(&self) -> bool {
+    matches!(self, UscsClass::Cl | UscsClass::ClMl | UscsClass::Ml | UscsClass::Ch | UscsClass::Mh)
+  }
+}
+
+impl fmt::Display for UscsClass {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}", self.symbol())
+  }
+}
+
+/// The grading of the coarse (retained on the #200 sieve) fraction of a soil.
+///
+/// Percents are of the total sample. The coefficients are only needed when 12% or less passes the #200 sieve.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CoarseGrading {
+  percent_gravel: f64,
+  percent_sand: f64,
+  coefficient_of_uniformity: Option<f64>,
+  coefficient_of_curvature: Option<f64>,
+}
+
+impl CoarseGrading {
+  /// Create a new instance of `CoarseGrading`.
+  ///
+  /// # Arguments
+  ///
+  /// - `percent_gravel` - Percent of the sample retained on the #4 sieve.
+  ///
+  /// - `percent_sand` - Percent of the sample passing the #4 sieve and retained on the #200 sieve.
+  ///
+  /// - `coefficient_of_uniformity` - The coefficient of uniformity, `Cu`, if known.
+  ///
+  /// - `coefficient_of_curvature` - The coefficient of curvature, `Cc`, if known.
+  ///
+  pub fn new(percent_gravel: f64, percent_sand: f64, coefficient_of_uniformity: Option<f64>, coefficient_of_curvature: Option<f64>) -> Self {
+    Self { percent_gravel, percent_sand, coefficient_of_uniformity, coefficient_of_curvature }
+  }
+
+  /// Getter for `percent_gravel`.
+  pub fn get_percent_gravel(&self) -> f64 {
+    self.percent_gravel
+  }
+
+  /// Getter for `percent_sand`.
+  pub fn get_percent_sand(&self) -> f64 {
+    self.percent_sand
+  }
+
+  /// Getter for `coefficient_of_uniformity`.
+  pub fn get_coefficient_of_uniformity(&self) -> Option<f64> {
+    self.coefficient_of_uniformity
+  }
+
+  /// Getter for `coefficient_of_curvature`.
+  pub fn get_coefficient_of_curvature(&self) -> Option<f64> {
+    self.coefficient_of_curvature
+  }
+
+  /// Returns `true` when more of the coarse fraction is gravel than sand.
+  fn is_gravel(&self) -> bool {
+    self.percent_gravel > self.percent_sand
+  }
+
+  /// Returns `Some(true)` when the coarse fraction is well graded, or `None` if a coefficient is missing.
+  ///
+  /// Gravels need `Cu >= 4` and sands need `Cu >= 6`, both need `1 <= Cc <= 3`.
+  fn is_well_graded(&self) -> Option<bool> {
+    let cu = self.coefficient_of_uniformity?;
+    let cc = self.coefficient_of_curvature?;
+
+    let cu_min = if self.is_gravel() { 4. } else { 6. };
+
+    Some(cu >= cu_min && (1. ..=3.).contains(&cc))
+  }
+}
+
+/// The plasticity index of the A-line, `PI = 0.73 * (LL - 20)`, for a liquid limit.
+///
+/// Soils plotting on or above the A-line are clays, those below are silts.
+pub fn a_line(liquid_limit: f64) -> f64 {
+  0.73 * (liquid_limit - 20.)
+}
+
+/// Classify the fines of a soil from its Atterberg limits.
+///
+/// - A liquid limit below 50 is low plasticity: `CL` above the A-line with `PI > 7`, `CL-ML` above the A-line with `4 <= PI <= 7`, otherwise `ML`.
+/// - A liquid limit of 50 or more is high plasticity: `CH` on or above the A-line, otherwise `MH`.
+///
+/// A soil that plots exactly on the A-line is treated as a clay.
+pub fn classify_fines(liquid_limit: f64, plasticity_index: f64) -> UscsClass {
+  let above_a_line = plasticity_index >= a_line(liquid_limit);
+
+  if liquid_limit < HIGH_PLASTICITY_LIQUID_LIMIT {
+    if above_a_line && plasticity_index > 7. {
+      UscsClass::Cl
+    } else if above_a_line && plasticity_index >= 4. {
+      UscsClass::ClMl
+    } else {
+      UscsClass::Ml
+    }
+  } else if above_a_line {
+    UscsClass::Ch
+  } else {
+    UscsClass::Mh
+  }
+}
+
+/// Classify a soil using the Unified Soil Classification System.
+///
+/// # Arguments
+///
+/// - `percent_fines` - Percent of the sample passing the #200 sieve.
+///
+/// - `liquid_limit` - The liquid limit of the fines, as a percent.
+///
+/// - `plasticity_index` - The plasticity index of the fines, as a percent.
+///
+/// - `coarse` - The `CoarseGrading` of the sample, only needed for a coarse grained soil.
+///
+/// # Boundaries
+///
+/// - 50% or more fines is fine grained, classified by `classify_fines()`.
+/// - Less than 5% fines is a clean gravel or sand (`GW`, `GP`, `SW`, `SP`).
+/// - 5% to 12% fines, inclusive, is given a dual symbol (for example `SP-SM`). Fines that classify as `CL-ML` are treated as clay (`GW-GC`).
+/// - More than 12% fines is classified by the fines (`GM`, `GC`, `SM`, `SC`), or `GC-GM` and `SC-SM` for `CL-ML` fines.
+///
+/// Returns `None` for a coarse grained soil when `coarse` is not provided, or when its coefficients are needed but missing.
+pub fn classify(percent_fines: f64, liquid_limit: f64, plasticity_index: f64, coarse: Option<CoarseGrading>) -> Option<UscsClass> {
+  let fines = classify_fines(liquid_limit, plasticity_index);
+
+  if percent_fines >= FINE_GRAINED_THRESHOLD {
+    return Some(fines);
+  }
+
+  let coarse = coarse?;
+  let gravel = coarse.is_gravel();
+
+  if percent_fines > DIRTY_FINES_THRESHOLD {
+    let class = match (gravel, fines) {
+      (true, UscsClass::ClMl) => UscsClass::GcGm,
+      (true, UscsClass::Cl | UscsClass::Ch) => UscsClass::Gc,
+      (true, _) => UscsClass::Gm,
+      (false, UscsClass::ClMl) => UscsClass::ScSm,
+      (false, UscsClass::Cl | UscsClass::Ch) => UscsClass::Sc,
+      (false, _) => UscsClass::Sm,
+    };
+
+    return Some(class);
+  }
+
+  let well_graded = coarse.is_well_graded()?;
+
+  if percent_fines < CLEAN_FINES_THRESHOLD {
+    let class = match (gravel, well_graded) {
+      (true, true) => UscsClass::Gw,
+      (true, false) => UscsClass::Gp,
+      (false, true) => UscsClass::Sw,
+      (false, false) => UscsClass::Sp,
+    };
+
+    return Some(class);
+  }
+
+  let clayey = matches!(fines, UscsClass::Cl | UscsClass::ClMl | UscsClass::Ch);
+
+  let class = match (gravel, well_graded, clayey) {
+    (true, true, false) => UscsClass::GwGm,
+    (true, true, true) => UscsClass::GwGc,
+    (true, false, false) => UscsClass::GpGm,
+    (true, false, true) => UscsClass::GpGc,
+    (false, true, false) => UscsClass::SwSm,
+    (false, true, true) => UscsClass::SwSc,
+    (false, false, false) => UscsClass::SpSm,
+    (false, false, true) => UscsClass::SpSc,
+  };
+
+  Some(class)
+}
+
+
+/// The frost susceptibility of a soil, from its percent finer than 0.02 mm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FrostClass {
+  /// Less than 3% finer than 0.02 mm.
+  NonSusceptible,
+  /// 3% to 10% finer than 0.02 mm, inclusive, which is susceptible if the soil is well graded, but not if it is uniform.
+  PossiblySusceptible,
+  /// More than 10% finer than 0.02 mm.
+  Susceptible,
+}
+
+/// Classify the frost susceptibility of a soil from its percent finer than 0.02 mm, such as from a `HydrometerAnalysis`.
+///
+/// Uses the Casagrande (1932) criteria, as adopted by the U.S. Army Corps of Engineers (TM 5-818-2): below `FROST_NON_SUSCEPTIBLE_PERCENT` is non-susceptible, and above `FROST_SUSCEPTIBLE_PERCENT` is susceptible.
+///
+/// The percent passing the #200 sieve, 0.075 mm, such as `GradationAnalysis::percent_fines()`, can be used when there is no hydrometer analysis, but it is only an approximation. It includes particles up to 0.075 mm, so it overstates the percent finer than 0.02 mm, and the class is conservative.
+///
+/// # Example
+///
+/// ```
+/// use billios::domain::classification::{frost_susceptibility, FrostClass};
+/// use billios::field_test::GradationAnalysis;
+///
+/// assert_eq!(FrostClass::NonSusceptible, frost_susceptibility(2.5));
+/// assert_eq!(FrostClass::PossiblySusceptible, frost_susceptibility(6.));
+///
+/// // Approximated from the #200 sieve, which can only overstate it.
+/// let gradation = GradationAnalysis::new(vec![(4.75, 45.), (0.425, 120.), (0.075, 100.)], Some(35.));
+///
+/// assert_eq!(Ok(11.7), gradation.percent_fines());
+/// assert_eq!(FrostClass::Susceptible, frost_susceptibility(gradation.percent_fines().unwrap()));
+/// ```
+pub fn frost_susceptibility(percent_finer: f64) -> FrostClass {
+  if percent_finer < FROST_NON_SUSCEPTIBLE_PERCENT {
+    FrostClass::NonSusceptible
+  } else if percent_finer > FROST_SUSCEPTIBLE_PERCENT {
+    FrostClass::Susceptible
+  } else {
+    FrostClass::PossiblySusceptible
+  }
+}
+
+/// The potential of a soil to swell, or heave, when wetted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SwellPotential {
+  /// A plasticity index below 10, or too few fines to swell.
+  Low,
+  /// A plasticity index from 10 to 20.
+  Medium,
+  /// A plasticity index from 20 to 35, inclusive.
+  High,
+  /// A plasticity index above 35.
+  VeryHigh,
+}
+
+/// Screen the swell potential of a soil from its plasticity index, such as the result of `PlasticityIndex`, and the percent passing the #200 sieve.
+///
+/// The plasticity index thresholds are those of Seed, Woodward and Lundgren (1962): `SWELL_MEDIUM_PLASTICITY_INDEX`, `SWELL_HIGH_PLASTICITY_INDEX`, and `SWELL_VERY_HIGH_PLASTICITY_INDEX`. A soil with no more than `DIRTY_FINES_THRESHOLD` fines is coarse grained, with too few fines to swell appreciably, so is `Low` whatever the plasticity of its fines.
+///
+/// This is a screening estimate, not a substitute for a swell test.
+///
+/// # Example
+///
+/// ```
+/// use billios::domain::classification::{swell_potential, SwellPotential};
+/// use billios::field_test::PlasticityIndex;
+///
+/// let plasticity_index = PlasticityIndex::new(62., 24.);
+///
+/// assert_eq!(SwellPotential::VeryHigh, swell_potential(plasticity_index.calculate().unwrap(), 85.));
+/// assert_eq!(SwellPotential::Low, swell_potential(plasticity_index.calculate().unwrap(), 8.));
+/// ```
+pub fn swell_potential(plasticity_index: f64, percent_fines: f64) -> SwellPotential {
+  if percent_fines <= DIRTY_FINES_THRESHOLD || plasticity_index < SWELL_MEDIUM_PLASTICITY_INDEX {
+    SwellPotential::Low
+  } else if plasticity_index < SWELL_HIGH_PLASTICITY_INDEX {
+    SwellPotential::Medium
+  } else if plasticity_index <= SWELL_VERY_HIGH_PLASTICITY_INDEX {
+    SwellPotential::High
+  } else {
+    SwellPotential::VeryHigh
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_a_line() {
+    assert_eq!(0., a_line(20.));
+    assert!((a_line(50.) - 21.9).abs() < 1e-9);
+  }
+
+  #[test]
+  fn test_classify_fines() {
+    assert_eq!(UscsClass::Cl, classify_fines(45., 23.));
+    assert_eq!(UscsClass::Ml, classify_fines(45., 10.));
+    assert_eq!(UscsClass::Ch, classify_fines(65., 40.));
+    assert_eq!(UscsClass::Mh, classify_fines(65., 20.));
+
+    // The hatched CL-ML zone.
+    assert_eq!(UscsClass::ClMl, classify_fines(25., 4.));
+    assert_eq!(UscsClass::ClMl, classify_fines(25., 7.));
+    assert_eq!(UscsClass::Cl, classify_fines(25., 7.5));
+    assert_eq!(UscsClass::Ml, classify_fines(25., 3.9));
+
+    // On the A-line is a clay, just below is a silt.
+    let on_a_line = a_line(40.);
+    assert_eq!(UscsClass::Cl, classify_fines(40., on_a_line));
+    assert_eq!(UscsClass::Ml, classify_fines(40., on_a_line - 0.01));
+
+    // A liquid limit of exactly 50 is high plasticity.
+    assert_eq!(UscsClass::Ch, classify_fines(50., 30.));
+    assert_eq!(UscsClass::Cl, classify_fines(49.9, 30.));
+  }
+
+  #[test]
+  fn test_classify_fine_grained() {
+    assert_eq!(Some(UscsClass::Cl), classify(50., 45., 23., None));
+    assert_eq!(Some(UscsClass::Mh), classify(85., 65., 20., None));
+  }
+
+  #[test]
+  fn test_classify_coarse_grained() {
+    let well_graded_gravel = CoarseGrading::new(70., 27., Some(8.), Some(1.5));
+    let poorly_graded_gravel = CoarseGrading::new(70., 27., Some(3.), Some(1.5));
+    let well_graded_sand = CoarseGrading::new(20., 77., Some(7.), Some(2.));
+    let poorly_graded_sand = CoarseGrading::new(20., 77., Some(7.), Some(0.8));
+
+    // Clean.
+    assert_eq!(Some(UscsClass::Gw), classify(3., 0., 0., Some(well_graded_gravel)));
+    assert_eq!(Some(UscsClass::Gp), classify(3., 0., 0., Some(poorly_graded_gravel)));
+    assert_eq!(Some(UscsClass::Sw), classify(3., 0., 0., Some(well_graded_sand)));
+    assert_eq!(Some(UscsClass::Sp), classify(4.9, 0., 0., Some(poorly_graded_sand)));
+
+    // A Cu of 4 is well graded for gravel, but not for sand.
+    let cu_four = CoarseGrading::new(70., 27., Some(4.), Some(1.));
+    assert_eq!(Some(UscsClass::Gw), classify(3., 0., 0., Some(cu_four)));
+    let cu_four = CoarseGrading::new(27., 70., Some(4.), Some(1.));
+    assert_eq!(Some(UscsClass::Sp), classify(3., 0., 0., Some(cu_four)));
+
+    // Dual symbols between 5% and 12% fines.
+    assert_eq!(Some(UscsClass::GwGm), classify(5., 30., 2., Some(well_graded_gravel)));
+    assert_eq!(Some(UscsClass::GpGc), classify(12., 45., 23., Some(poorly_graded_gravel)));
+    assert_eq!(Some(UscsClass::SwSc), classify(8., 25., 5., Some(well_graded_sand)));
+    assert_eq!(Some(UscsClass::SpSm), classify(8., 45., 10., Some(poorly_graded_sand)));
+
+    // More than 12% fines.
+    assert_eq!(Some(UscsClass::Gm), classify(20., 45., 10., Some(poorly_graded_gravel)));
+    assert_eq!(Some(UscsClass::Gc), classify(20., 65., 40., Some(poorly_graded_gravel)));
+    assert_eq!(Some(UscsClass::GcGm), classify(20., 25., 5., Some(poorly_graded_gravel)));
+    assert_eq!(Some(UscsClass::Sm), classify(30., 45., 10., Some(well_graded_sand)));
+    assert_eq!(Some(UscsClass::Sc), classify(30., 45., 23., Some(well_graded_sand)));
+    assert_eq!(Some(UscsClass::ScSm), classify(30., 25., 5., Some(well_graded_sand)));
+
+    // Equal gravel and sand is a sand.
+    let equal = CoarseGrading::new(45., 45., None, None);
+    assert_eq!(Some(UscsClass::Sm), classify(20., 45., 10., Some(equal)));
+  }
+
+  #[test]
+  fn test_classify_missing_data() {
+    assert_eq!(None, classify(20., 45., 10., None));
+
+    // The coefficients are only needed for 12% or less fines.
+    let no_coefficients = CoarseGrading::new(70., 27., None, None);
+    assert_eq!(None, classify(3., 0., 0., Some(no_coefficients)));
+    assert_eq!(Some(UscsClass::Gm), classify(13., 45., 10., Some(no_coefficients)));
+  }
+
+  #[test]
+  fn test_frost_susceptibility() {
+    assert_eq!(FrostClass::NonSusceptible, frost_susceptibility(0.));
+    assert_eq!(FrostClass::NonSusceptible, frost_susceptibility(2.9));
+    assert_eq!(FrostClass::PossiblySusceptible, frost_susceptibility(3.));
+    assert_eq!(FrostClass::PossiblySusceptible, frost_susceptibility(10.));
+    assert_eq!(FrostClass::Susceptible, frost_susceptibility(10.1));
+    assert_eq!(FrostClass::Susceptible, frost_susceptibility(60.));
+  }
+
+  #[test]
+  fn test_swell_potential() {
+    assert_eq!(SwellPotential::Low, swell_potential(0., 60.));
+    assert_eq!(SwellPotential::Low, swell_potential(9.9, 60.));
+    assert_eq!(SwellPotential::Medium, swell_potential(10., 60.));
+    assert_eq!(SwellPotential::High, swell_potential(20., 60.));
+    assert_eq!(SwellPotential::High, swell_potential(35., 60.));
+    assert_eq!(SwellPotential::VeryHigh, swell_potential(35.1, 60.));
+
+    // Too few fines to swell, however plastic.
+    assert_eq!(SwellPotential::Low, swell_potential(40., 12.));
+    assert_eq!(SwellPotential::VeryHigh, swell_potential(40., 12.1));
+  }
+
+  #[test]
+  fn test_symbol() {
+    assert_eq!("CL-ML", UscsClass::ClMl.symbol());
+    assert_eq!("SW-SM", UscsClass::SwSm.to_string());
+    assert!(UscsClass::Mh.is_fine_grained());
+    assert!(!UscsClass::GpGc.is_fine_grained());
+  }
+}
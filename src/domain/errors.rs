@@ -0,0 +1,249 @@
+//! Errors
+//!
+use crate::math::float::Float;
+use core::fmt;
+
+/// Errors that can occur while performing a calculation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CalcError {
+  /// The result could not be rounded to the requested number of decimal places.
+  Rounding,
+  /// A formula attempted to divide by zero.
+  DivisionByZero,
+  /// A formula produced a `NaN` or infinite result.
+  NonFinite,
+  /// Not enough data was provided to perform the calculation.
+  InsufficientData,
+  /// A value is outside the range covered by the data provided.
+  OutOfRange,
+  /// A fitted curve has no peak.
+  NotConcave,
+  /// A series of readings did not settle to a constant value, e.g. oven-dry masses that are still changing.
+  NotStabilized,
+}
+
+impl fmt::Display for CalcError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      CalcError::Rounding => write!(f, "error rounding the result"),
+      CalcError::DivisionByZero => write!(f, "division by zero"),
+      CalcError::NonFinite => write!(f, "result is NaN or infinite"),
+      CalcError::InsufficientData => write!(f, "not enough data to perform the calculation"),
+      CalcError::OutOfRange => write!(f, "value is outside the range of the data"),
+      CalcError::NotConcave => write!(f, "fitted curve is not concave"),
+      CalcError::NotStabilized => write!(f, "readings did not stabilize"),
+    }
+  }
+}
+
+impl core::error::Error for CalcError {}
+
+/// The reason a value failed validation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ValidationErrorKind {
+  /// The value is `NaN`.
+  NaN,
+  /// The value is infinite.
+  Infinite,
+  /// The value is negative where a negative value is physically impossible.
+  Negative,
+  /// A required value was not provided.
+  Missing,
+  /// A set of fractions does not sum to one.
+  NotNormalized,
+}
+
+impl fmt::Display for ValidationErrorKind {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      ValidationErrorKind::NaN => write!(f, "is NaN"),
+      ValidationErrorKind::Infinite => write!(f, "is infinite"),
+      ValidationErrorKind::Negative => write!(f, "must not be negative"),
+      ValidationErrorKind::Missing => write!(f, "is required"),
+      ValidationErrorKind::NotNormalized => write!(f, "must sum to 1"),
+    }
+  }
+}
+
+/// An error returned when an input to a calculation is invalid.
+///
+/// # Example
+///
+/// ```
+/// use billios::domain::errors::{ValidationError, ValidationErrorKind};
+/// use billios::field_test::SandUsed;
+///
+/// let error = SandUsed::try_new(14.65, -8.75, None).unwrap_err();
+///
+/// assert_eq!("cone_post_test", error.field);
+/// assert_eq!(ValidationErrorKind::Negative, error.kind);
+/// assert_eq!("`cone_post_test` must not be negative", error.to_string());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ValidationError {
+  /// The name of the field that failed validation.
+  pub field: &'static str,
+  /// The reason the field failed validation.
+  pub kind: ValidationErrorKind,
+}
+
+impl ValidationError {
+  /// Create a new instance of `ValidationError`.
+  pub fn new(field: &'static str, kind: ValidationErrorKind) -> Self {
+    Self { field, kind }
+  }
+
+  /// Check that `value` is finite and not negative.
+  pub(crate) fn check_non_negative<T: Float>(field: &'static str, value: T) -> Result<(), Self> {
+    if value.is_nan() {
+      return Err(Self::new(field, ValidationErrorKind::NaN));
+    }
+
+    if value.is_infinite() {
+      return Err(Self::new(field, ValidationErrorKind::Infinite));
+    }
+
+    if value < T::ZERO {
+      return Err(Self::new(field, ValidationErrorKind::Negative));
+    }
+
+    Ok(())
+  }
+
+  /// Check that `value`, if provided, is finite and not negative.
+  pub(crate) fn check_optional_non_negative<T: Float>(field: &'static str, value: Option<T>) -> Result<(), Self> {
+    match value {
+      Some(v) => Self::check_non_negative(field, v),
+      None => Ok(()),
+    }
+  }
+}
+
+impl fmt::Display for ValidationError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "`{}` {}", self.field, self.kind)
+  }
+}
+
+impl core::error::Error for ValidationError {}
+
+/// An error returned when a string cannot be parsed into a `*Choice` enum.
+///
+/// # Example
+///
+/// ```
+/// use billios::domain::errors::ParseChoiceError;
+/// use billios::field_test::types::RockCorrectionChoice;
+///
+/// assert_eq!(Ok(RockCorrectionChoice::Value(0.2)), "0.2".parse());
+/// assert_eq!(Ok(RockCorrectionChoice::Value(0.2)), "value:0.2".parse());
+///
+/// let error = "constructor:100,500".parse::<RockCorrectionChoice>().unwrap_err();
+///
+/// assert_eq!(ParseChoiceError::Constructor, error);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseChoiceError {
+  /// The value is not a valid float.
+  Float(core::num::ParseFloatError),
+  /// The string describes a `Constructor`, which cannot be parsed.
+  Constructor,
+}
+
+impl fmt::Display for ParseChoiceError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      ParseChoiceError::Float(e) => write!(f, "invalid value: {}", e),
+      ParseChoiceError::Constructor => write!(f, "a constructor cannot be parsed, only a value"),
+    }
+  }
+}
+
+impl core::error::Error for ParseChoiceError {
+  fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+    match self {
+      ParseChoiceError::Float(e) => Some(e),
+      ParseChoiceError::Constructor => None,
+    }
+  }
+}
+
+impl From<core::num::ParseFloatError> for ParseChoiceError {
+  fn from(error: core::num::ParseFloatError) -> Self {
+    ParseChoiceError::Float(error)
+  }
+}
+
+/// A warning returned when a calculated result is physically implausible, which usually indicates a data-entry error.
+///
+/// The result is still available from `value()` when the calculation succeeded.
+///
+/// # Example
+///
+/// ```
+/// use billios::domain::errors::RangeWarning;
+/// use billios::field_test::Compaction;
+/// use billios::field_test::types::DryDensityChoice;
+///
+/// // The dry density and lab max are swapped.
+/// let compaction = Compaction::new(DryDensityChoice::Value(135.6), 95.);
+/// let warning = compaction.calculate_checked().unwrap_err();
+///
+/// assert_eq!(RangeWarning::AboveRange(142.7), warning);
+/// assert_eq!(Some(142.7), warning.value());
+/// assert_eq!("result 142.7 is above the plausible maximum", warning.to_string());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub enum RangeWarning<T: Float = f64> {
+  /// The calculation itself failed.
+  Calc(CalcError),
+  /// The result is below the plausible minimum.
+  BelowRange(T),
+  /// The result is above the plausible maximum.
+  AboveRange(T),
+}
+
+impl<T: Float> RangeWarning<T> {
+  /// The implausible result, or `None` if the calculation failed.
+  pub fn value(&self) -> Option<T> {
+    match self {
+      RangeWarning::Calc(_) => None,
+      RangeWarning::BelowRange(v) | RangeWarning::AboveRange(v) => Some(*v),
+    }
+  }
+
+  /// Check that `value` is between `min` and `max`, inclusive.
+  pub(crate) fn check(value: T, min: T, max: T) -> Result<T, Self> {
+    if value < min {
+      return Err(RangeWarning::BelowRange(value));
+    }
+
+    if value > max {
+      return Err(RangeWarning::AboveRange(value));
+    }
+
+    Ok(value)
+  }
+}
+
+impl<T: Float> From<CalcError> for RangeWarning<T> {
+  fn from(error: CalcError) -> Self {
+    RangeWarning::Calc(error)
+  }
+}
+
+impl<T: Float> fmt::Display for RangeWarning<T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      RangeWarning::Calc(e) => write!(f, "{}", e),
+      RangeWarning::BelowRange(v) => write!(f, "result {} is below the plausible minimum", v),
+      RangeWarning::AboveRange(v) => write!(f, "result {} is above the plausible maximum", v),
+    }
+  }
+}
+
+impl<T: Float> core::error::Error for RangeWarning<T> {}
@@ -0,0 +1,64 @@
+//! Geometry
+//!
+//! Areas and volumes of the cylindrical samples and molds used by the volumetric calculations.
+//!
+//! The results are in the unit of the inputs, squared or cubed, so a diameter and height in inches give an area in square inches (in²) and a volume in cubic inches (in³). Divide a volume in cubic inches by `CUBIC_INCHES_PER_CUBIC_FOOT` for the cubic feet (ft³) the densities of this crate are based on.
+use crate::math::utilities::GetNumPower;
+use core::f64::consts::PI;
+
+/// Cubic inches in one cubic foot.
+pub const CUBIC_INCHES_PER_CUBIC_FOOT: f64 = 1728.;
+
+/// The cross-sectional area of a cylinder, `π · (d / 2)²`, from its `diameter`.
+///
+/// # Example
+///
+/// ```
+/// use billios::domain::geometry::cross_sectional_area;
+///
+/// // A 6 inch CBR mold, in square inches.
+/// assert!((cross_sectional_area(6.) - 28.2743).abs() < 1e-4);
+/// ```
+pub fn cross_sectional_area(diameter: f64) -> f64 {
+  PI * GetNumPower::power_f64(diameter / 2., 2.)
+}
+
+/// The volume of a cylinder, `π · (d / 2)² · h`, from its `diameter` and `height`.
+///
+/// # Example
+///
+/// ```
+/// use billios::domain::geometry::{cylinder_volume, CUBIC_INCHES_PER_CUBIC_FOOT};
+///
+/// // A 4 inch Proctor mold, 4.584 inches high, is 1/30 of a cubic foot.
+/// let volume = cylinder_volume(4., 4.584) / CUBIC_INCHES_PER_CUBIC_FOOT;
+///
+/// assert!((volume - 1. / 30.).abs() < 1e-5);
+/// ```
+pub fn cylinder_volume(diameter: f64, height: f64) -> f64 {
+  cross_sectional_area(diameter) * height
+}
+
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_cross_sectional_area() {
+    assert_eq!(PI / 4., cross_sectional_area(1.));
+    assert_eq!(PI, cross_sectional_area(2.));
+    assert_eq!(0., cross_sectional_area(0.));
+  }
+
+  #[test]
+  fn test_cylinder_volume() {
+    assert_eq!(PI, cylinder_volume(2., 1.));
+    assert_eq!(0., cylinder_volume(4., 0.));
+
+    // The 6 inch Proctor mold is 0.075 cubic feet.
+    assert!((cylinder_volume(6., 4.584) / CUBIC_INCHES_PER_CUBIC_FOOT - 0.075).abs() < 1e-5);
+    assert!(cylinder_volume(f64::NAN, 1.).is_nan());
+  }
+}
@@ -0,0 +1,161 @@
+//! Memoized
+//!
+//! A wrapper that calculates a chain of calculations once, for results that are read many times.
+use crate::domain::errors::CalcError;
+use crate::domain::traits::{Calculate, Rounding};
+use crate::math::float::Float;
+use alloc::vec::Vec;
+use core::cell::OnceCell;
+
+/// A calculation that caches its unrounded result.
+///
+/// The first call to `calculate_raw()`, or to `calculate()` and the other methods built on it, runs the wrapped calculation, including any `Constructor` choices it resolves. Every later call returns the cached result, or the cached error, rounded again as needed. `terms()` is not cached.
+///
+/// The cache is a `OnceCell`, so a `Memoized` is not `Sync`, and a clone keeps whatever its original had cached. Plain calculations are cheap to repeat, so only wrap those that are read several times, e.g. by a report.
+///
+/// # Example
+///
+/// ```
+/// use billios::domain::memoized::Memoized;
+/// use billios::domain::traits::Calculate;
+/// use billios::field_test::{Compaction, DryDensity, SandUsed, WetDensity};
+/// use billios::field_test::types::{DryDensityChoice, MoistureContentChoice, SandUsedChoice, WetDensityChoice};
+///
+/// let wet_density = WetDensity::new(4.65, SandUsedChoice::Constructor(SandUsed::new(14.65, 8.75, None)), None);
+/// let dry_density = DryDensity::new(WetDensityChoice::Constructor(wet_density), MoistureContentChoice::Value(0.1));
+/// let compaction = Memoized::new(Compaction::new(DryDensityChoice::Constructor(dry_density), 135.6));
+///
+/// assert!(!compaction.is_cached());
+/// assert_eq!(Ok(118.8), compaction.calculate());
+/// assert!(compaction.is_cached());
+/// assert_eq!(Ok(118.76), compaction.calculate_with_precision(2));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Memoized<T: Float, C> {
+  inner: C,
+  raw: OnceCell<Result<T, CalcError>>,
+}
+
+impl<T: Float, C> Memoized<T, C> {
+  /// Wrap the calculation `inner`, with nothing cached.
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn new(inner: C) -> Self {
+    Self { inner, raw: OnceCell::new() }
+  }
+
+  /// Whether the result has been calculated and cached.
+  pub fn is_cached(&self) -> bool {
+    self.raw.get().is_some()
+  }
+
+  /// Getter for the wrapped calculation.
+  pub fn get_inner(&self) -> &C {
+    &self.inner
+  }
+
+  /// Unwrap the calculation, dropping the cached result.
+  pub fn into_inner(self) -> C {
+    self.inner
+  }
+}
+
+impl<T: Float, C: Calculate<T>> Rounding<T> for Memoized<T, C> {}
+
+impl<T: Float, C: Calculate<T>> Calculate<T> for Memoized<T, C> {
+  /// The cached result of the wrapped calculation, calculated on the first call.
+  fn calculate_raw(&self) -> Result<T, CalcError> {
+    *self.raw.get_or_init(|| self.inner.calculate_raw())
+  }
+
+  fn precision(&self) -> i32 {
+    self.inner.precision()
+  }
+
+  /// Rounds the cached result the same as the wrapped calculation, including any rounding it overrides.
+  fn round_result(&self, raw: T, n: i32) -> Result<T, CalcError> {
+    self.inner.round_result(raw, n)
+  }
+
+  fn unit_label(&self) -> &'static str {
+    self.inner.unit_label()
+  }
+
+  fn terms(&self) -> Result<Vec<(&'static str, T)>, CalcError> {
+    self.inner.terms()
+  }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::domain::rounded::Rounded;
+  use crate::domain::traits::CalculateClone;
+  use crate::domain::types::{RoundingConfig, RoundingMode};
+  use crate::field_test::{Compaction, RockCorrection};
+  use alloc::boxed::Box;
+  use crate::field_test::types::DryDensityChoice;
+  use core::cell::Cell;
+
+  /// Counts how many times it is calculated.
+  struct Counter<'a> {
+    calls: &'a Cell<u32>,
+  }
+
+  impl Rounding<f64> for Counter<'_> {}
+
+  impl Calculate<f64> for Counter<'_> {
+    fn calculate_raw(&self) -> Result<f64, CalcError> {
+      self.calls.set(self.calls.get() + 1);
+      Ok(114.3071)
+    }
+
+    fn precision(&self) -> i32 {
+      1
+    }
+  }
+
+  #[test]
+  fn test_memoized() {
+    let calls = Cell::new(0);
+    let memoized = Memoized::new(Counter { calls: &calls });
+
+    assert_eq!(0, calls.get());
+    assert_eq!(Ok(114.3), memoized.calculate());
+    assert_eq!(Ok(114.31), memoized.calculate_with_precision(2));
+    assert_eq!(Ok(114.3071), memoized.calculate_raw());
+    assert_eq!(1, calls.get());
+
+    let compaction = Compaction::new(DryDensityChoice::Value(155.), 135.6);
+    let wrapped = Memoized::new(compaction);
+
+    assert_eq!(compaction.calculate_detailed(), wrapped.calculate_detailed());
+    assert_eq!(compaction.precision(), wrapped.precision());
+    assert_eq!("%", wrapped.unit_label());
+    assert_eq!(&compaction, wrapped.get_inner());
+  }
+
+  #[test]
+  fn test_memoized_rounded() {
+    let even = RoundingConfig::new(RoundingMode::HalfToEven, None);
+    let rounded = Rounded::new(RockCorrection::new(31.25, 500.), even);
+    let memoized = Memoized::new(rounded);
+
+    assert_eq!(Ok(0.062), rounded.calculate());
+    assert_eq!(rounded.calculate(), memoized.calculate());
+    assert_eq!(Ok(0.06), memoized.calculate_with_precision(2));
+
+    let boxed: Box<dyn CalculateClone> = Box::new(Memoized::new(rounded));
+    assert_eq!(Ok(0.062), boxed.calculate());
+  }
+
+  #[test]
+  fn test_memoized_error() {
+    let memoized = Memoized::new(RockCorrection::new(100., 0.));
+
+    assert_eq!(Err(CalcError::DivisionByZero), memoized.calculate());
+    assert!(memoized.is_cached());
+    assert_eq!(Err(CalcError::DivisionByZero), memoized.calculate_raw());
+  }
+}
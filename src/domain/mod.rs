@@ -1 +1,9 @@
-pub mod traits;
\ No newline at end of file
+pub mod chain;
+pub mod classification;
+pub mod errors;
+pub mod geometry;
+pub mod memoized;
+pub mod rounded;
+pub mod traits;
+pub mod types;
+pub mod units;
\ No newline at end of file
@@ -0,0 +1,130 @@
+//! Rounded
+//!
+//! A wrapper that applies one rounding policy to any calculation, in place of the precision each one bakes in.
+use crate::domain::errors::CalcError;
+use crate::domain::traits::{Calculate, Rounding};
+use crate::domain::types::RoundingConfig;
+use crate::math::float::Float;
+use alloc::vec::Vec;
+
+/// A calculation rounded by a `RoundingConfig`.
+///
+/// The wrapped calculation's unrounded result is rounded with the config's mode, to its precision, or to the calculation's own `precision()` if it has none. `calculate_raw()` and `terms()` are unchanged, so a whole report can share one policy without changing the calculations.
+///
+/// # Example
+///
+/// ```
+/// use billios::domain::rounded::Rounded;
+/// use billios::domain::traits::Calculate;
+/// use billios::domain::types::{RoundingConfig, RoundingMode};
+/// use billios::field_test::{RockCorrection, SandUsed};
+///
+/// let truncate = RoundingConfig::new(RoundingMode::Floor, Some(1));
+///
+/// assert_eq!(Ok(2.3), Rounded::new(SandUsed::new(14.65, 8.75, None), truncate).calculate());
+/// assert_eq!(Ok(0.2), Rounded::new(RockCorrection::new(125., 500.), truncate).calculate());
+///
+/// // Only the mode, keeping the precision of the calculation.
+/// let even = RoundingConfig::new(RoundingMode::HalfToEven, None);
+///
+/// assert_eq!(Ok(0.063), RockCorrection::new(31.25, 500.).calculate());
+/// assert_eq!(Ok(0.062), Rounded::new(RockCorrection::new(31.25, 500.), even).calculate());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Rounded<C> {
+  inner: C,
+  config: RoundingConfig,
+}
+
+impl<C> Rounded<C> {
+  /// Wrap the calculation `inner`, to be rounded by `config`.
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn new(inner: C, config: RoundingConfig) -> Self {
+    Self { inner, config }
+  }
+
+  /// Getter for the wrapped calculation.
+  pub fn get_inner(&self) -> &C {
+    &self.inner
+  }
+
+  /// Getter for `config`
+  pub fn get_config(&self) -> RoundingConfig {
+    self.config
+  }
+
+  /// Unwrap the calculation.
+  pub fn into_inner(self) -> C {
+    self.inner
+  }
+}
+
+impl<T: Float, C: Calculate<T>> Rounding<T> for Rounded<C> {}
+
+impl<T: Float, C: Calculate<T>> Calculate<T> for Rounded<C> {
+  fn calculate_raw(&self) -> Result<T, CalcError> {
+    self.inner.calculate_raw()
+  }
+
+  /// The precision of the config, or of the wrapped calculation if the config has none.
+  fn precision(&self) -> i32 {
+    self.config.get_precision().unwrap_or_else(|| self.inner.precision())
+  }
+
+  fn unit_label(&self) -> &'static str {
+    self.inner.unit_label()
+  }
+
+  /// Rounds with the mode of the config, to (n) decimal places.
+  fn round_result(&self, raw: T, n: i32) -> Result<T, CalcError> {
+    self.round_n_with(raw, n, self.config.get_mode())
+  }
+
+  fn terms(&self) -> Result<Vec<(&'static str, T)>, CalcError> {
+    self.inner.terms()
+  }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::domain::types::RoundingMode;
+  use crate::field_test::{Compaction, RockCorrection, SandUsed};
+  use alloc::boxed::Box;
+  use alloc::vec;
+  use crate::field_test::types::DryDensityChoice;
+
+  #[test]
+  fn test_rounded() {
+    let compaction = Compaction::new(DryDensityChoice::Value(155.), 135.6);
+
+    // The default config rounds the same as the calculation.
+    let default = Rounded::new(compaction, RoundingConfig::default());
+    assert_eq!(compaction.calculate(), default.calculate());
+    assert_eq!(compaction.precision(), default.precision());
+    assert_eq!("%", default.unit_label());
+    assert_eq!(compaction.calculate_detailed(), default.calculate_detailed());
+
+    let ceil = Rounded::new(compaction, RoundingConfig::new(RoundingMode::Ceil, Some(0)));
+    assert_eq!(Ok(115.), ceil.calculate());
+    assert_eq!(0, ceil.precision());
+    assert_eq!(Ok(114.31), ceil.calculate_with_precision(2));
+    assert_eq!(compaction.calculate_raw(), ceil.calculate_raw());
+    assert_eq!(compaction, ceil.into_inner());
+
+    let mixed: Vec<Box<dyn Calculate>> = vec![
+      Box::new(Rounded::new(compaction, RoundingConfig::new(RoundingMode::Floor, Some(-1)))),
+      Box::new(Rounded::new(RockCorrection::new(31.25, 500.), RoundingConfig::new(RoundingMode::HalfToEven, None))),
+    ];
+    assert_eq!(vec![110., 0.062], mixed.iter().map(|c| c.calculate_or_panic()).collect::<Vec<_>>());
+  }
+
+  #[test]
+  fn test_rounded_error() {
+    let rounded = Rounded::new(SandUsed::new(f64::NAN, 8.75, None), RoundingConfig::new(RoundingMode::Floor, Some(1)));
+
+    assert_eq!(Err(CalcError::NonFinite), rounded.calculate());
+  }
+}
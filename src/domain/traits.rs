@@ -1,15 +1,500 @@
 //! Traits
 //!
-use crate::math::utilities::GetNumPower;
+use crate::domain::errors::CalcError;
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use crate::domain::types::{CalcResult, RoundingMode};
+use crate::math::float::{approx_eq, format_result, Float};
 
 /// A trait for adding the ability to round to (n) decimal places.
-pub trait Rounding {
+///
+/// Generic over the `Float` type being rounded, which defaults to `f64`.
+pub trait Rounding<T: Float = f64> {
   /// Round a floating point number to the number (n) decimal points.
-  fn round_n(&self, number: f64, n: u32) -> Result<f64, ()> {
-    let power: f64 = GetNumPower::power_10(n).into();
+  ///
+  /// A negative `n` rounds to the left of the decimal point, e.g. `n = -1` rounds to the nearest ten.
+  ///
+  /// Uses the default `RoundingMode::HalfAwayFromZero`.
+  ///
+  /// Returns `CalcError::NonFinite` if `number` is `NaN` or infinite, and `CalcError::Rounding` if the rounded result is not finite.
+  fn round_n(&self, number: T, n: i32) -> Result<T, CalcError> {
+    self.round_n_with(number, n, RoundingMode::default())
+  }
+
+  /// Round a floating point number to the number (n) decimal points using the given `RoundingMode`.
+  ///
+  /// If scaling `number` by `10^n` overflows, `number` is returned unchanged since it cannot be represented with more precision.
+  ///
+  /// Returns `CalcError::NonFinite` if `number` is `NaN` or infinite, and `CalcError::Rounding` if the rounded result is not finite.
+  fn round_n_with(&self, number: T, n: i32, mode: RoundingMode) -> Result<T, CalcError> {
+    if !number.is_finite() {
+      return Err(CalcError::NonFinite);
+    }
+
+    let power = T::from_f64(10.).powi(n);
+    let scaled = number * power;
+
+    if !scaled.is_finite() {
+      return Ok(number);
+    }
 
-    let result = (number * power).round() / power;
+    let rounded = match mode {
+      RoundingMode::HalfAwayFromZero => scaled.round(),
+      RoundingMode::HalfToEven => scaled.round_ties_even(),
+      RoundingMode::Ceil => scaled.ceil(),
+      RoundingMode::Floor => scaled.floor(),
+    };
+
+    let result = rounded / power;
+
+    if !result.is_finite() {
+      return Err(CalcError::Rounding);
+    }
 
     Ok(result)
   }
+
+  /// Round a floating point number to (sig) significant figures, using `round_n()`.
+  ///
+  /// For example `0.0142857` to three significant figures is `0.0143`, and `1771.4` is `1770`. Zero is returned as is.
+  ///
+  /// Returns `CalcError::OutOfRange` if `sig` is `0`, along with the errors of `round_n()`.
+  fn round_sig(&self, number: T, sig: u32) -> Result<T, CalcError> {
+    if sig == 0 {
+      return Err(CalcError::OutOfRange);
+    }
+
+    if !number.is_finite() {
+      return Err(CalcError::NonFinite);
+    }
+
+    if number == T::ZERO {
+      return Ok(number);
+    }
+
+    let ten = T::from_f64(10.);
+    let absolute = number.abs();
+
+    // The logarithm can land either side of an exact power of ten, so check the magnitude against its powers.
+    let mut magnitude = (absolute.ln() / ten.ln()).floor().to_f64() as i32;
+
+    if ten.powi(magnitude) > absolute {
+      magnitude -= 1;
+    } else if ten.powi(magnitude + 1) <= absolute {
+      magnitude += 1;
+    }
+
+    let sig = i32::try_from(sig).unwrap_or(i32::MAX);
+
+    self.round_n(number, sig.saturating_sub(1).saturating_sub(magnitude))
+  }
+}
+
+/// A trait shared by every calculation that produces a single result.
+///
+/// This allows calculations to be treated uniformly, either through generics or trait objects. Generic over the `Float` type of the result, which defaults to `f64`.
+///
+/// # Example
+///
+/// ```
+/// use billios::domain::errors::CalcError;
+/// use billios::domain::traits::Calculate;
+/// use billios::field_test::{SandUsed, RockCorrection};
+///
+/// fn run<T: Calculate>(c: &T) -> Result<f64, CalcError> {
+///   c.calculate()
+/// }
+///
+/// assert_eq!(Ok(2.31), run(&SandUsed::new(14.65, 8.75, None)));
+///
+/// let calculations: Vec<Box<dyn Calculate>> = vec![
+///   Box::new(SandUsed::new(14.65, 8.75, None)),
+///   Box::new(RockCorrection::new(100., 500.)),
+/// ];
+///
+/// let results: Vec<f64> = calculations.iter().map(|c| c.calculate_or_panic()).collect();
+/// assert_eq!(vec![2.31, 0.2], results);
+/// ```
+pub trait Calculate<T: Float = f64>: Rounding<T> {
+  /// Calculate the unrounded result of the formula.
+  ///
+  /// Useful for chaining further calculations without accumulating rounding error. Divisions return `CalcError::DivisionByZero` or `CalcError::NonFinite` rather than `NaN` or infinity, but the result is not otherwise checked, so it may still be `NaN` or infinite where `calculate()` would return `CalcError::NonFinite`.
+  fn calculate_raw(&self) -> Result<T, CalcError>;
+
+  /// The number of decimal places `calculate()` rounds the result to.
+  fn precision(&self) -> i32;
+
+  /// Calculate the result of the formula, rounded to its default `precision()`.
+  ///
+  /// Returns `CalcError::NonFinite` if the result is `NaN` or infinite, such as from a `NaN` or infinite input, so it never returns `Ok(NaN)`. Use `try_new()` to reject those inputs before calculating.
+  #[must_use = "the result of a calculation should be used, or its error handled"]
+  fn calculate(&self) -> Result<T, CalcError> {
+    self.calculate_with_precision(self.precision())
+  }
+
+  /// Calculate the result of the formula, rounded to (n) decimal places instead of the default `precision()`.
+  ///
+  /// Follows `round_n()`, so a negative `n` rounds to the left of the decimal point.
+  fn calculate_with_precision(&self, n: i32) -> Result<T, CalcError> {
+    self.round_result(self.calculate_raw()?, n)
+  }
+
+  /// Round an unrounded result of the formula to (n) decimal places, the step `calculate_with_precision()` applies to `calculate_raw()`.
+  ///
+  /// Defaults to `round_n()`. Implementations that round differently, such as `Rounded`, override this, so wrappers that produce the raw result themselves, such as `Memoized`, can still round it the same way.
+  fn round_result(&self, raw: T, n: i32) -> Result<T, CalcError> {
+    self.round_n(raw, n)
+  }
+
+  /// The labelled terms used by the formula, with any defaults applied and `Constructor` values resolved.
+  ///
+  /// Defaults to no terms, for implementations that do not provide them.
+  fn terms(&self) -> Result<Vec<(&'static str, T)>, CalcError> {
+    Ok(Vec::new())
+  }
+
+  /// The unit of the result, for labelling it without knowing which calculation it came from, e.g. `"pcf"`, `"lb"`, `"%"`, or `"fraction"` for a fraction (decimal).
+  ///
+  /// Defaults to `""`, which is also the label of a result with no unit, such as a specific gravity or void ratio, or one in the unit of its inputs, such as `Settlement`.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use billios::domain::traits::Calculate;
+  /// use billios::field_test::{MoistureContent, SandUsed};
+  ///
+  /// let calculations: Vec<Box<dyn Calculate>> = vec![
+  ///   Box::new(SandUsed::new(14.65, 8.75, None)),
+  ///   Box::new(MoistureContent::new(1750., 1575., 1400.)),
+  /// ];
+  ///
+  /// let labelled: Vec<String> = calculations.iter().map(|c| format!("{} {}", c.calculate_or_panic(), c.unit_label())).collect();
+  /// assert_eq!(vec!["2.31 lb", "1 fraction"], labelled);
+  /// ```
+  fn unit_label(&self) -> &'static str {
+    ""
+  }
+
+  /// The number of decimal places `calculate_formatted()` shows, the `precision()`, or zero if it rounds to the left of the decimal point.
+  fn display_precision(&self) -> u32 {
+    self.precision().max(0) as u32
+  }
+
+  /// Calculate the result of the formula, formatted with exactly `display_precision()` decimal places, keeping trailing zeros.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use billios::domain::traits::Calculate;
+  /// use billios::field_test::{SandUsed, WetDensity};
+  /// use billios::field_test::types::SandUsedChoice;
+  ///
+  /// let sand_used = SandUsed::new(14.7, 8.75, None);
+  /// assert_eq!(Ok(2.36), sand_used.calculate());
+  /// assert_eq!(Ok("2.36".to_string()), sand_used.calculate_formatted());
+  ///
+  /// let wet_density = WetDensity::new(4.65, SandUsedChoice::Value(2.325), None);
+  /// assert_eq!(Ok("176.0000".to_string()), wet_density.calculate_formatted());
+  /// ```
+  fn calculate_formatted(&self) -> Result<String, CalcError> {
+    Ok(format_result(self.calculate()?.to_f64(), self.display_precision()))
+  }
+
+  /// Calculate the result of the formula, along with the `terms()` that went into it.
+  fn calculate_detailed(&self) -> Result<CalcResult<T>, CalcError> {
+    Ok(CalcResult::new(self.calculate()?, self.terms()?))
+  }
+
+  /// Calculate the result of the formula, panicking if the calculation fails.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `calculate()` returns an error.
+  fn calculate_or_panic(&self) -> T {
+    self.calculate().expect("Error calculating the result.")
+  }
+
+  /// Returns `true` if the calculation succeeds and its result is within `epsilon` of `expected`.
+  ///
+  /// Useful for asserting results in downstream tests without comparing floats exactly.
+  fn approx_eq(&self, expected: T, epsilon: T) -> bool {
+    match self.calculate() {
+      Ok(result) => approx_eq(result, expected, epsilon),
+      Err(_) => false,
+    }
+  }
+}
+
+/// A `Calculate` that can be cloned behind a `Box`, for storing different calculations together in a cloneable `Vec`.
+///
+/// `Clone` can't be used by a trait object, so `clone_box()` is used in its place. It is implemented for every `Calculate` that is `Clone`, which is every calculation struct in the crate, and `Box<dyn CalculateClone>` is itself `Clone` and `Calculate`.
+///
+/// # Example
+///
+/// ```
+/// use billios::domain::traits::{Calculate, CalculateClone};
+/// use billios::field_test::{SandUsed, RockCorrection};
+///
+/// let calculations: Vec<Box<dyn CalculateClone>> = vec![
+///   Box::new(SandUsed::new(14.65, 8.75, None)),
+///   Box::new(RockCorrection::new(100., 500.)),
+/// ];
+///
+/// let copied = calculations.clone();
+///
+/// let results: Vec<f64> = copied.iter().map(|c| c.calculate_or_panic()).collect();
+/// assert_eq!(vec![2.31, 0.2], results);
+/// ```
+pub trait CalculateClone<T: Float = f64>: Calculate<T> {
+  /// Clone the calculation into a new `Box`.
+  fn clone_box(&self) -> Box<dyn CalculateClone<T>>;
+}
+
+impl<T: Float, C: Calculate<T> + Clone + 'static> CalculateClone<T> for C {
+  fn clone_box(&self) -> Box<dyn CalculateClone<T>> {
+    Box::new(self.clone())
+  }
+}
+
+impl<T: Float> Clone for Box<dyn CalculateClone<T>> {
+  fn clone(&self) -> Self {
+    (**self).clone_box()
+  }
+}
+
+impl<T: Float> Rounding<T> for Box<dyn CalculateClone<T>> {}
+
+/// Delegates to the boxed calculation, including any methods it overrides.
+impl<T: Float> Calculate<T> for Box<dyn CalculateClone<T>> {
+  fn calculate_raw(&self) -> Result<T, CalcError> {
+    (**self).calculate_raw()
+  }
+
+  fn precision(&self) -> i32 {
+    (**self).precision()
+  }
+
+  fn calculate(&self) -> Result<T, CalcError> {
+    (**self).calculate()
+  }
+
+  fn calculate_with_precision(&self, n: i32) -> Result<T, CalcError> {
+    (**self).calculate_with_precision(n)
+  }
+
+  fn round_result(&self, raw: T, n: i32) -> Result<T, CalcError> {
+    (**self).round_result(raw, n)
+  }
+
+  fn terms(&self) -> Result<Vec<(&'static str, T)>, CalcError> {
+    (**self).terms()
+  }
+
+  fn display_precision(&self) -> u32 {
+    (**self).display_precision()
+  }
+
+  fn unit_label(&self) -> &'static str {
+    (**self).unit_label()
+  }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  struct Rounder;
+
+  impl Rounding for Rounder {}
+
+  impl Rounding<f32> for Rounder {}
+
+  #[test]
+  fn test_clone_box() {
+    use crate::domain::chain::Chain;
+    use crate::field_test::{RockCorrection, SandUsed, WetDensity};
+    use crate::field_test::batch::calculate_all;
+    use alloc::vec;
+
+    let calculations: Vec<Box<dyn CalculateClone>> = vec![
+      Box::new(SandUsed::new(14.65, 8.75, None)),
+      Box::new(RockCorrection::new(100., 0.)),
+      Box::new(Chain::new(SandUsed::new(14.65, 8.75, None)).then(|su| WetDensity::new(4.65, su.into(), None))),
+    ];
+
+    let copied = calculations.clone();
+
+    assert_eq!(vec![Ok(2.31), Err(CalcError::DivisionByZero), Ok(177.1429)], calculate_all(&copied));
+    assert_eq!(calculations[2].precision(), copied[2].precision());
+    assert_eq!(calculations[0].calculate_detailed(), copied[0].calculate_detailed());
+    assert_eq!(Ok(2.3), copied[0].calculate_with_precision(1));
+  }
+
+  /// Every calculation, with `x` as its first input.
+  fn every_calculation(x: f64) -> Vec<(&'static str, Box<dyn Calculate>)> {
+    use crate::field_test::*;
+    use crate::field_test::types::*;
+    use alloc::vec;
+
+    vec![
+      ("SandUsed", Box::new(SandUsed::new(x, 8.75, None))),
+      ("WetDensity", Box::new(WetDensity::new(x, SandUsedChoice::Value(2.31), None))),
+      ("MoistureContent", Box::new(MoistureContent::new(x, 1575., 1400.))),
+      ("DryDensity", Box::new(DryDensity::new(WetDensityChoice::Value(x), MoistureContentChoice::Value(0.1)))),
+      ("Compaction", Box::new(Compaction::new(DryDensityChoice::Value(x), 135.6))),
+      ("BulkSpecificGravity", Box::new(BulkSpecificGravity::new(x, 1205.3, 695.1))),
+      ("AsphaltCompaction", Box::new(AsphaltCompaction::new(BulkSpecificGravityChoice::Value(x), 2.5))),
+      ("AirVoids", Box::new(AirVoids::new(BulkSpecificGravityChoice::Value(x), 2.5))),
+      ("RockCorrection", Box::new(RockCorrection::new(x, 500.))),
+      ("LabMaxCorrection", Box::new(LabMaxCorrection::new(RockCorrectionChoice::Value(x), 135.6, None))),
+      ("CorrectedCompaction", Box::new(CorrectedCompaction::new(DryDensityChoice::Value(x), 135.6, RockCorrectionChoice::Value(0.2), None))),
+      ("InterpolatedLabMax", Box::new(InterpolatedLabMax::new((0.1, 130.), (0.3, 138.), RockCorrectionChoice::Value(x)))),
+      ("PlasticityIndex", Box::new(PlasticityIndex::new(x, 20.))),
+      ("ShrinkageLimit", Box::new(ShrinkageLimit::new(MoistureContentChoice::Value(x), 20., 15., 25.))),
+      ("VoidRatio", Box::new(VoidRatio::new(DryDensityChoice::Value(x), None))),
+      ("Porosity", Box::new(Porosity::new(DryDensityChoice::Value(x), None))),
+      ("Saturation", Box::new(Saturation::new(MoistureContentChoice::Value(x), VoidRatioChoice::Value(0.5), None))),
+      ("RelativeDensity", Box::new(RelativeDensity::new(x, 0.4, VoidRatioChoice::Value(0.55)))),
+      ("SpecificGravity", Box::new(SpecificGravity::new(x, 650., 712.5, None))),
+      ("OnePointProctor", Box::new(OnePointProctor::new(DryDensityChoice::Value(x), MoistureContentChoice::Value(0.12), None, None))),
+      ("CoreSampleDensity", Box::new(CoreSampleDensity::new(x, 0.025))),
+      ("MaxIndexDensity", Box::new(MaxIndexDensity::new(x, 10., 0.03))),
+      ("MinIndexDensity", Box::new(MinIndexDensity::new(x, 10., 0.03))),
+      ("CompositeLabMax", Box::new(CompositeLabMax::new(vec![(1., x)]))),
+      ("MoistureDeviation", Box::new(MoistureDeviation::new(MoistureContentChoice::Value(x), 12., 2.))),
+      ("BearingCapacity", Box::new(BearingCapacity::new(x, 30., DryDensityChoice::Value(110.), 3., 4.))),
+      ("Settlement", Box::new(Settlement::new(x, VoidRatioChoice::Value(0.8), 10., 1000., 500.))),
+      ("HazenPermeability", Box::new(HazenPermeability::new(x, None))),
+      ("LiquidLimitFlowCurve", Box::new(LiquidLimitFlowCurve::new(vec![(x, 40.), (15., 44.)]))),
+    ]
+  }
+
+  #[test]
+  fn test_non_finite_inputs() {
+    for x in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+      for (name, calculation) in every_calculation(x) {
+        assert_eq!(Err(CalcError::NonFinite), calculation.calculate(), "{} with {}", name, x);
+        assert_eq!(Err(CalcError::NonFinite), calculation.calculate_with_precision(2), "{} with {}", name, x);
+        assert!(!calculation.approx_eq(x, f64::INFINITY), "{} with {}", name, x);
+      }
+    }
+  }
+
+  #[test]
+  fn test_unit_label() {
+    for (name, calculation) in every_calculation(0.5) {
+      let expected = match name {
+        "SandUsed" => "lb",
+        "WetDensity" | "DryDensity" | "LabMaxCorrection" | "InterpolatedLabMax" | "OnePointProctor" | "CoreSampleDensity" | "MaxIndexDensity" | "MinIndexDensity" | "CompositeLabMax" => "pcf",
+        "MoistureContent" | "RockCorrection" | "Porosity" => "fraction",
+        "Compaction" | "AsphaltCompaction" | "AirVoids" | "CorrectedCompaction" | "PlasticityIndex" | "ShrinkageLimit" | "Saturation" | "RelativeDensity" | "MoistureDeviation" | "LiquidLimitFlowCurve" => "%",
+        "HazenPermeability" => "cm/s",
+        _ => "",
+      };
+
+      assert_eq!(expected, calculation.unit_label(), "{}", name);
+    }
+
+    let boxed: Box<dyn CalculateClone> = Box::new(crate::field_test::SandUsed::new(14.7, 8.75, None));
+    assert_eq!("lb", boxed.unit_label());
+  }
+
+  #[test]
+  fn test_calculate_formatted() {
+    use crate::domain::rounded::Rounded;
+    use crate::domain::types::{RoundingConfig, RoundingMode};
+    use crate::field_test::SandUsed;
+
+    for (name, calculation) in every_calculation(0.5) {
+      let Ok(formatted) = calculation.calculate_formatted() else { continue };
+      let decimals = formatted.split_once('.').map_or(0, |(_, d)| d.len());
+
+      assert_eq!(calculation.display_precision() as usize, decimals, "{} formatted as {}", name, formatted);
+    }
+
+    // Rounding to the left of the decimal point shows no decimals.
+    let tens = Rounded::new(SandUsed::new(1465., 875., None), RoundingConfig::new(RoundingMode::HalfAwayFromZero, Some(-1)));
+    assert_eq!(0, tens.display_precision());
+    assert_eq!(Ok("590".into()), tens.calculate_formatted());
+
+    let boxed: Box<dyn CalculateClone> = Box::new(SandUsed::new(14.7, 8.75, None));
+    assert_eq!(2, boxed.display_precision());
+    assert_eq!(Err(CalcError::DivisionByZero), crate::field_test::RockCorrection::new(1., 0.).calculate_formatted());
+  }
+
+  #[test]
+  fn test_round_n() {
+    assert_eq!(Ok(0.13), Rounder.round_n(0.125, 2));
+    assert_eq!(Ok(3.), Rounder.round_n(2.5, 0));
+    assert_eq!(Ok(-3.), Rounder.round_n(-2.5, 0));
+    assert_eq!(Err(CalcError::NonFinite), Rounder.round_n(f64::NAN, 2));
+  }
+
+  #[test]
+  fn test_round_n_zero() {
+    assert_eq!(Ok(114.), Rounder.round_n(114.31, 0));
+    assert_eq!(Ok(115.), Rounder.round_n(114.5, 0));
+  }
+
+  #[test]
+  fn test_round_n_negative() {
+    assert_eq!(Ok(110.), Rounder.round_n(114.31, -1));
+    assert_eq!(Ok(100.), Rounder.round_n(114.31, -2));
+    assert_eq!(Ok(200.), Rounder.round_n(150., -2));
+    assert_eq!(Ok(-1200.), Rounder.round_n(-1234.5, -2));
+  }
+
+  #[test]
+  fn test_round_n_large() {
+    assert_eq!(Ok(0.123456789012346), Rounder.round_n(0.1234567890123456, 15));
+    assert_eq!(Ok(2.31), Rounder.round_n(2.31, 15));
+    assert_eq!(Ok(f64::MAX), Rounder.round_n(f64::MAX, 15));
+  }
+
+  #[test]
+  fn test_round_n_f32() {
+    assert_eq!(Ok(0.13_f32), Rounder.round_n(0.125_f32, 2));
+    assert_eq!(Ok(110_f32), Rounder.round_n(114.31_f32, -1));
+    assert_eq!(Err(CalcError::NonFinite), Rounder.round_n(f32::NAN, 2));
+  }
+
+  #[test]
+  fn test_round_n_with() {
+    assert_eq!(Ok(0.13), Rounder.round_n_with(0.125, 2, RoundingMode::HalfAwayFromZero));
+
+    assert_eq!(Ok(0.12), Rounder.round_n_with(0.125, 2, RoundingMode::HalfToEven));
+    assert_eq!(Ok(2.), Rounder.round_n_with(2.5, 0, RoundingMode::HalfToEven));
+    assert_eq!(Ok(4.), Rounder.round_n_with(3.5, 0, RoundingMode::HalfToEven));
+    assert_eq!(Ok(-2.), Rounder.round_n_with(-2.5, 0, RoundingMode::HalfToEven));
+
+    assert_eq!(Ok(114.4), Rounder.round_n_with(114.31, 1, RoundingMode::Ceil));
+    assert_eq!(Ok(-114.3), Rounder.round_n_with(-114.31, 1, RoundingMode::Ceil));
+
+    assert_eq!(Ok(114.3), Rounder.round_n_with(114.39, 1, RoundingMode::Floor));
+    assert_eq!(Ok(-114.4), Rounder.round_n_with(-114.39, 1, RoundingMode::Floor));
+  }
+
+  #[test]
+  fn test_round_sig() {
+    assert_eq!(Ok(0.0143), Rounder.round_sig(0.0142857, 3));
+    assert_eq!(Ok(0.14), Rounder.round_sig(0.14285714, 2));
+    assert_eq!(Ok(2.31), Rounder.round_sig(2.3125, 3));
+    assert_eq!(Ok(1770.), Rounder.round_sig(1771.4, 3));
+    assert_eq!(Ok(177100.), Rounder.round_sig(177142.9, 4));
+    assert_eq!(Ok(-1800.), Rounder.round_sig(-1771.4, 2));
+
+    // Exact powers of ten, and rounding up to the next one.
+    assert_eq!(Ok(1000.), Rounder.round_sig(1000., 1));
+    assert_eq!(Ok(0.001), Rounder.round_sig(0.001, 2));
+    assert_eq!(Ok(1000.), Rounder.round_sig(999.6, 3));
+
+    assert_eq!(Ok(0.), Rounder.round_sig(0., 3));
+    assert_eq!(Ok(0.0143_f32), Rounder.round_sig(0.0142857_f32, 3));
+    assert_eq!(Ok(2.3125), Rounder.round_sig(2.3125, u32::MAX));
+    assert_eq!(Err(CalcError::OutOfRange), Rounder.round_sig(2.31, 0));
+    assert_eq!(Err(CalcError::NonFinite), Rounder.round_sig(f64::INFINITY, 3));
+  }
 }
\ No newline at end of file
@@ -0,0 +1,207 @@
+//! Types
+use alloc::vec::Vec;
+use crate::math::float::Float;
+use core::fmt;
+
+/// The strategy used when rounding a number to (n) decimal places.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RoundingMode {
+  /// Round half-way cases away from zero (`2.5` becomes `3`, `-2.5` becomes `-3`). This is the default.
+  #[default]
+  HalfAwayFromZero,
+  /// Round half-way cases to the nearest even number, also known as banker's rounding (`2.5` becomes `2`, `3.5` becomes `4`).
+  HalfToEven,
+  /// Always round towards positive infinity.
+  Ceil,
+  /// Always round towards negative infinity.
+  Floor,
+}
+
+/// A rounding policy, the `RoundingMode` and number of decimal places, applied to a calculation by `Rounded`.
+///
+/// A `precision` of `None` keeps the default `precision()` of each calculation, so only the mode changes. The default is the same rounding as an unwrapped calculation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RoundingConfig {
+  mode: RoundingMode,
+  precision: Option<i32>,
+}
+
+impl RoundingConfig {
+  /// Create a new instance of `RoundingConfig`.
+  ///
+  /// # Arguments
+  ///
+  /// - `mode` - The `RoundingMode` to round with.
+  ///
+  /// - `precision` - An `Option<i32>` of the decimal places to round to, that when `None` uses the `precision()` of each calculation.
+  ///
+  pub fn new(mode: RoundingMode, precision: Option<i32>) -> Self {
+    Self { mode, precision }
+  }
+
+  /// Getter for `mode`
+  pub fn get_mode(&self) -> RoundingMode {
+    self.mode
+  }
+
+  /// Getter for `precision`
+  pub fn get_precision(&self) -> Option<i32> {
+    self.precision
+  }
+}
+
+/// A quantity that is a proportion, such as a moisture content, rock correction, or compaction, held so it is unambiguous whether it is a fraction or a percent.
+///
+/// `from_fraction(0.2)` and `from_percent(20.)` are the same `Percentage`, and converting it back is explicit with `as_fraction()` or `as_percent()`.
+///
+/// # Example
+///
+/// ```
+/// use billios::domain::types::Percentage;
+/// use billios::field_test::{DryDensity, MoistureContent};
+/// use billios::field_test::types::WetDensityChoice;
+///
+/// let moisture_content = MoistureContent::new(1620., 1600., 1400.).percentage().unwrap();
+///
+/// assert_eq!(Percentage::from_percent(10.), moisture_content);
+/// assert_eq!(0.1, moisture_content.as_fraction());
+/// assert_eq!("10%", moisture_content.to_string());
+///
+/// // Converts to the fraction a `MoistureContentChoice` expects.
+/// let dry_density = DryDensity::new(WetDensityChoice::Value(177.1429), moisture_content.into());
+/// assert_eq!(Ok(161.), dry_density.calculate());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Percentage<T: Float = f64> {
+  fraction: T,
+}
+
+impl<T: Float> Percentage<T> {
+  /// Create a new instance of `Percentage` from a fraction (decimal), e.g. `0.2` for 20%.
+  pub fn from_fraction(fraction: T) -> Self {
+    Self { fraction }
+  }
+
+  /// Create a new instance of `Percentage` from a percent, e.g. `20.` for 20%.
+  pub fn from_percent(percent: T) -> Self {
+    Self { fraction: percent / T::from_f64(100.) }
+  }
+
+  /// The value as a fraction (decimal), e.g. `0.2` for 20%.
+  pub fn as_fraction(&self) -> T {
+    self.fraction
+  }
+
+  /// The value as a percent, e.g. `20.` for 20%.
+  pub fn as_percent(&self) -> T {
+    self.fraction * T::from_f64(100.)
+  }
+}
+
+/// Displays the percent, followed by `%`.
+impl<T: Float> fmt::Display for Percentage<T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}%", self.as_percent())
+  }
+}
+
+/// The result of a calculation, along with the labelled terms that went into it.
+///
+/// Returned by `Calculate::calculate_detailed()`. The terms have any defaults applied, and `Constructor` values resolved, so every number used can be shown on a calculation sheet.
+///
+/// # Example
+///
+/// ```
+/// use billios::domain::traits::Calculate;
+/// use billios::field_test::SandUsed;
+///
+/// let detailed = SandUsed::new(14.65, 8.75, None).calculate_detailed().unwrap();
+///
+/// assert_eq!(2.31, detailed.value());
+/// assert_eq!(Some(3.59), detailed.get("sand_in_cone"));
+/// assert_eq!("2.31\n  cone_pre_test: 14.65\n  cone_post_test: 8.75\n  sand_in_cone: 3.59", detailed.to_string());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalcResult<T: Float = f64> {
+  value: T,
+  terms: Vec<(&'static str, T)>,
+}
+
+impl<T: Float> CalcResult<T> {
+  /// Create a new instance of `CalcResult`.
+  pub fn new(value: T, terms: Vec<(&'static str, T)>) -> Self {
+    Self { value, terms }
+  }
+
+  /// Getter for `value`, the rounded result of the calculation.
+  pub fn value(&self) -> T {
+    self.value
+  }
+
+  /// Getter for `terms`, as `(label, value)` pairs in the order they appear in the formula.
+  pub fn terms(&self) -> &[(&'static str, T)] {
+    &self.terms
+  }
+
+  /// Get the value of the term with the given `label`, if the calculation used one.
+  pub fn get(&self, label: &str) -> Option<T> {
+    self.terms.iter().find(|(name, _)| *name == label).map(|&(_, value)| value)
+  }
+}
+
+impl<T: Float> fmt::Display for CalcResult<T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}", self.value)?;
+
+    for (label, value) in &self.terms {
+      write!(f, "\n  {}: {}", label, value)?;
+    }
+
+    Ok(())
+  }
+}
+
+/// Compares the `value` and every term, and requires the terms to have the same labels in the same order.
+#[cfg(feature = "approx")]
+impl<T: Float + approx::AbsDiffEq<Epsilon = T>> approx::AbsDiffEq for CalcResult<T> {
+  type Epsilon = T;
+
+  fn default_epsilon() -> T {
+    T::default_epsilon()
+  }
+
+  fn abs_diff_eq(&self, other: &Self, epsilon: T) -> bool {
+    self.value.abs_diff_eq(&other.value, epsilon)
+      && self.terms.len() == other.terms.len()
+      && self.terms.iter().zip(&other.terms).all(|((a_label, a), (b_label, b))| a_label == b_label && a.abs_diff_eq(b, epsilon))
+  }
+}
+
+#[cfg(feature = "approx")]
+impl<T: Float + approx::RelativeEq<Epsilon = T>> approx::RelativeEq for CalcResult<T> {
+  fn default_max_relative() -> T {
+    T::default_max_relative()
+  }
+
+  fn relative_eq(&self, other: &Self, epsilon: T, max_relative: T) -> bool {
+    self.value.relative_eq(&other.value, epsilon, max_relative)
+      && self.terms.len() == other.terms.len()
+      && self.terms.iter().zip(&other.terms).all(|((a_label, a), (b_label, b))| a_label == b_label && a.relative_eq(b, epsilon, max_relative))
+  }
+}
+
+#[cfg(feature = "approx")]
+impl<T: Float + approx::UlpsEq<Epsilon = T>> approx::UlpsEq for CalcResult<T> {
+  fn default_max_ulps() -> u32 {
+    T::default_max_ulps()
+  }
+
+  fn ulps_eq(&self, other: &Self, epsilon: T, max_ulps: u32) -> bool {
+    self.value.ulps_eq(&other.value, epsilon, max_ulps)
+      && self.terms.len() == other.terms.len()
+      && self.terms.iter().zip(&other.terms).all(|((a_label, a), (b_label, b))| a_label == b_label && a.ulps_eq(b, epsilon, max_ulps))
+  }
+}
@@ -0,0 +1,324 @@
+//! Units
+//!
+//! The calculations in this crate use imperial units: weights in pounds (lb) and densities in pounds per cubic foot (pcf).
+//! These helpers convert SI measurements into those units before a calculation, and convert the results back for reporting.
+
+/// Kilograms in one pound.
+pub const KG_PER_LB: f64 = 0.453_592_37;
+
+/// Grams in one pound.
+pub const GRAMS_PER_LB: f64 = 453.592_37;
+
+/// Kilograms per cubic meter in one pound per cubic foot.
+pub const KG_M3_PER_PCF: f64 = 16.018_463_373_960_14;
+
+/// Convert a density from pounds per cubic foot (pcf) to kilograms per cubic meter (kg/m³).
+pub fn pcf_to_kg_m3(pcf: f64) -> f64 {
+  pcf * KG_M3_PER_PCF
+}
+
+/// Convert a density from kilograms per cubic meter (kg/m³) to pounds per cubic foot (pcf).
+pub fn kg_m3_to_pcf(kg_m3: f64) -> f64 {
+  kg_m3 / KG_M3_PER_PCF
+}
+
+/// Convert a weight from pounds (lb) to kilograms (kg).
+pub fn lb_to_kg(lb: f64) -> f64 {
+  lb * KG_PER_LB
+}
+
+/// Convert a weight from kilograms (kg) to pounds (lb).
+pub fn kg_to_lb(kg: f64) -> f64 {
+  kg / KG_PER_LB
+}
+
+/// Convert a weight from grams (g) to pounds (lb).
+pub fn grams_to_lb(grams: f64) -> f64 {
+  grams / GRAMS_PER_LB
+}
+
+/// Convert a weight from pounds (lb) to grams (g).
+pub fn lb_to_grams(lb: f64) -> f64 {
+  lb * GRAMS_PER_LB
+}
+
+/// Get the temperature correction factor, `K`, that corrects a pycnometer specific gravity to 20°C.
+///
+/// `K` is the ratio of the density of water at the test temperature to its density at 20°C, as in ASTM D854, from `water_density()`. It covers the same 0°C to 40°C, and is clamped the same outside it.
+///
+/// # Example
+///
+/// ```
+/// use billios::domain::units::temperature_correction_factor;
+/// use billios::field_test::SpecificGravity;
+///
+/// assert!((temperature_correction_factor(24.) - 0.99909).abs() < 5e-6);
+///
+/// let specific_gravity = SpecificGravity::new(100., 650., 712.5, Some(temperature_correction_factor(24.)));
+/// assert_eq!(Ok(2.66), specific_gravity.calculate());
+/// ```
+pub fn temperature_correction_factor(test_temp_c: f64) -> f64 {
+  water_density(test_temp_c) / water_density(20.)
+}
+
+/// The dynamic viscosity of water, in poise (g/(cm·s)), by temperature in °C.
+const WATER_VISCOSITY_TABLE: [(f64, f64); 16] = [
+  (15., 0.01139),
+  (16., 0.01109),
+  (17., 0.01081),
+  (18., 0.01053),
+  (19., 0.01027),
+  (20., 0.01002),
+  (21., 0.00978),
+  (22., 0.00955),
+  (23., 0.00933),
+  (24., 0.00911),
+  (25., 0.00890),
+  (26., 0.00871),
+  (27., 0.00851),
+  (28., 0.00833),
+  (29., 0.00815),
+  (30., 0.00798),
+];
+
+/// The density of air-free water at one atmosphere, in grams per cubic centimetre (g/cm³), by temperature in °C.
+///
+/// From the CRC Handbook of Chemistry and Physics, after Kell (1975), rounded to five decimal places. Water is densest at 4°C.
+const WATER_DENSITY_TABLE: [(f64, f64); 41] = [
+  (0., 0.99984),
+  (1., 0.99990),
+  (2., 0.99994),
+  (3., 0.99996),
+  (4., 0.99997),
+  (5., 0.99996),
+  (6., 0.99994),
+  (7., 0.99990),
+  (8., 0.99985),
+  (9., 0.99978),
+  (10., 0.99970),
+  (11., 0.99961),
+  (12., 0.99950),
+  (13., 0.99938),
+  (14., 0.99924),
+  (15., 0.99910),
+  (16., 0.99894),
+  (17., 0.99877),
+  (18., 0.99860),
+  (19., 0.99841),
+  (20., 0.99821),
+  (21., 0.99799),
+  (22., 0.99777),
+  (23., 0.99754),
+  (24., 0.99730),
+  (25., 0.99705),
+  (26., 0.99679),
+  (27., 0.99652),
+  (28., 0.99624),
+  (29., 0.99595),
+  (30., 0.99565),
+  (31., 0.99534),
+  (32., 0.99503),
+  (33., 0.99470),
+  (34., 0.99437),
+  (35., 0.99403),
+  (36., 0.99368),
+  (37., 0.99333),
+  (38., 0.99297),
+  (39., 0.99259),
+  (40., 0.99222),
+];
+
+/// Get the dynamic viscosity of water, in poise (g/(cm·s)), at a temperature in °C.
+///
+/// Interpolated between the whole degrees of the table, over 15°C to 30°C, and clamped to its nearest end outside it.
+pub fn water_viscosity(temp_c: f64) -> f64 {
+  interpolate(&WATER_VISCOSITY_TABLE, temp_c)
+}
+
+/// Get the density of water, in grams per cubic centimetre (g/cm³), at a temperature in °C.
+///
+/// Numerically equal to the specific gravity of water. Interpolated between the whole degrees of the table, over 0°C to 40°C, and clamped to its nearest end outside it.
+///
+/// # Example
+///
+/// ```
+/// use billios::domain::units::water_density;
+///
+/// assert_eq!(0.99997, water_density(4.));
+/// assert!((water_density(22.5) - 0.997655).abs() < 1e-9);
+/// ```
+pub fn water_density(temp_c: f64) -> f64 {
+  interpolate(&WATER_DENSITY_TABLE, temp_c)
+}
+
+/// Linearly interpolate a value from a table sorted by its first column, clamping to the ends of the table.
+fn interpolate(table: &[(f64, f64)], x: f64) -> f64 {
+  let (first, last) = (table[0], table[table.len() - 1]);
+
+  if x <= first.0 {
+    return first.1;
+  }
+
+  if x >= last.0 {
+    return last.1;
+  }
+
+  table
+    .windows(2)
+    .find(|w| x <= w[1].0)
+    .map(|w| {
+      let ((x0, y0), (x1, y1)) = (w[0], w[1]);
+
+      y0 + (y1 - y0) * (x - x0) / (x1 - x0)
+    })
+    .unwrap_or(f64::NAN)
+}
+
+/// The system of units a measurement is recorded in.
+///
+/// # Example
+///
+/// ```
+/// use billios::domain::units::UnitSystem;
+///
+/// let density = UnitSystem::Metric.to_pcf(2837.5);
+///
+/// assert_eq!(177.1393, (density * 10000.).round() / 10000.);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UnitSystem {
+  /// Weights in pounds (lb) and densities in pounds per cubic foot (pcf). This is the default, and the system used by the calculations.
+  #[default]
+  Imperial,
+  /// Weights in kilograms (kg) and densities in kilograms per cubic meter (kg/m³).
+  Metric,
+}
+
+impl UnitSystem {
+  /// Convert a density in this unit system to pounds per cubic foot (pcf).
+  pub fn to_pcf(&self, density: f64) -> f64 {
+    match self {
+      UnitSystem::Imperial => density,
+      UnitSystem::Metric => kg_m3_to_pcf(density),
+    }
+  }
+
+  /// Convert a density in pounds per cubic foot (pcf) to this unit system.
+  pub fn from_pcf(&self, pcf: f64) -> f64 {
+    match self {
+      UnitSystem::Imperial => pcf,
+      UnitSystem::Metric => pcf_to_kg_m3(pcf),
+    }
+  }
+
+  /// Convert a weight in this unit system to pounds (lb).
+  pub fn to_lb(&self, weight: f64) -> f64 {
+    match self {
+      UnitSystem::Imperial => weight,
+      UnitSystem::Metric => kg_to_lb(weight),
+    }
+  }
+
+  /// Convert a weight in pounds (lb) to this unit system.
+  pub fn from_lb(&self, lb: f64) -> f64 {
+    match self {
+      UnitSystem::Imperial => lb,
+      UnitSystem::Metric => lb_to_kg(lb),
+    }
+  }
+
+  /// The unit label for a density in this unit system.
+  pub fn density_unit(&self) -> &'static str {
+    match self {
+      UnitSystem::Imperial => "pcf",
+      UnitSystem::Metric => "kg/m³",
+    }
+  }
+
+  /// The unit label for a weight in this unit system.
+  pub fn weight_unit(&self) -> &'static str {
+    match self {
+      UnitSystem::Imperial => "lb",
+      UnitSystem::Metric => "kg",
+    }
+  }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const EPSILON: f64 = 1e-9;
+
+  #[test]
+  fn test_density_conversions() {
+    assert!((pcf_to_kg_m3(1.) - 16.018463).abs() < 1e-6);
+    assert!((kg_m3_to_pcf(1000.) - 62.427961).abs() < 1e-6);
+
+    for pcf in [0., 62.4, 135.6, 177.1429] {
+      assert!((kg_m3_to_pcf(pcf_to_kg_m3(pcf)) - pcf).abs() < EPSILON);
+    }
+  }
+
+  #[test]
+  fn test_weight_conversions() {
+    assert_eq!(0.45359237, lb_to_kg(1.));
+    assert!((grams_to_lb(453.59237) - 1.).abs() < EPSILON);
+
+    for lb in [0., 2.31, 4.65, 14.65] {
+      assert!((kg_to_lb(lb_to_kg(lb)) - lb).abs() < EPSILON);
+      assert!((grams_to_lb(lb_to_grams(lb)) - lb).abs() < EPSILON);
+    }
+  }
+
+  #[test]
+  fn test_temperature_correction_factor() {
+    assert_eq!(1., temperature_correction_factor(20.));
+    assert!((temperature_correction_factor(15.) - 1.00089).abs() < 5e-6);
+    assert!((temperature_correction_factor(22.) - 0.99956).abs() < 5e-6);
+    assert!((temperature_correction_factor(30.) - 0.99744).abs() < 5e-6);
+    assert!((temperature_correction_factor(22.5) - 0.99944).abs() < 5e-6);
+
+    // Always the ratio of the water densities.
+    for t in 0..=40 {
+      let t = t as f64;
+      assert_eq!(water_density(t) / water_density(20.), temperature_correction_factor(t));
+    }
+
+    // Clamped outside the water density table.
+    assert_eq!(temperature_correction_factor(0.), temperature_correction_factor(-5.));
+    assert_eq!(temperature_correction_factor(40.), temperature_correction_factor(45.));
+
+    assert!(temperature_correction_factor(f64::NAN).is_nan());
+  }
+
+  #[test]
+  fn test_water_properties() {
+    assert_eq!(0.01002, water_viscosity(20.));
+    assert!((water_viscosity(20.5) - 0.0099).abs() < EPSILON);
+    assert_eq!(0.99821, water_density(20.));
+    assert_eq!(0.99503, water_density(32.));
+    assert!((water_density(10.5) - 0.999655).abs() < EPSILON);
+    assert_eq!(0.99984, water_density(-5.));
+    assert_eq!(0.99222, water_density(45.));
+    assert!(water_density(f64::NAN).is_nan());
+  }
+
+  #[test]
+  fn test_unit_system() {
+    assert_eq!(135.6, UnitSystem::Imperial.to_pcf(135.6));
+    assert_eq!(4.65, UnitSystem::Imperial.from_lb(4.65));
+
+    let pcf = UnitSystem::Metric.to_pcf(2172.1);
+    assert!((UnitSystem::Metric.from_pcf(pcf) - 2172.1).abs() < EPSILON);
+
+    let lb = UnitSystem::Metric.to_lb(2.1);
+    assert!((UnitSystem::Metric.from_lb(lb) - 2.1).abs() < EPSILON);
+
+    assert_eq!("kg/m³", UnitSystem::Metric.density_unit());
+    assert_eq!("lb", UnitSystem::Imperial.weight_unit());
+  }
+}
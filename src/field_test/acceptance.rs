@@ -0,0 +1,163 @@
+//! Acceptance
+use alloc::vec::Vec;
+use crate::domain::errors::CalcError;
+use crate::domain::traits::Rounding;
+use crate::field_test::Compaction;
+use crate::math::float::Float;
+use crate::math::stats;
+use core::fmt;
+
+/// Acceptance criteria for a lot, from the compaction of every test in it.
+///
+/// A lot is accepted when the mean compaction is greater than or equal to `target_mean`, and no single test is below `min_individual`. Each test uses its rounded result from `calculate()`, the same value that is reported, and the mean is rounded to one decimal place, the same as `Compaction`.
+///
+/// An individual test below the minimum rejects the lot whatever the mean, so it governs when both checks fail.
+///
+/// # Example
+///
+/// ```
+/// use billios::field_test::{Acceptance, AcceptanceCriteria, Compaction};
+/// use billios::field_test::types::DryDensityChoice;
+///
+/// let lot = vec![
+///   Compaction::new(DryDensityChoice::Value(130.), 135.6),
+///   Compaction::new(DryDensityChoice::Value(132.), 135.6),
+///   Compaction::new(DryDensityChoice::Value(126.), 135.6),
+/// ];
+///
+/// assert_eq!(Ok(Acceptance::Accepted { mean: 95.4 }), AcceptanceCriteria::new(95., 92.).evaluate(&lot));
+/// assert_eq!(Ok(Acceptance::MeanBelowTarget { mean: 95.4 }), AcceptanceCriteria::new(96., 92.).evaluate(&lot));
+/// assert_eq!(Ok(Acceptance::BelowMinimum { index: 2, compaction: 92.9 }), AcceptanceCriteria::new(95., 93.).evaluate(&lot));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AcceptanceCriteria<T: Float = f64> {
+  target_mean: T,
+  min_individual: T,
+}
+
+impl<T: Float> Rounding<T> for AcceptanceCriteria<T> {}
+
+impl<T: Float> AcceptanceCriteria<T> {
+  /// Create a new instance of `AcceptanceCriteria`.
+  ///
+  /// # Arguments
+  ///
+  /// - `target_mean` - The minimum mean compaction of the lot, in percent, e.g. `95.` for 95%.
+  ///
+  /// - `min_individual` - The minimum compaction of any single test, in percent.
+  ///
+  pub fn new(target_mean: T, min_individual: T) -> Self {
+    Self { target_mean, min_individual }
+  }
+
+  /// Evaluate the lot of compaction `results` against the criteria.
+  ///
+  /// Returns `CalcError::InsufficientData` if `results` is empty, and the first error of a test that cannot be calculated, as the lot cannot be judged without it.
+  pub fn evaluate(&self, results: &[Compaction<T>]) -> Result<Acceptance<T>, CalcError> {
+    let compactions = results.iter().map(|result| result.calculate().map(T::to_f64)).collect::<Result<Vec<f64>, CalcError>>()?;
+
+    let (Some(mean), Some(lowest)) = (stats::mean(&compactions), stats::min(&compactions)) else {
+      return Err(CalcError::InsufficientData);
+    };
+
+    if T::from_f64(lowest) < self.min_individual {
+      let index = compactions.iter().position(|&c| c == lowest).unwrap_or_default();
+
+      return Ok(Acceptance::BelowMinimum { index, compaction: T::from_f64(lowest) });
+    }
+
+    let mean = self.round_n(T::from_f64(mean), 1)?;
+
+    if mean < self.target_mean {
+      return Ok(Acceptance::MeanBelowTarget { mean });
+    }
+
+    Ok(Acceptance::Accepted { mean })
+  }
+
+  /// Getter for `target_mean`
+  pub fn get_target_mean(&self) -> T {
+    self.target_mean
+  }
+
+  /// Getter for `min_individual`
+  pub fn get_min_individual(&self) -> T {
+    self.min_individual
+  }
+}
+
+/// The decision of `AcceptanceCriteria::evaluate()`, with the result that governed it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Acceptance<T: Float = f64> {
+  /// Every test is at or above the minimum, and the `mean` at or above the target.
+  Accepted { mean: T },
+  /// Every test is at or above the minimum, but the `mean` is below the target.
+  MeanBelowTarget { mean: T },
+  /// The lowest test, at `index` in the results, has a `compaction` below the minimum.
+  BelowMinimum { index: usize, compaction: T },
+}
+
+impl<T: Float> Acceptance<T> {
+  /// Whether the lot is accepted.
+  pub fn is_accepted(&self) -> bool {
+    matches!(self, Acceptance::Accepted { .. })
+  }
+}
+
+impl<T: Float> fmt::Display for Acceptance<T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Acceptance::Accepted { mean } => write!(f, "accepted, mean compaction {}%", mean),
+      Acceptance::MeanBelowTarget { mean } => write!(f, "rejected, mean compaction {}% is below the target", mean),
+      Acceptance::BelowMinimum { index, compaction } => write!(f, "rejected, test {} compaction {}% is below the minimum", index, compaction),
+    }
+  }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::field_test::types::DryDensityChoice;
+
+  fn lot(dry_densities: &[f64]) -> Vec<Compaction> {
+    dry_densities.iter().map(|&dd| Compaction::new(DryDensityChoice::Value(dd), 135.6)).collect()
+  }
+
+  #[test]
+  fn test_acceptance_criteria() {
+    let criteria = AcceptanceCriteria::new(95., 92.);
+
+    assert_eq!(95., criteria.get_target_mean());
+    assert_eq!(92., criteria.get_min_individual());
+
+    // The bounds are inclusive.
+    let at_limits = criteria.evaluate(&lot(&[128.8, 128.8])).unwrap();
+    assert_eq!(Acceptance::Accepted { mean: 95. }, at_limits);
+    assert!(at_limits.is_accepted());
+    assert_eq!("accepted, mean compaction 95%", at_limits.to_string());
+
+    let low_mean = criteria.evaluate(&lot(&[128., 128.8])).unwrap();
+    assert_eq!(Acceptance::MeanBelowTarget { mean: 94.7 }, low_mean);
+    assert!(!low_mean.is_accepted());
+
+    // A low test governs even when the mean passes, and the lowest is reported.
+    let low_test = criteria.evaluate(&lot(&[140., 124., 140., 120.])).unwrap();
+    assert_eq!(Acceptance::BelowMinimum { index: 3, compaction: 88.5 }, low_test);
+    assert_eq!("rejected, test 3 compaction 88.5% is below the minimum", low_test.to_string());
+  }
+
+  #[test]
+  fn test_acceptance_criteria_errors() {
+    let criteria = AcceptanceCriteria::new(95., 92.);
+
+    assert_eq!(Err(CalcError::InsufficientData), criteria.evaluate(&[]));
+
+    let mut failing = lot(&[130., 130.]);
+    failing.push(Compaction::new(DryDensityChoice::Value(130.), 0.));
+    assert_eq!(Err(CalcError::DivisionByZero), criteria.evaluate(&failing));
+  }
+}
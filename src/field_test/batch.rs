@@ -0,0 +1,146 @@
+//! Batch
+//!
+//! Perform many calculations in one call, e.g. every test from a spreadsheet export.
+//!
+//! # Example
+//!
+//! ```
+//! use billios::field_test::Compaction;
+//! use billios::field_test::batch::{calculate_all, try_calculate_all};
+//! use billios::field_test::types::DryDensityChoice;
+//!
+//! let tests = vec![
+//!   Compaction::new(DryDensityChoice::Value(155.), 135.6),
+//!   Compaction::new(DryDensityChoice::Value(130.), 135.6),
+//! ];
+//!
+//! assert_eq!(vec![Ok(114.3), Ok(95.9)], calculate_all(&tests));
+//! assert_eq!(Ok(vec![114.3, 95.9]), try_calculate_all(&tests));
+//! ```
+use alloc::vec::Vec;
+use crate::domain::errors::CalcError;
+use crate::domain::traits::Calculate;
+use crate::field_test::Compaction;
+use crate::math::float::Float;
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// Calculate every item, returning each result in the same order as `items`.
+pub fn calculate_all<T: Float, C: Calculate<T>>(items: &[C]) -> Vec<Result<T, CalcError>> {
+  items.iter().map(|item| item.calculate()).collect()
+}
+
+/// Calculate every item, returning the first error if any calculation fails.
+pub fn try_calculate_all<T: Float, C: Calculate<T>>(items: &[C]) -> Result<Vec<T>, CalcError> {
+  items.iter().map(|item| item.calculate()).collect()
+}
+
+/// Rank compaction results from the worst to the best, by their `margin()` above `spec_percent`.
+///
+/// Returns the index of each result in `results` paired with its margin, sorted ascending, so the failing tests come first. A result that cannot be calculated has a margin of `NaN`, and is ranked before all of the others so it is never hidden. Ties keep the order of `results`.
+///
+/// # Example
+///
+/// ```
+/// use billios::field_test::Compaction;
+/// use billios::field_test::batch::rank_by_margin;
+/// use billios::field_test::types::DryDensityChoice;
+///
+/// let tests = vec![
+///   Compaction::new(DryDensityChoice::Value(130.), 135.6),
+///   Compaction::new(DryDensityChoice::Value(124.75), 135.6),
+///   Compaction::new(DryDensityChoice::Value(130.), 0.),
+///   Compaction::new(DryDensityChoice::Value(155.), 135.6),
+/// ];
+///
+/// let ranked = rank_by_margin(&tests, 95_f64);
+///
+/// assert_eq!(vec![2, 1, 0, 3], ranked.iter().map(|&(i, _)| i).collect::<Vec<_>>());
+/// assert!(ranked[0].1.is_nan());
+/// assert_eq!((1, -3.), ranked[1]);
+/// ```
+pub fn rank_by_margin<T: Float>(results: &[Compaction<T>], spec_percent: T) -> Vec<(usize, T)> {
+  let mut ranked: Vec<(usize, Option<T>)> = results
+    .iter()
+    .enumerate()
+    .map(|(i, result)| (i, result.margin(spec_percent).ok().filter(|m| !m.is_nan())))
+    .collect();
+
+  ranked.sort_by(|(_, a), (_, b)| match (a, b) {
+    (Some(a), Some(b)) => a.total_cmp(b),
+    (a, b) => a.is_some().cmp(&b.is_some()),
+  });
+
+  ranked.into_iter().map(|(i, margin)| (i, margin.unwrap_or(T::from_f64(f64::NAN)))).collect()
+}
+
+/// Calculate every item in parallel, returning each result in the same order as `items`.
+///
+/// Requires the `rayon` feature.
+#[cfg(feature = "rayon")]
+pub fn par_calculate_all<T: Float + Send, C: Calculate<T> + Sync>(items: &[C]) -> Vec<Result<T, CalcError>> {
+  items.par_iter().map(|item| item.calculate()).collect()
+}
+
+/// Calculate every item in parallel, returning an error if any calculation fails.
+///
+/// Requires the `rayon` feature.
+#[cfg(feature = "rayon")]
+pub fn try_par_calculate_all<T: Float + Send, C: Calculate<T> + Sync>(items: &[C]) -> Result<Vec<T>, CalcError> {
+  items.par_iter().map(|item| item.calculate()).collect()
+}
+
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::field_test::RockCorrection;
+  use crate::field_test::types::DryDensityChoice;
+
+  fn items() -> Vec<RockCorrection> {
+    vec![RockCorrection::new(100., 500.), RockCorrection::new(100., 0.), RockCorrection::new(50., 500.)]
+  }
+
+  #[test]
+  fn test_calculate_all() {
+    assert_eq!(vec![Ok(0.2), Err(CalcError::DivisionByZero), Ok(0.1)], calculate_all(&items()));
+    assert!(calculate_all::<f64, RockCorrection>(&[]).is_empty());
+  }
+
+  #[test]
+  fn test_try_calculate_all() {
+    assert_eq!(Err(CalcError::DivisionByZero), try_calculate_all(&items()));
+    assert_eq!(Ok(vec![0.2, 0.1]), try_calculate_all(&[RockCorrection::new(100., 500.), RockCorrection::new(50., 500.)]));
+  }
+
+  #[test]
+  fn test_rank_by_margin() {
+    let tests = vec![
+      Compaction::new(DryDensityChoice::Value(130.), 135.6),
+      Compaction::new(DryDensityChoice::Value(f64::NAN), 135.6),
+      Compaction::new(DryDensityChoice::Value(124.75), 135.6),
+      Compaction::new(DryDensityChoice::Value(130.), 0.),
+      Compaction::new(DryDensityChoice::Value(130.), 135.6),
+    ];
+
+    let ranked = rank_by_margin(&tests, 95.);
+
+    // Both failures first, in order, then ascending, with the tie kept in order.
+    assert_eq!(vec![1, 3, 2, 0, 4], ranked.iter().map(|&(i, _)| i).collect::<Vec<_>>());
+    assert!(ranked[0].1.is_nan() && ranked[1].1.is_nan());
+    assert_eq!(vec![-3., 0.9, 0.9], ranked[2..].iter().map(|&(_, m)| m).collect::<Vec<_>>());
+
+    assert!(rank_by_margin::<f64>(&[], 95.).is_empty());
+  }
+
+  #[cfg(feature = "rayon")]
+  #[test]
+  fn test_par_calculate_all() {
+    let many: Vec<RockCorrection> = (1..=1000).map(|i| RockCorrection::new(i as f64, 1000.)).collect();
+
+    assert_eq!(calculate_all(&many), par_calculate_all(&many));
+    assert_eq!(Err(CalcError::DivisionByZero), try_par_calculate_all(&items()));
+  }
+}
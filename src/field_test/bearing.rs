@@ -0,0 +1,320 @@
+//! Bearing
+use alloc::vec;
+use alloc::vec::Vec;
+use crate::domain::errors::{CalcError, ValidationError};
+use crate::domain::traits::{Calculate, Rounding};
+use crate::field_test::types::DryDensityChoice;
+use crate::math::float::{checked_div, Float};
+use super::constants::BEARING_MAX_FRICTION_ANGLE;
+use core::fmt;
+
+/// The shape of a shallow footing, which sets the shape factors of a `BearingCapacity`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FootingShape {
+  /// A continuous strip footing, `qu = c * Nc + q * Nq + 0.5 * γ * B * Nγ`. This is the default.
+  #[default]
+  Strip,
+  /// A square footing, `qu = 1.3 * c * Nc + q * Nq + 0.4 * γ * B * Nγ`.
+  Square,
+  /// A circular footing, with `B` the diameter, `qu = 1.3 * c * Nc + q * Nq + 0.3 * γ * B * Nγ`.
+  Circular,
+}
+
+impl FootingShape {
+  /// The factors applied to the cohesion term and to the unit weight term, `(1.3, 0.4)` for a square footing.
+  pub fn shape_factors(&self) -> (f64, f64) {
+    match self {
+      FootingShape::Strip => (1., 0.5),
+      FootingShape::Square => (1.3, 0.4),
+      FootingShape::Circular => (1.3, 0.3),
+    }
+  }
+}
+
+/// Terzaghi's bearing capacity factors, `(Nc, Nq, Nγ)`, for a friction angle in degrees, unrounded.
+///
+/// `Nq = a² / (2 * cos²(45° + φ / 2))`, with `a = e^((3π / 4 - φ / 2) * tan φ)`, and `Nc = (Nq - 1) * cot φ`, or `1.5π + 1` when `φ` is `0`. Terzaghi gave `Nγ` only as a table, so the closed form approximation `Nγ = 2 * (Nq + 1) * tan φ / (1 + 0.4 * sin 4φ)` is used, which is within a few percent of it.
+///
+/// Returns `CalcError::OutOfRange` if `friction_angle` is negative, or above `BEARING_MAX_FRICTION_ANGLE`.
+///
+/// # Example
+///
+/// ```
+/// use billios::field_test::terzaghi_factors;
+///
+/// let (nc, nq, n_gamma) = terzaghi_factors(30_f64).unwrap();
+///
+/// assert!((nc - 37.16).abs() < 0.01);
+/// assert!((nq - 22.46).abs() < 0.01);
+/// assert!((n_gamma - 20.12).abs() < 0.01);
+/// ```
+pub fn terzaghi_factors<T: Float>(friction_angle: T) -> Result<(T, T, T), CalcError> {
+  if friction_angle.is_nan() || friction_angle < T::ZERO || friction_angle > T::from_f64(BEARING_MAX_FRICTION_ANGLE) {
+    return Err(CalcError::OutOfRange);
+  }
+
+  let pi = T::from_f64(core::f64::consts::PI);
+  let two = T::from_f64(2.);
+  let phi = friction_angle * pi / T::from_f64(180.);
+
+  let a = ((T::from_f64(0.75) * pi - phi / two) * phi.tan()).exp();
+  let nq = a * a / (two * (pi / T::from_f64(4.) + phi / two).cos().powi(2));
+
+  let nc = if phi.abs() < T::EPSILON {
+    T::from_f64(1.5) * pi + T::ONE
+  } else {
+    checked_div(nq - T::ONE, phi.tan())?
+  };
+
+  let n_gamma = checked_div(two * (nq + T::ONE) * phi.tan(), T::ONE + T::from_f64(0.4) * (T::from_f64(4.) * phi).sin())?;
+
+  Ok((nc, nq, n_gamma))
+}
+
+/// Ultimate bearing capacity calculation, for a shallow footing, using Terzaghi's equation.
+///
+/// `qu = c * Nc + q * Nq + 0.5 * γ * B * Nγ` for a strip footing, where the overburden `q = γ * Df`, and the factors are from `terzaghi_factors()`. A square or circular footing uses Terzaghi's shape factors instead, set with `with_shape()`.
+///
+/// The soil above and below the footing is assumed to have the same unit weight, with the water table well below it. Use consistent units, e.g. a cohesion in psf, unit weight in pcf, and depth and width in feet give a capacity in psf.
+///
+/// # Example
+///
+/// ```
+/// use billios::field_test::{BearingCapacity, FootingShape};
+/// use billios::field_test::types::DryDensityChoice;
+///
+/// let bearing_capacity = BearingCapacity::new(200., 30., DryDensityChoice::Value(110.), 3., 4.);
+///
+/// assert_eq!(Ok(19268.), bearing_capacity.calculate());
+/// assert_eq!(Ok(6423.), bearing_capacity.allowable(3.));
+/// assert_eq!(Ok(20613.), bearing_capacity.with_shape(FootingShape::Square).calculate());
+/// ```
+///
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BearingCapacity<T: Float = f64> {
+  cohesion: T,
+  friction_angle: T,
+  unit_weight: DryDensityChoice<T>,
+  depth: T,
+  width: T,
+  shape: FootingShape,
+}
+
+impl<T: Float> Rounding<T> for BearingCapacity<T> {}
+
+impl<T: Float> Calculate<T> for BearingCapacity<T> {
+  fn calculate_raw(&self) -> Result<T, CalcError> {
+    let unit_weight = self.get_unit_weight()?;
+    let (nc, nq, n_gamma) = terzaghi_factors(self.friction_angle)?;
+    let (cohesion_factor, unit_weight_factor) = self.shape.shape_factors();
+
+    let result = T::from_f64(cohesion_factor) * self.cohesion * nc + unit_weight * self.depth * nq + T::from_f64(unit_weight_factor) * unit_weight * self.width * n_gamma;
+
+    Ok(result)
+  }
+
+  fn precision(&self) -> i32 {
+    0
+  }
+
+  fn terms(&self) -> Result<Vec<(&'static str, T)>, CalcError> {
+    let unit_weight = self.get_unit_weight()?;
+    let (nc, nq, n_gamma) = terzaghi_factors(self.friction_angle)?;
+
+    Ok(vec![
+      ("cohesion", self.cohesion),
+      ("friction_angle", self.friction_angle),
+      ("unit_weight", unit_weight),
+      ("depth", self.depth),
+      ("width", self.width),
+      ("overburden", unit_weight * self.depth),
+      ("nc", nc),
+      ("nq", nq),
+      ("n_gamma", n_gamma),
+    ])
+  }
+}
+
+impl<T: Float> BearingCapacity<T> {
+  /// Create a new instance of `BearingCapacity`, for a strip footing.
+  ///
+  /// # Arguments
+  ///
+  /// - `cohesion` - A float that corresponds to the measurement: **Cohesion**, `c`.
+  ///
+  /// - `friction_angle` - A float that corresponds to the measurement: **Friction Angle**, `φ`, in degrees.
+  ///
+  /// - `unit_weight` - A `DryDensityChoice` for the measurement: **Unit Weight** of the soil, `γ`. This offers the ability to use either a value (float), or pass an existing `DryDensity::new()` constructor.
+  ///
+  /// - `depth` - A float that corresponds to the measurement: **Depth** of the footing below the ground, `Df`.
+  ///
+  /// - `width` - A float that corresponds to the measurement: **Width** of the footing, `B`, or the diameter of a circular footing.
+  ///
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn new(cohesion: T, friction_angle: T, unit_weight: DryDensityChoice<T>, depth: T, width: T) -> Self {
+    Self { cohesion, friction_angle, unit_weight, depth, width, shape: FootingShape::Strip }
+  }
+
+  /// Create a new, validated, instance of `BearingCapacity`, for a strip footing.
+  ///
+  /// Returns a `ValidationError` if a value is `NaN`, infinite, or negative. `Constructor` values are not checked, use their own `try_new()` to validate them.
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn try_new(cohesion: T, friction_angle: T, unit_weight: DryDensityChoice<T>, depth: T, width: T) -> Result<Self, ValidationError> {
+    ValidationError::check_non_negative("cohesion", cohesion)?;
+    ValidationError::check_non_negative("friction_angle", friction_angle)?;
+
+    if let DryDensityChoice::Value(v) = unit_weight {
+      ValidationError::check_non_negative("unit_weight", v)?;
+    }
+
+    ValidationError::check_non_negative("depth", depth)?;
+    ValidationError::check_non_negative("width", width)?;
+
+    Ok(Self::new(cohesion, friction_angle, unit_weight, depth, width))
+  }
+
+  /// Calculate the ultimate Bearing Capacity value, rounded to a whole number.
+  ///
+  /// Returns `CalcError::OutOfRange` if `friction_angle` is negative, or above `BEARING_MAX_FRICTION_ANGLE`.
+  ///
+  /// Delegates to the `Calculate` implementation.
+  #[must_use = "the result of a calculation should be used, or its error handled"]
+  pub fn calculate(&self) -> Result<T, CalcError> {
+    Calculate::calculate(self)
+  }
+
+  /// Calculate the allowable bearing capacity, `qa = qu / FS`, rounded to a whole number.
+  ///
+  /// Returns `CalcError::DivisionByZero` when the absolute value of `factor_of_safety` is less than `T::EPSILON`.
+  pub fn allowable(&self, factor_of_safety: T) -> Result<T, CalcError> {
+    self.round_n(checked_div(self.calculate_raw()?, factor_of_safety)?, self.precision())
+  }
+
+  /// Returns a copy of the `BearingCapacity` for a footing of the given `shape`.
+  pub fn with_shape(self, shape: FootingShape) -> Self {
+    Self { shape, ..self }
+  }
+
+  /// Getter for `cohesion`
+  pub fn get_cohesion(&self) -> T {
+    self.cohesion
+  }
+
+  /// Getter for `friction_angle`
+  pub fn get_friction_angle(&self) -> T {
+    self.friction_angle
+  }
+
+  /// Getter for `unit_weight`
+  ///
+  /// A `Constructor` value is calculated without rounding, so any error from its calculation is returned.
+  pub fn get_unit_weight(&self) -> Result<T, CalcError> {
+    self.unit_weight.resolve()
+  }
+
+  /// Getter for `depth`
+  pub fn get_depth(&self) -> T {
+    self.depth
+  }
+
+  /// Getter for `width`
+  pub fn get_width(&self) -> T {
+    self.width
+  }
+
+  /// Getter for `shape`
+  pub fn get_shape(&self) -> FootingShape {
+    self.shape
+  }
+}
+
+impl<T: Float> fmt::Display for BearingCapacity<T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let shape = match self.shape {
+      FootingShape::Strip => "strip",
+      FootingShape::Square => "square",
+      FootingShape::Circular => "circular",
+    };
+
+    write!(f, "Bearing Capacity ({}): c = {}, φ = {}°, B = {}", shape, self.cohesion, self.friction_angle, self.width)?;
+
+    match self.calculate() {
+      Ok(v) => write!(f, " = {}", v),
+      Err(e) => write!(f, " = [{}]", e),
+    }
+  }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::field_test::DryDensity;
+  use crate::field_test::types::{MoistureContentChoice, WetDensityChoice};
+
+  fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 0.01
+  }
+
+  #[test]
+  fn test_terzaghi_factors() {
+    // Terzaghi's table, for Nc and Nq.
+    for (phi, nc, nq) in [(10., 9.61, 2.69), (20., 17.69, 7.44), (30., 37.16, 22.46), (40., 95.66, 81.27)] {
+      let (c, q, _) = terzaghi_factors(phi).unwrap();
+
+      assert!(close(nc, c), "Nc at {}", phi);
+      assert!(close(nq, q), "Nq at {}", phi);
+    }
+
+    let (nc, nq, n_gamma) = terzaghi_factors(0.).unwrap();
+    assert!(close(5.71, nc));
+    assert!(close(1., nq));
+    assert_eq!(0., n_gamma);
+
+    assert_eq!(Err(CalcError::OutOfRange), terzaghi_factors(-1.));
+    assert_eq!(Err(CalcError::OutOfRange), terzaghi_factors(50.1));
+    assert_eq!(Err(CalcError::OutOfRange), terzaghi_factors(f64::NAN));
+  }
+
+  #[test]
+  fn test_bearing_capacity() {
+    let bearing_capacity = BearingCapacity::new(200., 30., DryDensityChoice::Value(110.), 3., 4.);
+
+    assert_eq!(Ok(19268.), bearing_capacity.calculate());
+    assert_eq!(Ok(6423.), bearing_capacity.allowable(3.));
+    assert_eq!(Err(CalcError::DivisionByZero), bearing_capacity.allowable(0.));
+    assert_eq!(FootingShape::Strip, bearing_capacity.get_shape());
+    assert_eq!(Ok(330.), bearing_capacity.calculate_detailed().map(|d| d.get("overburden").unwrap()));
+    assert_eq!("Bearing Capacity (strip): c = 200, φ = 30°, B = 4 = 19268", bearing_capacity.to_string());
+
+    assert_eq!(Ok(20613.), bearing_capacity.with_shape(FootingShape::Square).calculate());
+    assert_eq!(Ok(19728.), bearing_capacity.with_shape(FootingShape::Circular).calculate());
+
+    // A purely cohesive soil only has the cohesion and overburden terms.
+    let clay = BearingCapacity::new(1000., 0., DryDensityChoice::Value(120.), 2., 3.);
+    assert_eq!(Ok(5952.), clay.calculate());
+  }
+
+  #[test]
+  fn test_bearing_capacity_unit_weight() {
+    let dry_density = DryDensity::new(WetDensityChoice::Value(126.5), MoistureContentChoice::Value(0.15));
+    let bearing_capacity = BearingCapacity::new(0., 35., DryDensityChoice::Constructor(dry_density), 3., 4.);
+
+    assert!(close(110., bearing_capacity.get_unit_weight().unwrap()));
+    assert_eq!(BearingCapacity::new(0., 35., DryDensityChoice::Value(110.), 3., 4.).calculate(), bearing_capacity.calculate());
+  }
+
+  #[test]
+  fn test_bearing_capacity_errors() {
+    let steep = BearingCapacity::new(0., 55., DryDensityChoice::Value(110.), 3., 4.);
+    assert_eq!(Err(CalcError::OutOfRange), steep.calculate());
+    assert_eq!("Bearing Capacity (strip): c = 0, φ = 55°, B = 4 = [value is outside the range of the data]", steep.to_string());
+
+    assert!(BearingCapacity::try_new(-200., 30., DryDensityChoice::Value(110.), 3., 4.).is_err());
+    assert!(BearingCapacity::try_new(200., 30., DryDensityChoice::Value(f64::NAN), 3., 4.).is_err());
+    assert!(BearingCapacity::try_new(200., 30., DryDensityChoice::Value(110.), 3., 4.).is_ok());
+  }
+}
@@ -0,0 +1,134 @@
+//! Builders
+use crate::domain::errors::{ValidationError, ValidationErrorKind};
+use crate::field_test::types::{RockCorrectionChoice, SpecificGravityChoice};
+use super::calculations::LabMaxCorrection;
+use crate::math::float::Float;
+
+/// Builder for `LabMaxCorrection`.
+///
+/// Created by `LabMaxCorrection::builder()`. Naming each value makes call sites self-documenting, and avoids mixing up `lab_max` with `specific_gravity`.
+///
+/// # Example
+///
+/// ```
+/// use billios::field_test::LabMaxCorrection;
+/// use billios::field_test::types::RockCorrectionChoice;
+///
+/// let lab_max_correction = LabMaxCorrection::builder()
+///   .rock_correction(RockCorrectionChoice::Value(0.2))
+///   .lab_max(135.6)
+///   .build()
+///   .unwrap();
+///
+/// assert_eq!(Ok(139.7), lab_max_correction.calculate());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LabMaxCorrectionBuilder<T: Float = f64> {
+  rock_correction: Option<RockCorrectionChoice<T>>,
+  lab_max: Option<T>,
+  specific_gravity: Option<SpecificGravityChoice<T>>,
+}
+
+impl<T: Float> Default for LabMaxCorrectionBuilder<T> {
+  fn default() -> Self {
+    Self { rock_correction: None, lab_max: None, specific_gravity: None }
+  }
+}
+
+impl<T: Float> LabMaxCorrectionBuilder<T> {
+  /// Create a new, empty, instance of `LabMaxCorrectionBuilder`.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Setter for `rock_correction`.
+  pub fn rock_correction(mut self, rock_correction: RockCorrectionChoice<T>) -> Self {
+    self.rock_correction = Some(rock_correction);
+    self
+  }
+
+  /// Setter for `lab_max`.
+  pub fn lab_max(mut self, lab_max: T) -> Self {
+    self.lab_max = Some(lab_max);
+    self
+  }
+
+  /// Setter for `specific_gravity`.
+  ///
+  /// Accepts either a value (float) or a `SpecificGravity` constructor. If this is not called, the `SPECIFIC_GRAVITY` constant is used.
+  pub fn specific_gravity(mut self, specific_gravity: impl Into<SpecificGravityChoice<T>>) -> Self {
+    self.specific_gravity = Some(specific_gravity.into());
+    self
+  }
+
+  /// Build the `LabMaxCorrection`.
+  ///
+  /// Returns a `ValidationError` with `ValidationErrorKind::Missing` if `rock_correction` or `lab_max` has not been set.
+  pub fn build(self) -> Result<LabMaxCorrection<T>, ValidationError> {
+    let rock_correction = self.rock_correction.ok_or(ValidationError::new("rock_correction", ValidationErrorKind::Missing))?;
+    let lab_max = self.lab_max.ok_or(ValidationError::new("lab_max", ValidationErrorKind::Missing))?;
+
+    Ok(LabMaxCorrection::new(rock_correction, lab_max, self.specific_gravity))
+  }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::field_test::constants::SPECIFIC_GRAVITY;
+  use crate::field_test::calculations::RockCorrection;
+
+  #[test]
+  fn test_lab_max_correction_builder() {
+    let rock_correction = RockCorrection::new(100., 500.);
+
+    let built = LabMaxCorrection::builder()
+      .rock_correction(RockCorrectionChoice::Constructor(rock_correction))
+      .lab_max(135.6)
+      .specific_gravity(2.65)
+      .build()
+      .unwrap();
+
+    assert_eq!(Ok(0.2), built.get_rock_correction());
+    assert_eq!(135.6, built.get_lab_max());
+    assert_eq!(Ok(2.65), built.get_specific_gravity());
+
+    // `specific_gravity` defaults to the constant when unset.
+    let default = LabMaxCorrection::builder()
+      .lab_max(135.6)
+      .rock_correction(RockCorrectionChoice::Value(0.2))
+      .build()
+      .unwrap();
+
+    assert_eq!(Ok(SPECIFIC_GRAVITY), default.get_specific_gravity());
+    assert_eq!(Ok(139.7), default.calculate());
+  }
+
+  #[test]
+  fn test_to_builder() {
+    let lab_max_correction = LabMaxCorrection::new(RockCorrectionChoice::Constructor(RockCorrection::new(100., 500.)), 135.6, Some(SpecificGravityChoice::Value(2.7)));
+
+    assert_eq!(Ok(lab_max_correction), lab_max_correction.to_builder().build());
+
+    let changed = lab_max_correction.to_builder().lab_max(131.2).build().unwrap();
+
+    assert_eq!(131.2, changed.get_lab_max());
+    assert_eq!(Ok(0.2), changed.get_rock_correction());
+    assert_eq!(Ok(2.7), changed.get_specific_gravity());
+
+    // An unset `specific_gravity` stays unset, so still defaults to the constant.
+    let default = LabMaxCorrection::new(RockCorrectionChoice::Value(0.2), 135.6, None);
+    assert_eq!(Ok(default), default.to_builder().build());
+  }
+
+  #[test]
+  fn test_lab_max_correction_builder_missing() {
+    let missing_lab_max = LabMaxCorrection::builder().rock_correction(RockCorrectionChoice::Value(0.2)).build();
+    assert_eq!(Some(ValidationError::new("lab_max", ValidationErrorKind::Missing)), missing_lab_max.err());
+
+    let missing_rock_correction = LabMaxCorrectionBuilder::new().lab_max(135.6).build();
+    assert_eq!(Some(ValidationError::new("rock_correction", ValidationErrorKind::Missing)), missing_rock_correction.err());
+  }
+}
@@ -1,8 +1,31 @@
 //! Calculations
-use crate::domain::traits::Rounding;
-use crate::field_test::types::{DryDensityChoice, MoistureContentChoice, WetDensityChoice, RockCorrectionChoice};
+use crate::domain::classification::{swell_potential, SwellPotential};
+use crate::domain::errors::{CalcError, RangeWarning, ValidationError};
+use crate::domain::traits::{Calculate, Rounding};
+use crate::domain::types::Percentage;
+use crate::field_test::types::{BulkSpecificGravityChoice, DryDensityChoice, MoistureContentChoice, SandUsedChoice, WetDensityChoice, RockCorrectionChoice, SpecificGravityChoice, VoidRatioChoice};
+use super::builders::LabMaxCorrectionBuilder;
 use super::constants::*;
+use super::profile::CalibrationProfile;
+use crate::math::float::{checked_div, Float};
 // use super::utilities::Rounding;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Displays a calculated value followed by its unit, or the error if the calculation failed.
+struct DisplayResult<T: Float>(Result<T, CalcError>, &'static str);
+
+impl<T: Float> fmt::Display for DisplayResult<T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self.0 {
+      Ok(v) => write!(f, "{}{}", v, self.1),
+      Err(e) => write!(f, "[{}]", e),
+    }
+  }
+}
 
 /// Sand used calculation
 ///
@@ -12,19 +35,49 @@ use super::constants::*;
 /// use billios::field_test::SandUsed;
 ///
 /// let sand_used = SandUsed::new(14.65, 8.75, None);
-/// assert_eq!(2.31, sand_used.calculate());
+/// assert_eq!(Ok(2.31), sand_used.calculate());
+///
+/// let recalibrated = sand_used.with_sand_in_cone(3.6);
+/// assert_eq!(Ok(2.3), recalibrated.calculate());
 /// ```
 ///
-#[derive(Debug, Clone, Copy)]
-pub struct SandUsed {
-  cone_pre_test: f64,
-  cone_post_test: f64,
-  sand_in_cone: Option<f64>,
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SandUsed<T: Float = f64> {
+  cone_pre_test: T,
+  cone_post_test: T,
+  sand_in_cone: Option<T>,
 }
 
-impl Rounding for SandUsed {}
+impl<T: Float> Rounding<T> for SandUsed<T> {}
+
+impl<T: Float> Calculate<T> for SandUsed<T> {
+  fn calculate_raw(&self) -> Result<T, CalcError> {
+    let sand_in_cone = self.get_sand_in_cone();
+
+    let result = self.cone_pre_test - (self.cone_post_test + sand_in_cone);
+
+    Ok(result)
+  }
+
+  fn precision(&self) -> i32 {
+    2
+  }
+
+  fn unit_label(&self) -> &'static str {
+    "lb"
+  }
+
+  fn terms(&self) -> Result<Vec<(&'static str, T)>, CalcError> {
+    Ok(vec![
+      ("cone_pre_test", self.cone_pre_test),
+      ("cone_post_test", self.cone_post_test),
+      ("sand_in_cone", self.get_sand_in_cone()),
+    ])
+  }
+}
 
-impl SandUsed {
+impl<T: Float> SandUsed<T> {
   /// Create a new instance of `SandUsed`.
   ///
   /// # Arguments
@@ -33,105 +86,228 @@ impl SandUsed {
   ///
   /// - `cone_post_test` - A float that corresponds to the measurement: **Cone Post Test**.
   ///
-  /// - `sand_in_cone` - An `Option<f64>` that when `None` defaults to the constant value `SAND_IN_CONE`. This can be modified as needed by setting a `Some()` value.
+  /// - `sand_in_cone` - An `Option<T>` that when `None` defaults to the constant value `SAND_IN_CONE`. This can be modified as needed by setting a `Some()` value.
   ///
-  pub fn new(cone_pre_test: f64, cone_post_test: f64, sand_in_cone: Option<f64>) -> Self {
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn new(cone_pre_test: T, cone_post_test: T, sand_in_cone: Option<T>) -> Self {
     Self { cone_pre_test, cone_post_test, sand_in_cone }
   }
 
-  /// Calculate the Sand Used value.
-  pub fn calculate(&self) -> f64 {
-    let sand_in_cone = self.get_sand_in_cone();
+  /// Create a new instance of `SandUsed`, using the `sand_in_cone` from a `CalibrationProfile`.
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn with_profile(cone_pre_test: T, cone_post_test: T, profile: &CalibrationProfile<T>) -> Self {
+    Self::new(cone_pre_test, cone_post_test, Some(profile.get_sand_in_cone()))
+  }
 
-    let result = self.cone_pre_test - (self.cone_post_test + sand_in_cone);
+  /// Create a new, validated, instance of `SandUsed`.
+  ///
+  /// Returns a `ValidationError` if any value is `NaN`, infinite, or negative.
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn try_new(cone_pre_test: T, cone_post_test: T, sand_in_cone: Option<T>) -> Result<Self, ValidationError> {
+    ValidationError::check_non_negative("cone_pre_test", cone_pre_test)?;
+    ValidationError::check_non_negative("cone_post_test", cone_post_test)?;
+    ValidationError::check_optional_non_negative("sand_in_cone", sand_in_cone)?;
 
-    self.round_n(result, 2).expect("Error rounding the result.")
+    Ok(Self::new(cone_pre_test, cone_post_test, sand_in_cone))
+  }
+
+  /// Calculate the Sand Used value.
+  ///
+  /// Delegates to the `Calculate` implementation.
+  #[must_use = "the result of a calculation should be used, or its error handled"]
+  pub fn calculate(&self) -> Result<T, CalcError> {
+    Calculate::calculate(self)
   }
 
   /// Getter for `cone_pre_test`
-  pub fn get_cone_pre_test(&self) -> f64 {
+  pub fn get_cone_pre_test(&self) -> T {
     self.cone_pre_test
   }
 
   /// Getter for `cone_post_test`
-  pub fn get_cone_post_test(&self) -> f64 {
+  pub fn get_cone_post_test(&self) -> T {
     self.cone_post_test
   }
 
+  /// Returns a copy of the `SandUsed` with `sand_in_cone` set to `value`, in place of the `Option` passed to `new()`.
+  pub fn with_sand_in_cone(self, value: T) -> Self {
+    Self { sand_in_cone: Some(value), ..self }
+  }
+
   /// Getter for `sand_in_cone`
   ///
   /// If no value is provided, the default is set to the `SAND_IN_CONE` constant.
-  fn get_sand_in_cone(&self) -> f64 {
+  pub(crate) fn get_sand_in_cone(&self) -> T {
     match self.sand_in_cone {
       Some(v) => v,
-      None => SAND_IN_CONE,
+      None => T::from_f64(SAND_IN_CONE),
     }
   }
 }
 
+impl<T: Float> fmt::Display for SandUsed<T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "Sand Used: {} - ({} + {}) = {}",
+      self.cone_pre_test, self.cone_post_test, self.get_sand_in_cone(), DisplayResult(self.calculate(), " lb")
+    )
+  }
+}
+
 /// Wet Density calculation
 ///
-/// # Example
+/// # Example 1
+///
+/// An example using `SandUsedChoice::Value()`.
 ///
 /// ```
 /// use billios::field_test::WetDensity;
+/// use billios::field_test::types::SandUsedChoice;
+///
+/// let wet_density = WetDensity::new(4.65, SandUsedChoice::Value(2.31), None);
+/// assert_eq!(Ok(177.1429), wet_density.calculate());
+/// ```
+///
+/// # Example 2
 ///
-/// let wet_density = WetDensity::new(4.65, 2.31, None);
-/// assert_eq!(177.1429, wet_density.calculate());
+/// An example chaining the full calculation from the cone measurements to compaction using `*Choice::Constructor()`.
+///
+/// ```
+/// use billios::field_test::{SandUsed, WetDensity, MoistureContent, DryDensity, Compaction};
+/// use billios::field_test::types::{SandUsedChoice, WetDensityChoice, MoistureContentChoice, DryDensityChoice};
+///
+/// let sand_used = SandUsed::new(14.65, 8.75, None);
+/// let wet_density = WetDensity::new(4.65, SandUsedChoice::Constructor(sand_used), None);
+/// let moisture_content = MoistureContent::new(1600., 1575., 1400.);
+/// let dry_density = DryDensity::new(WetDensityChoice::Constructor(wet_density), MoistureContentChoice::Constructor(moisture_content));
+/// let compaction = Compaction::new(DryDensityChoice::Constructor(dry_density), 135.6);
+///
+/// assert_eq!(Ok(177.1429), wet_density.calculate());
+/// assert_eq!(Ok(114.3), compaction.calculate());
 /// ```
 ///
-#[derive(Debug, Clone, Copy)]
-pub struct WetDensity {
-  soil: f64,
-  sand_used: f64,
-  sand_density: Option<f64>,
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WetDensity<T: Float = f64> {
+  soil: T,
+  sand_used: SandUsedChoice<T>,
+  sand_density: Option<T>,
 }
 
-impl Rounding for WetDensity {}
+impl<T: Float> Rounding<T> for WetDensity<T> {}
+
+impl<T: Float> Calculate<T> for WetDensity<T> {
+  fn calculate_raw(&self) -> Result<T, CalcError> {
+    let sand_used = self.get_sand_used()?;
+    let sand_density = self.get_sand_density();
+
+    let result = checked_div(self.soil, sand_used)? * sand_density;
+
+    Ok(result)
+  }
+
+  fn precision(&self) -> i32 {
+    4
+  }
+
+  fn unit_label(&self) -> &'static str {
+    "pcf"
+  }
+
+  fn terms(&self) -> Result<Vec<(&'static str, T)>, CalcError> {
+    Ok(vec![
+      ("soil", self.soil),
+      ("sand_used", self.get_sand_used()?),
+      ("sand_density", self.get_sand_density()),
+    ])
+  }
+}
 
-impl WetDensity {
+impl<T: Float> WetDensity<T> {
   /// Create a new instance of `WetDensity`.
   ///
   /// # Arguments
   ///
   /// - `soil` - A float that corresponds to the measurement: **Soil**.
   ///
-  /// - `sand_used` - A float that corresponds to the measurement: **Sand Used**
+  /// - `sand_used` - A `SandUsedChoice` for the measurement: **Sand Used**. This offers the ability to use either a value (float), or pass an existing `SandUsed::new()` constructor.
   ///
-  /// - `sand_density` - An `Option<f64>` that when `None` defaults to the constant value `SAND_DENSITY`. This can be modified as needed by setting a `Some()` value.
+  /// - `sand_density` - An `Option<T>` that when `None` defaults to the constant value `SAND_DENSITY`. This can be modified as needed by setting a `Some()` value.
   ///
-  pub fn new(soil: f64, sand_used: f64, sand_density: Option<f64>) -> Self {
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn new(soil: T, sand_used: SandUsedChoice<T>, sand_density: Option<T>) -> Self {
     Self { soil, sand_used, sand_density }
   }
 
-  /// Calculate the Wet Density value.
-  pub fn calculate(&self) -> f64 {
-    let sand_density = self.get_sand_density();
+  /// Create a new instance of `WetDensity`, using the `sand_density` from a `CalibrationProfile`.
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn with_profile(soil: T, sand_used: SandUsedChoice<T>, profile: &CalibrationProfile<T>) -> Self {
+    Self::new(soil, sand_used, Some(profile.get_sand_density()))
+  }
+
+  /// Create a new, validated, instance of `WetDensity`.
+  ///
+  /// Returns a `ValidationError` if a value is `NaN`, infinite, or negative. `Constructor` values are not checked, use their own `try_new()` to validate them.
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn try_new(soil: T, sand_used: SandUsedChoice<T>, sand_density: Option<T>) -> Result<Self, ValidationError> {
+    ValidationError::check_non_negative("soil", soil)?;
+
+    if let SandUsedChoice::Value(v) = sand_used {
+      ValidationError::check_non_negative("sand_used", v)?;
+    }
+
+    ValidationError::check_optional_non_negative("sand_density", sand_density)?;
 
-    let result = (self.soil / self.sand_used) * sand_density;
+    Ok(Self::new(soil, sand_used, sand_density))
+  }
 
-    self.round_n(result, 4).expect("Error rounding the result.")
+  /// Calculate the Wet Density value.
+  ///
+  /// Returns `CalcError::DivisionByZero` when the absolute value of `sand_used` is less than `T::EPSILON` (about `2.22e-16`).
+  ///
+  /// Delegates to the `Calculate` implementation.
+  #[must_use = "the result of a calculation should be used, or its error handled"]
+  pub fn calculate(&self) -> Result<T, CalcError> {
+    Calculate::calculate(self)
   }
 
   /// Getter for `soil`
-  pub fn get_soil(&self) -> f64 {
+  pub fn get_soil(&self) -> T {
     self.soil
   }
 
   /// Getter for `sand_used`
-  pub fn get_sand_used(&self) -> f64 {
-    self.sand_used
+  ///
+  /// A `Constructor` value is calculated without rounding, so any error from its calculation is returned.
+  pub fn get_sand_used(&self) -> Result<T, CalcError> {
+    self.sand_used.resolve()
+  }
+
+  /// Returns a copy of the `WetDensity` with `sand_density` set to `value`, in place of the `Option` passed to `new()`.
+  pub fn with_sand_density(self, value: T) -> Self {
+    Self { sand_density: Some(value), ..self }
   }
 
   /// Getter for `sand_density`
-  pub fn get_sand_density(&self) -> f64 {
+  pub fn get_sand_density(&self) -> T {
     match self.sand_density {
         Some(v) => v,
-        None => SAND_DENSITY,
+        None => T::from_f64(SAND_DENSITY),
     }
   }
 }
 
+impl<T: Float> fmt::Display for WetDensity<T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "Wet Density: ({} / {}) * {} = {}",
+      self.soil, DisplayResult(self.sand_used.resolve_rounded(), ""), self.get_sand_density(), DisplayResult(self.calculate(), " pcf")
+    )
+  }
+}
+
 /// Moisture Content calculation
 ///
 /// # Example
@@ -140,19 +316,47 @@ impl WetDensity {
 /// use billios::field_test::MoistureContent;
 ///
 /// let moisture_content = MoistureContent::new(1600., 1575., 1400.);
-/// assert_eq!(0.14285714, moisture_content.calculate());
+/// assert_eq!(Ok(0.14285714), moisture_content.calculate());
+/// assert_eq!(Ok(14.285714), moisture_content.calculate_percent());
 /// ```
 ///
-#[derive(Debug, Clone, Copy)]
-pub struct MoistureContent {
-  wet_weight: f64,
-  dry_weight: f64,
-  tare_pan: f64,
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MoistureContent<T: Float = f64> {
+  wet_weight: T,
+  dry_weight: T,
+  tare_pan: T,
 }
 
-impl Rounding for MoistureContent {}
+impl<T: Float> Rounding<T> for MoistureContent<T> {}
+
+impl<T: Float> Calculate<T> for MoistureContent<T> {
+  fn calculate_raw(&self) -> Result<T, CalcError> {
+    let dry_soil = self.dry_weight - self.tare_pan;
+
+    let result = checked_div(self.wet_weight - self.dry_weight, dry_soil)?;
+
+    Ok(result)
+  }
+
+  fn precision(&self) -> i32 {
+    8
+  }
+
+  fn unit_label(&self) -> &'static str {
+    "fraction"
+  }
+
+  fn terms(&self) -> Result<Vec<(&'static str, T)>, CalcError> {
+    Ok(vec![
+      ("wet_weight", self.wet_weight),
+      ("dry_weight", self.dry_weight),
+      ("tare_pan", self.tare_pan),
+    ])
+  }
+}
 
-impl MoistureContent {
+impl<T: Float> MoistureContent<T> {
   /// Create new instance of MoistureContent
   ///
   /// # Arguments
@@ -163,33 +367,108 @@ impl MoistureContent {
   ///
   /// - `tare_pan` - A float that corresponds to the measurement: **Tare, Pan **
   ///
-  pub fn new(wet_weight: f64, dry_weight: f64, tare_pan: f64) -> Self {
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn new(wet_weight: T, dry_weight: T, tare_pan: T) -> Self {
     Self { wet_weight, dry_weight, tare_pan }
   }
 
-  // Calculate the Moisture Content value.
-  pub fn calculate(&self) -> f64 {
-    let result = (self.wet_weight - self.dry_weight) / (self.dry_weight - self.tare_pan);
+  /// Create a new, validated, instance of `MoistureContent`.
+  ///
+  /// Returns a `ValidationError` if any value is `NaN`, infinite, or negative.
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn try_new(wet_weight: T, dry_weight: T, tare_pan: T) -> Result<Self, ValidationError> {
+    ValidationError::check_non_negative("wet_weight", wet_weight)?;
+    ValidationError::check_non_negative("dry_weight", dry_weight)?;
+    ValidationError::check_non_negative("tare_pan", tare_pan)?;
+
+    Ok(Self::new(wet_weight, dry_weight, tare_pan))
+  }
+
+  /// Create a new instance of `MoistureContent` from the masses weighed during oven drying, using the last as the dry weight.
+  ///
+  /// The sample is at constant mass when the last two masses differ by no more than `CONSTANT_MASS_TOLERANCE` of the dry soil, `last - tare_pan`.
+  ///
+  /// Returns `CalcError::InsufficientData` if fewer than two masses were given, and `CalcError::NotStabilized` if a mass is more than the one before it, or the sample has not reached constant mass.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use billios::domain::errors::CalcError;
+  /// use billios::field_test::MoistureContent;
+  ///
+  /// let moisture_content = MoistureContent::from_drying_series(1620., &[1420., 1402., 1400.5, 1400.2], 200.).unwrap();
+  ///
+  /// assert_eq!(1400.2, moisture_content.get_dry_weight());
+  /// assert_eq!(Ok(0.18313614), moisture_content.calculate());
+  ///
+  /// // Still losing water.
+  /// assert_eq!(Err(CalcError::NotStabilized), MoistureContent::from_drying_series(1620., &[1420., 1402., 1400.], 200.));
+  /// ```
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn from_drying_series(wet_weight: T, masses: &[T], tare_pan: T) -> Result<Self, CalcError> {
+    let [.., previous, last] = *masses else {
+      return Err(CalcError::InsufficientData);
+    };
+
+    if masses.windows(2).any(|w| w[1] > w[0]) {
+      return Err(CalcError::NotStabilized);
+    }
+
+    if checked_div(previous - last, last - tare_pan)? > T::from_f64(CONSTANT_MASS_TOLERANCE) {
+      return Err(CalcError::NotStabilized);
+    }
+
+    Ok(Self::new(wet_weight, last, tare_pan))
+  }
+
+  /// Calculate the Moisture Content value.
+  ///
+  /// Returns `CalcError::DivisionByZero` when the absolute value of `dry_weight - tare_pan` is less than `T::EPSILON` (about `2.22e-16`).
+  ///
+  /// Delegates to the `Calculate` implementation.
+  #[must_use = "the result of a calculation should be used, or its error handled"]
+  pub fn calculate(&self) -> Result<T, CalcError> {
+    Calculate::calculate(self)
+  }
+
+  /// Calculate the Moisture Content value as a percent, rounded to two fewer decimal places than `calculate()`.
+  ///
+  /// Use `MoistureContentChoice::Percent()` to pass the result on, as the other calculations expect a fraction.
+  pub fn calculate_percent(&self) -> Result<T, CalcError> {
+    self.round_n(self.calculate_raw()? * T::from_f64(100.), self.precision() - 2)
+  }
 
-    self.round_n(result, 8).expect("Error rounding the result.")
+  /// Calculate the Moisture Content value as a `Percentage`, from the fraction of `calculate()`.
+  pub fn percentage(&self) -> Result<Percentage<T>, CalcError> {
+    Ok(Percentage::from_fraction(self.calculate()?))
   }
 
   /// Getter for `wet_weight`
-  pub fn get_wet_weight(&self) -> f64 {
+  pub fn get_wet_weight(&self) -> T {
     self.wet_weight
   }
 
   /// Getter for `dry_weight`
-  pub fn get_dry_weight(&self) -> f64 {
+  pub fn get_dry_weight(&self) -> T {
     self.dry_weight
   }
 
   /// Getter for `tare_pan`
-  pub fn get_tare_pan(&self) -> f64 {
+  pub fn get_tare_pan(&self) -> T {
     self.tare_pan
   }
 }
 
+impl<T: Float> fmt::Display for MoistureContent<T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "Moisture Content: ({} - {}) / ({} - {}) = {}",
+      self.wet_weight, self.dry_weight, self.dry_weight, self.tare_pan, DisplayResult(self.calculate(), "")
+    )
+  }
+}
+
 /// Dry Density calculation
 ///
 /// # Example 1
@@ -202,18 +481,45 @@ impl MoistureContent {
 ///
 /// let dry_density = DryDensity::new(WetDensityChoice::Value(177.1429), MoistureContentChoice::Value(0.1428571));
 ///
-/// assert_eq!(155., dry_density.calculate());
+/// assert_eq!(Ok(155.), dry_density.calculate());
 /// ```
 ///
-#[derive(Debug, Clone, Copy)]
-pub struct DryDensity {
-  wet_density: WetDensityChoice,
-  moisture_content: MoistureContentChoice,
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DryDensity<T: Float = f64> {
+  wet_density: WetDensityChoice<T>,
+  moisture_content: MoistureContentChoice<T>,
 }
 
-impl Rounding for DryDensity {}
+impl<T: Float> Rounding<T> for DryDensity<T> {}
+
+impl<T: Float> Calculate<T> for DryDensity<T> {
+  fn calculate_raw(&self) -> Result<T, CalcError> {
+    let wet_density = self.get_wet_density()?;
+    let moisture_content = self.get_moisture_content()?;
+
+    let result = checked_div(wet_density, T::ONE + moisture_content)?;
+
+    Ok(result)
+  }
+
+  fn precision(&self) -> i32 {
+    0
+  }
+
+  fn unit_label(&self) -> &'static str {
+    "pcf"
+  }
+
+  fn terms(&self) -> Result<Vec<(&'static str, T)>, CalcError> {
+    Ok(vec![
+      ("wet_density", self.get_wet_density()?),
+      ("moisture_content", self.get_moisture_content()?),
+    ])
+  }
+}
 
-impl DryDensity {
+impl<T: Float> DryDensity<T> {
   /// Create a new instance of DryDensity
   ///
   /// # Arguments
@@ -222,34 +528,59 @@ impl DryDensity {
   ///
   /// - `moisture_content` - A `MoistureContentChoice` for the measurement: **Moisture Content**. This offers the ability to use either a value (float), or pass an existing `MoistureContent::new()` constructor.
   ///
-  pub fn new(wet_density: WetDensityChoice, moisture_content: MoistureContentChoice) -> Self {
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn new(wet_density: WetDensityChoice<T>, moisture_content: MoistureContentChoice<T>) -> Self {
     Self { wet_density, moisture_content }
   }
 
-  /// Calculate Dry Density value.
-  pub fn calculate(&self) -> f64 {
-    let wet_density = self.get_wet_density();
-    let moisture_content = self.get_moisture_content();
+  /// Create a new, validated, instance of `DryDensity`.
+  ///
+  /// Returns a `ValidationError` if a `Value` is `NaN`, infinite, or negative. `Constructor` values are not checked, use their own `try_new()` to validate them.
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn try_new(wet_density: WetDensityChoice<T>, moisture_content: MoistureContentChoice<T>) -> Result<Self, ValidationError> {
+    if let WetDensityChoice::Value(v) = wet_density {
+      ValidationError::check_non_negative("wet_density", v)?;
+    }
 
-    let result = wet_density / (1. + moisture_content);
+    if let MoistureContentChoice::Value(v) | MoistureContentChoice::Percent(v) = moisture_content {
+      ValidationError::check_non_negative("moisture_content", v)?;
+    }
 
-    self.round_n(result, 0).expect("Error rounding the result.")
+    Ok(Self::new(wet_density, moisture_content))
   }
 
-  /// Getter for `wet_density`
-  pub fn get_wet_density(&self) -> f64 {
-    match self.wet_density {
-      WetDensityChoice::Value(v) => v,
-      WetDensityChoice::Constructor(v) => v.calculate(),
-    }
+  /// Calculate Dry Density value.
+  ///
+  /// Returns `CalcError::DivisionByZero` when the absolute value of `1 + moisture_content` is less than `T::EPSILON`.
+  ///
+  /// Delegates to the `Calculate` implementation.
+  #[must_use = "the result of a calculation should be used, or its error handled"]
+  pub fn calculate(&self) -> Result<T, CalcError> {
+    Calculate::calculate(self)
   }
 
   /// Getter for `wet_density`
-  pub fn get_moisture_content(&self) -> f64 {
-    match self.moisture_content {
-      MoistureContentChoice::Value(v) => v,
-      MoistureContentChoice::Constructor(v) => v.calculate(),
-    }
+  ///
+  /// A `Constructor` value is calculated without rounding, so any error from its calculation is returned.
+  pub fn get_wet_density(&self) -> Result<T, CalcError> {
+    self.wet_density.resolve()
+  }
+
+  /// Getter for `moisture_content`
+  ///
+  /// A `Constructor` value is calculated without rounding, so any error from its calculation is returned.
+  pub fn get_moisture_content(&self) -> Result<T, CalcError> {
+    self.moisture_content.resolve()
+  }
+}
+
+impl<T: Float> fmt::Display for DryDensity<T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "Dry Density: {} / (1 + {}) = {}",
+      DisplayResult(self.wet_density.resolve_rounded(), ""), DisplayResult(self.moisture_content.resolve_rounded(), ""), DisplayResult(self.calculate(), " pcf")
+    )
   }
 }
 
@@ -264,7 +595,7 @@ impl DryDensity {
 /// use billios::field_test::types::DryDensityChoice;
 ///
 /// let compaction = Compaction::new(DryDensityChoice::Value(155.), 135.6);
-/// assert_eq!(114.3, compaction.calculate());
+/// assert_eq!(Ok(114.3), compaction.calculate());
 ///
 /// ```
 ///
@@ -280,382 +611,3814 @@ impl DryDensity {
 /// let dry_density = DryDensity::new(WetDensityChoice::Value(177.1429), MoistureContentChoice::Value(0.1428571));
 ///
 /// let compaction = Compaction::new(DryDensityChoice::Constructor(dry_density), lab_max);
-/// assert_eq!(114.3, compaction.calculate());
+/// assert_eq!(Ok(114.3), compaction.calculate());
 ///
 /// ```
 ///
-#[derive(Debug, Clone, Copy)]
-pub struct Compaction {
-  dry_density: DryDensityChoice,
-  lab_max: f64,
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Compaction<T: Float = f64> {
+  dry_density: DryDensityChoice<T>,
+  lab_max: T,
 }
 
-impl Rounding for Compaction {}
+impl<T: Float> Rounding<T> for Compaction<T> {}
+
+impl<T: Float> Calculate<T> for Compaction<T> {
+  fn calculate_raw(&self) -> Result<T, CalcError> {
+    let dry_density = self.get_dry_density()?;
+
+    let result = checked_div(dry_density, self.lab_max)? * T::from_f64(100.);
 
-impl Compaction {
+    Ok(result)
+  }
+
+  fn precision(&self) -> i32 {
+    1
+  }
+
+  fn unit_label(&self) -> &'static str {
+    "%"
+  }
+
+  fn terms(&self) -> Result<Vec<(&'static str, T)>, CalcError> {
+    Ok(vec![
+      ("dry_density", self.get_dry_density()?),
+      ("lab_max", self.lab_max),
+    ])
+  }
+}
+
+impl<T: Float> Compaction<T> {
   /// Create new instance of Compaction
-  pub fn new(dry_density: DryDensityChoice, lab_max: f64) -> Self {
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn new(dry_density: DryDensityChoice<T>, lab_max: T) -> Self {
     Self { dry_density, lab_max }
   }
 
+  /// Create a new instance of `Compaction` from the wet density and moisture content, building the `DryDensity` internally.
+  ///
+  /// The same as passing `DryDensityChoice::Constructor(DryDensity::new(wet_density, moisture_content))` to `new()`.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use billios::field_test::Compaction;
+  /// use billios::field_test::types::{MoistureContentChoice, WetDensityChoice};
+  ///
+  /// let compaction = Compaction::from_wet(WetDensityChoice::Value(177.1429), MoistureContentChoice::Percent(14.285714), 135.6);
+  ///
+  /// assert_eq!(Ok(114.3), compaction.calculate());
+  /// ```
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn from_wet(wet_density: WetDensityChoice<T>, moisture_content: MoistureContentChoice<T>, lab_max: T) -> Self {
+    Self::new(DryDensityChoice::Constructor(DryDensity::new(wet_density, moisture_content)), lab_max)
+  }
+
+  /// Create a new, validated, instance of `Compaction`.
+  ///
+  /// Returns a `ValidationError` if a value is `NaN`, infinite, or negative. `Constructor` values are not checked, use their own `try_new()` to validate them.
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn try_new(dry_density: DryDensityChoice<T>, lab_max: T) -> Result<Self, ValidationError> {
+    if let DryDensityChoice::Value(v) = dry_density {
+      ValidationError::check_non_negative("dry_density", v)?;
+    }
+
+    ValidationError::check_non_negative("lab_max", lab_max)?;
+
+    Ok(Self::new(dry_density, lab_max))
+  }
+
   /// Calculate the Compaction value
-  pub fn calculate(&self) -> f64 {
-    let dry_density = self.get_dry_density();
+  ///
+  /// Returns `CalcError::DivisionByZero` when the absolute value of `lab_max` is less than `T::EPSILON`.
+  ///
+  /// Delegates to the `Calculate` implementation.
+  #[must_use = "the result of a calculation should be used, or its error handled"]
+  pub fn calculate(&self) -> Result<T, CalcError> {
+    Calculate::calculate(self)
+  }
+
+  /// Calculate the Compaction value, checking that it is plausible.
+  ///
+  /// Returns `RangeWarning::BelowRange` or `RangeWarning::AboveRange`, with the rounded result, if it is outside `COMPACTION_MIN_PLAUSIBLE` to `COMPACTION_MAX_PLAUSIBLE`. This usually means the inputs were entered incorrectly, e.g. swapped.
+  pub fn calculate_checked(&self) -> Result<T, RangeWarning<T>> {
+    RangeWarning::check(self.calculate()?, T::from_f64(COMPACTION_MIN_PLAUSIBLE), T::from_f64(COMPACTION_MAX_PLAUSIBLE))
+  }
+
+  /// Check the Compaction against a minimum spec, e.g. `95.` for 95%.
+  ///
+  /// The comparison uses the rounded result from `calculate()`, the same value that is reported, and passes when it is greater than or equal to `spec_percent`.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use billios::field_test::Compaction;
+  /// use billios::field_test::types::DryDensityChoice;
+  ///
+  /// let compaction = Compaction::new(DryDensityChoice::Value(128.8), 135.6);
+  ///
+  /// assert_eq!(Ok(95.), compaction.calculate());
+  /// assert_eq!(Ok(true), compaction.is_passing(95.));
+  /// assert_eq!(Ok(false), compaction.is_passing(98.));
+  /// assert_eq!(Ok(-3.), compaction.margin(98.));
+  /// ```
+  pub fn is_passing(&self, spec_percent: T) -> Result<bool, CalcError> {
+    Ok(self.calculate()? >= spec_percent)
+  }
+
+  /// Calculate how far above, (positive), or below, (negative), `spec_percent` the Compaction is.
+  ///
+  /// Uses the rounded result from `calculate()`, and is rounded to one decimal place.
+  pub fn margin(&self, spec_percent: T) -> Result<T, CalcError> {
+    self.round_n(self.calculate()? - spec_percent, 1)
+  }
+
+  /// Calculate the Compaction value as a `Percentage`, from the percent of `calculate()`.
+  pub fn percentage(&self) -> Result<Percentage<T>, CalcError> {
+    Ok(Percentage::from_percent(self.calculate()?))
+  }
 
-    let result = (dry_density / self.lab_max) * 100.;
+  /// Format the Compaction for a report line, flagged against `spec_percent`, e.g. `"114.3% (PASS, +18.7)"` or `"92.0% (FAIL, -3.0)"`.
+  ///
+  /// The label is `PASS` or `FAIL` from `is_passing()`, followed by the `margin()`, which is signed `+` when at or above the spec, and `-` below it. Both numbers are shown to one decimal place.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use billios::field_test::Compaction;
+  /// use billios::field_test::types::DryDensityChoice;
+  ///
+  /// assert_eq!(Ok("114.3% (PASS, +19.3)".to_string()), Compaction::new(DryDensityChoice::Value(155.), 135.6).format_with_spec(95.));
+  /// assert_eq!(Ok("92.0% (FAIL, -3.0)".to_string()), Compaction::new(DryDensityChoice::Value(124.75), 135.6).format_with_spec(95.));
+  /// ```
+  pub fn format_with_spec(&self, spec_percent: T) -> Result<String, CalcError> {
+    let label = if self.is_passing(spec_percent)? { "PASS" } else { "FAIL" };
 
-    self.round_n(result, 1).expect("Error rounding the result.")
+    Ok(format!("{:.1}% ({}, {:+.1})", self.calculate()?, label, self.margin(spec_percent)?))
   }
 
   /// Getter for `dry_density`.
-  pub fn get_dry_density(&self) -> f64 {
-    match self.dry_density {
-      DryDensityChoice::Value(v) => v,
-      DryDensityChoice::Constructor(v) => v.calculate(),
-    }
+  ///
+  /// A `Constructor` value is calculated without rounding, so any error from its calculation is returned.
+  pub fn get_dry_density(&self) -> Result<T, CalcError> {
+    self.dry_density.resolve()
   }
 
   /// Getter for `lab_max`.
-  pub fn get_lab_max(&self) -> f64 {
+  pub fn get_lab_max(&self) -> T {
     self.lab_max
   }
 }
 
-/// Rock Correction (percent oversize) calculation
-///
-/// # Example
+impl<T: Float> fmt::Display for Compaction<T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "Compaction: {} / {} * 100 = {}",
+      DisplayResult(self.dry_density.resolve_rounded(), ""), self.lab_max, DisplayResult(self.calculate(), "%")
+    )
+  }
+}
+
+/// Bulk Specific Gravity calculation
 ///
-/// ```
-/// use billios::field_test::RockCorrection;
+/// The bulk specific gravity of a compacted specimen, e.g. an asphalt core, `Gmb = A / (B - C)`, from its dry mass in air, `A`, its saturated surface-dry (SSD) mass, `B`, and its mass submerged in water, `C`. Any unit of mass can be used, as long as all three are the same.
 ///
-/// let left_on_sieve_weight = 100.;
-/// let pre_sieve_rock_correction = 500.;
+/// The result is the `Gmb` of an `AsphaltCompaction`.
 ///
-/// let rock_correction = RockCorrection::new(left_on_sieve_weight, pre_sieve_rock_correction);
+/// # Example
 ///
-/// assert_eq!(0.2, rock_correction.calculate());
+/// ```
+/// use billios::field_test::BulkSpecificGravity;
 ///
+/// let bulk_specific_gravity = BulkSpecificGravity::new(1200., 1205.3, 695.1);
+/// assert_eq!(Ok(2.352), bulk_specific_gravity.calculate());
 /// ```
-#[derive(Debug, Clone, Copy)]
-pub struct RockCorrection {
-  left_on_sieve_weight: f64,
-  pre_sieve_rock_correction: f64,
+///
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BulkSpecificGravity<T: Float = f64> {
+  dry_mass: T,
+  ssd_mass: T,
+  submerged_mass: T,
 }
 
-impl Rounding for RockCorrection {}
+impl<T: Float> Rounding<T> for BulkSpecificGravity<T> {}
 
-impl RockCorrection {
-  /// Create new instance of RockCorrection.
+impl<T: Float> Calculate<T> for BulkSpecificGravity<T> {
+  fn calculate_raw(&self) -> Result<T, CalcError> {
+    let volume = self.ssd_mass - self.submerged_mass;
+
+    // The SSD mass is always the greater, so a difference that is not positive means the masses were swapped or mistyped.
+    if volume <= T::ZERO {
+      return Err(CalcError::OutOfRange);
+    }
+
+    checked_div(self.dry_mass, volume)
+  }
+
+  fn precision(&self) -> i32 {
+    3
+  }
+
+  fn terms(&self) -> Result<Vec<(&'static str, T)>, CalcError> {
+    Ok(vec![
+      ("dry_mass", self.dry_mass),
+      ("ssd_mass", self.ssd_mass),
+      ("submerged_mass", self.submerged_mass),
+    ])
+  }
+}
+
+impl<T: Float> BulkSpecificGravity<T> {
+  /// Create new instance of BulkSpecificGravity.
   ///
   /// # Arguments
   ///
-  /// `left_on_sieve_weight` - A float that corresponds to the measurement: **Left on Sieve Weight**.
+  /// - `dry_mass` - A float that corresponds to the measurement: **Dry Mass in Air**, `A`.
   ///
-  /// `pre_sieve_rock_correction` - A float that corresponds to the measurement: **Pre Sieve Rock Correction**.
+  /// - `ssd_mass` - A float that corresponds to the measurement: **Saturated Surface-Dry Mass**, `B`.
   ///
-  pub fn new(left_on_sieve_weight: f64, pre_sieve_rock_correction: f64) -> Self {
-    Self { left_on_sieve_weight, pre_sieve_rock_correction }
+  /// - `submerged_mass` - A float that corresponds to the measurement: **Submerged Mass**, `C`, in water.
+  ///
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn new(dry_mass: T, ssd_mass: T, submerged_mass: T) -> Self {
+    Self { dry_mass, ssd_mass, submerged_mass }
   }
 
-  /// Calculate the Rock Correction value.
-  pub fn calculate(&self) -> f64 {
-    let result = self.left_on_sieve_weight / self.pre_sieve_rock_correction;
+  /// Create a new, validated, instance of `BulkSpecificGravity`.
+  ///
+  /// Returns a `ValidationError` if a value is `NaN`, infinite, or negative.
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn try_new(dry_mass: T, ssd_mass: T, submerged_mass: T) -> Result<Self, ValidationError> {
+    ValidationError::check_non_negative("dry_mass", dry_mass)?;
+    ValidationError::check_non_negative("ssd_mass", ssd_mass)?;
+    ValidationError::check_non_negative("submerged_mass", submerged_mass)?;
 
-    self.round_n(result, 1).expect("Error rounding the result.")
+    Ok(Self::new(dry_mass, ssd_mass, submerged_mass))
   }
 
-  /// Getter for `left_on_sieve_weight`.
-  pub fn get_left_on_sieve_weight(&self) -> f64 {
-    self.left_on_sieve_weight
+  /// Calculate the Bulk Specific Gravity value, rounded to three decimal places.
+  ///
+  /// Returns `CalcError::OutOfRange` if `ssd_mass - submerged_mass` is not positive.
+  ///
+  /// Delegates to the `Calculate` implementation.
+  #[must_use = "the result of a calculation should be used, or its error handled"]
+  pub fn calculate(&self) -> Result<T, CalcError> {
+    Calculate::calculate(self)
   }
 
-  /// Getter for `pre_sieve_rock_correction`.
-  pub fn get_pre_sieve_rock_correction(&self) -> f64 {
-    self.pre_sieve_rock_correction
+  /// Getter for `dry_mass`
+  pub fn get_dry_mass(&self) -> T {
+    self.dry_mass
+  }
+
+  /// Getter for `ssd_mass`
+  pub fn get_ssd_mass(&self) -> T {
+    self.ssd_mass
+  }
+
+  /// Getter for `submerged_mass`
+  pub fn get_submerged_mass(&self) -> T {
+    self.submerged_mass
   }
 }
 
-/// Lab Max Correction calculation
+impl<T: Float> fmt::Display for BulkSpecificGravity<T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "Bulk Specific Gravity: {} / ({} - {}) = {}",
+      self.dry_mass, self.ssd_mass, self.submerged_mass, DisplayResult(self.calculate(), "")
+    )
+  }
+}
+
+/// Asphalt Compaction calculation
+///
+/// The degree of compaction of asphalt, as a percent of its theoretical maximum specific gravity, `%Gmm = Gmb / Gmm * 100`. It takes the place of `Compaction` for asphalt, with the bulk specific gravity of a core, `Gmb`, in place of the dry density and the maximum specific gravity of the mix, `Gmm`, in place of the lab max.
 ///
 /// # Example 1
 ///
-/// Example using a `RockCorrectionChoice::Value()`.
+/// An example using `BulkSpecificGravityChoice::Value()`.
 ///
 /// ```
-/// use billios::field_test::LabMaxCorrection;
-/// use billios::field_test::types::RockCorrectionChoice;
-///
-/// let lab_max = 135.6;
-/// let rock_correction = 0.2;
-/// let lab_max_correction = LabMaxCorrection::new(RockCorrectionChoice::Value(rock_correction), lab_max, None);
+/// use billios::field_test::AsphaltCompaction;
+/// use billios::field_test::types::BulkSpecificGravityChoice;
 ///
-/// assert_eq!(139.7, lab_max_correction.calculate());
+/// let compaction = AsphaltCompaction::new(BulkSpecificGravityChoice::Value(2.345), 2.498);
 ///
+/// assert_eq!(Ok(93.9), compaction.calculate());
+/// assert_eq!(Ok(6.1), compaction.air_voids());
+/// assert_eq!(Ok(true), compaction.is_passing(92.));
+/// assert_eq!(Ok(-0.1), compaction.margin(94.));
 /// ```
 ///
 /// # Example 2
 ///
-/// Example using a `RockCorrectionChoice::Constructor()`.
+/// An example using `BulkSpecificGravityChoice::Constructor()`.
 ///
 /// ```
-/// use billios::field_test::{LabMaxCorrection, RockCorrection};
-/// use billios::field_test::types::RockCorrectionChoice;
-///
-/// let lab_max = 135.6;
-/// let left_on_sieve_weight = 100.;
-/// let pre_sieve_rock_correction = 500.;
-/// let rock_correction = RockCorrection::new(left_on_sieve_weight, pre_sieve_rock_correction);
-///
-/// let lab_max_correction = LabMaxCorrection::new(RockCorrectionChoice::Constructor(rock_correction), lab_max, None);
+/// use billios::field_test::{AsphaltCompaction, BulkSpecificGravity};
 ///
-/// assert_eq!(139.7, lab_max_correction.calculate());
+/// let bulk_specific_gravity = BulkSpecificGravity::new(1200., 1205.3, 695.1);
+/// let compaction = AsphaltCompaction::new(bulk_specific_gravity.into(), 2.498);
 ///
+/// assert_eq!(Ok(2.352), bulk_specific_gravity.calculate());
+/// assert_eq!(Ok(94.2), compaction.calculate());
 /// ```
-#[derive(Debug, Clone, Copy)]
-pub struct LabMaxCorrection {
-  rock_correction: RockCorrectionChoice,
-  lab_max: f64,
-  specific_gravity: Option<f64>,
+///
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AsphaltCompaction<T: Float = f64> {
+  bulk_specific_gravity: BulkSpecificGravityChoice<T>,
+  max_specific_gravity: T,
 }
 
-impl Rounding for LabMaxCorrection {}
+impl<T: Float> Rounding<T> for AsphaltCompaction<T> {}
 
-impl LabMaxCorrection {
-  /// Create new instance of LabMaxCorrection.
-  ///
-  /// # Arguments
+impl<T: Float> Calculate<T> for AsphaltCompaction<T> {
+  fn calculate_raw(&self) -> Result<T, CalcError> {
+    let bulk_specific_gravity = self.get_bulk_specific_gravity()?;
+
+    let result = checked_div(bulk_specific_gravity, self.max_specific_gravity)? * T::from_f64(100.);
+
+    Ok(result)
+  }
+
+  fn precision(&self) -> i32 {
+    1
+  }
+
+  fn unit_label(&self) -> &'static str {
+    "%"
+  }
+
+  fn terms(&self) -> Result<Vec<(&'static str, T)>, CalcError> {
+    Ok(vec![
+      ("bulk_specific_gravity", self.get_bulk_specific_gravity()?),
+      ("max_specific_gravity", self.max_specific_gravity),
+    ])
+  }
+}
+
+impl<T: Float> AsphaltCompaction<T> {
+  /// Create new instance of AsphaltCompaction.
+  ///
+  /// # Arguments
+  ///
+  /// - `bulk_specific_gravity` - A `BulkSpecificGravityChoice` for the measurement: **Bulk Specific Gravity**, `Gmb`, of the compacted mix. This offers the ability to use either a value (float), or pass an existing `BulkSpecificGravity::new()` constructor.
+  ///
+  /// - `max_specific_gravity` - A float that corresponds to the measurement: **Theoretical Maximum Specific Gravity**, `Gmm`, of the loose mix.
+  ///
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn new(bulk_specific_gravity: BulkSpecificGravityChoice<T>, max_specific_gravity: T) -> Self {
+    Self { bulk_specific_gravity, max_specific_gravity }
+  }
+
+  /// Create a new, validated, instance of `AsphaltCompaction`.
+  ///
+  /// Returns a `ValidationError` if a value is `NaN`, infinite, or negative. `Constructor` values are not checked, use their own `try_new()` to validate them.
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn try_new(bulk_specific_gravity: BulkSpecificGravityChoice<T>, max_specific_gravity: T) -> Result<Self, ValidationError> {
+    if let BulkSpecificGravityChoice::Value(v) = bulk_specific_gravity {
+      ValidationError::check_non_negative("bulk_specific_gravity", v)?;
+    }
+
+    ValidationError::check_non_negative("max_specific_gravity", max_specific_gravity)?;
+
+    Ok(Self::new(bulk_specific_gravity, max_specific_gravity))
+  }
+
+  /// Calculate the Asphalt Compaction value, as a percent of `Gmm` rounded to one decimal place.
+  ///
+  /// Returns `CalcError::DivisionByZero` when the absolute value of `max_specific_gravity` is less than `T::EPSILON`.
+  ///
+  /// Delegates to the `Calculate` implementation.
+  #[must_use = "the result of a calculation should be used, or its error handled"]
+  pub fn calculate(&self) -> Result<T, CalcError> {
+    Calculate::calculate(self)
+  }
+
+  /// Check the Asphalt Compaction against a minimum spec, e.g. `92.` for 92% of `Gmm`.
+  ///
+  /// The comparison uses the rounded result from `calculate()`, the same as `Compaction::is_passing()`, and passes when it is greater than or equal to `spec_percent`.
+  pub fn is_passing(&self, spec_percent: T) -> Result<bool, CalcError> {
+    Ok(self.calculate()? >= spec_percent)
+  }
+
+  /// Calculate how far above, (positive), or below, (negative), `spec_percent` the Asphalt Compaction is.
+  ///
+  /// Uses the rounded result from `calculate()`, and is rounded to one decimal place.
+  pub fn margin(&self, spec_percent: T) -> Result<T, CalcError> {
+    self.round_n(self.calculate()? - spec_percent, 1)
+  }
+
+  /// Calculate the air voids, `100 - %Gmm`, as a percent, from the rounded result of `calculate()`.
+  ///
+  /// `AirVoids` calculates them from the unrounded ratio instead, so the two can differ in the last decimal place.
+  pub fn air_voids(&self) -> Result<T, CalcError> {
+    self.round_n(T::from_f64(100.) - self.calculate()?, 1)
+  }
+
+  /// Getter for `bulk_specific_gravity`.
+  ///
+  /// A `Constructor` value is calculated without rounding, so any error from its calculation is returned.
+  pub fn get_bulk_specific_gravity(&self) -> Result<T, CalcError> {
+    self.bulk_specific_gravity.resolve()
+  }
+
+  /// Getter for `max_specific_gravity`
+  pub fn get_max_specific_gravity(&self) -> T {
+    self.max_specific_gravity
+  }
+}
+
+impl<T: Float> fmt::Display for AsphaltCompaction<T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "Asphalt Compaction: {} / {} * 100 = {}",
+      DisplayResult(self.bulk_specific_gravity.resolve_rounded(), ""), self.max_specific_gravity, DisplayResult(self.calculate(), "% Gmm")
+    )
+  }
+}
+
+/// Air Voids calculation
+///
+/// The air voids of a compacted asphalt mix, `VA = (1 - Gmb / Gmm) * 100`, as a percent of its total volume, from the bulk specific gravity of the compacted mix, `Gmb`, and the maximum specific gravity of the loose mix, `Gmm`. It is the companion to `AsphaltCompaction`, calculated from the same two values.
+///
+/// `Gmb` can never exceed `Gmm`, so negative air voids mean one of them was measured or recorded wrong. They are returned as calculated rather than as an error, and should be flagged for a retest.
+///
+/// # Example 1
+///
+/// An example using `BulkSpecificGravityChoice::Value()`.
+///
+/// ```
+/// use billios::field_test::AirVoids;
+/// use billios::field_test::types::BulkSpecificGravityChoice;
+///
+/// let air_voids = AirVoids::new(BulkSpecificGravityChoice::Value(2.345), 2.498);
+/// assert_eq!(Ok(6.1), air_voids.calculate());
+///
+/// // A `Gmb` above `Gmm` is a measurement error.
+/// let air_voids = AirVoids::new(BulkSpecificGravityChoice::Value(2.52), 2.498);
+/// assert_eq!(Ok(-0.9), air_voids.calculate());
+/// ```
+///
+/// # Example 2
+///
+/// An example using `BulkSpecificGravityChoice::Constructor()`.
+///
+/// ```
+/// use billios::field_test::{AirVoids, BulkSpecificGravity};
+///
+/// let bulk_specific_gravity = BulkSpecificGravity::new(1200., 1205.3, 695.1);
+/// let air_voids = AirVoids::new(bulk_specific_gravity.into(), 2.498);
+///
+/// assert_eq!(Ok(5.8), air_voids.calculate());
+/// ```
+///
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AirVoids<T: Float = f64> {
+  bulk_specific_gravity: BulkSpecificGravityChoice<T>,
+  max_specific_gravity: T,
+}
+
+impl<T: Float> Rounding<T> for AirVoids<T> {}
+
+impl<T: Float> Calculate<T> for AirVoids<T> {
+  fn calculate_raw(&self) -> Result<T, CalcError> {
+    let bulk_specific_gravity = self.get_bulk_specific_gravity()?;
+
+    let result = (T::ONE - checked_div(bulk_specific_gravity, self.max_specific_gravity)?) * T::from_f64(100.);
+
+    Ok(result)
+  }
+
+  fn precision(&self) -> i32 {
+    1
+  }
+
+  fn unit_label(&self) -> &'static str {
+    "%"
+  }
+
+  fn terms(&self) -> Result<Vec<(&'static str, T)>, CalcError> {
+    Ok(vec![
+      ("bulk_specific_gravity", self.get_bulk_specific_gravity()?),
+      ("max_specific_gravity", self.max_specific_gravity),
+    ])
+  }
+}
+
+impl<T: Float> AirVoids<T> {
+  /// Create new instance of AirVoids.
+  ///
+  /// # Arguments
+  ///
+  /// - `bulk_specific_gravity` - A `BulkSpecificGravityChoice` for the measurement: **Bulk Specific Gravity**, `Gmb`, of the compacted mix. This offers the ability to use either a value (float), or pass an existing `BulkSpecificGravity::new()` constructor.
+  ///
+  /// - `max_specific_gravity` - A float that corresponds to the measurement: **Theoretical Maximum Specific Gravity**, `Gmm`, of the loose mix.
+  ///
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn new(bulk_specific_gravity: BulkSpecificGravityChoice<T>, max_specific_gravity: T) -> Self {
+    Self { bulk_specific_gravity, max_specific_gravity }
+  }
+
+  /// Create a new, validated, instance of `AirVoids`.
+  ///
+  /// Returns a `ValidationError` if a value is `NaN`, infinite, or negative. `Constructor` values are not checked, use their own `try_new()` to validate them.
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn try_new(bulk_specific_gravity: BulkSpecificGravityChoice<T>, max_specific_gravity: T) -> Result<Self, ValidationError> {
+    if let BulkSpecificGravityChoice::Value(v) = bulk_specific_gravity {
+      ValidationError::check_non_negative("bulk_specific_gravity", v)?;
+    }
+
+    ValidationError::check_non_negative("max_specific_gravity", max_specific_gravity)?;
+
+    Ok(Self::new(bulk_specific_gravity, max_specific_gravity))
+  }
+
+  /// Calculate the Air Voids value, as a percent rounded to one decimal place.
+  ///
+  /// Returns `CalcError::DivisionByZero` when the absolute value of `max_specific_gravity` is less than `T::EPSILON`. A negative result is not an error, but means `Gmb` or `Gmm` is wrong.
+  ///
+  /// Delegates to the `Calculate` implementation.
+  #[must_use = "the result of a calculation should be used, or its error handled"]
+  pub fn calculate(&self) -> Result<T, CalcError> {
+    Calculate::calculate(self)
+  }
+
+  /// Getter for `bulk_specific_gravity`.
+  ///
+  /// A `Constructor` value is calculated without rounding, so any error from its calculation is returned.
+  pub fn get_bulk_specific_gravity(&self) -> Result<T, CalcError> {
+    self.bulk_specific_gravity.resolve()
+  }
+
+  /// Getter for `max_specific_gravity`
+  pub fn get_max_specific_gravity(&self) -> T {
+    self.max_specific_gravity
+  }
+}
+
+impl<T: Float> fmt::Display for AirVoids<T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "Air Voids: (1 - {} / {}) * 100 = {}",
+      DisplayResult(self.bulk_specific_gravity.resolve_rounded(), ""), self.max_specific_gravity, DisplayResult(self.calculate(), "%")
+    )
+  }
+}
+
+/// Rock Correction (percent oversize) calculation
+///
+/// The result is the oversize as a fraction (decimal), e.g. `0.2` for 20%, which is the form `LabMaxCorrection` expects. Use `percent()` for the percent.
+///
+/// # Example
+///
+/// ```
+/// use billios::field_test::RockCorrection;
+///
+/// let left_on_sieve_weight = 100.;
+/// let pre_sieve_rock_correction = 500.;
+///
+/// let rock_correction = RockCorrection::new(left_on_sieve_weight, pre_sieve_rock_correction);
+///
+/// assert_eq!(Ok(0.2), rock_correction.calculate());
+/// assert_eq!(Ok(20.), rock_correction.percent());
+///
+/// // A percent oversize that is already known.
+/// assert_eq!(Ok(0.235), RockCorrection::from_percent(23.5).calculate());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RockCorrection<T: Float = f64> {
+  left_on_sieve_weight: T,
+  pre_sieve_rock_correction: T,
+}
+
+impl<T: Float> Rounding<T> for RockCorrection<T> {}
+
+impl<T: Float> Calculate<T> for RockCorrection<T> {
+  fn calculate_raw(&self) -> Result<T, CalcError> {
+    let result = checked_div(self.left_on_sieve_weight, self.pre_sieve_rock_correction)?;
+
+    // More rock than sample, usually swapped inputs, or a negative mass.
+    if !(T::ZERO..=T::ONE).contains(&result) {
+      return Err(CalcError::OutOfRange);
+    }
+
+    Ok(result)
+  }
+
+  fn precision(&self) -> i32 {
+    3
+  }
+
+  fn unit_label(&self) -> &'static str {
+    "fraction"
+  }
+
+  fn terms(&self) -> Result<Vec<(&'static str, T)>, CalcError> {
+    Ok(vec![
+      ("left_on_sieve_weight", self.left_on_sieve_weight),
+      ("pre_sieve_rock_correction", self.pre_sieve_rock_correction),
+    ])
+  }
+}
+
+impl<T: Float> RockCorrection<T> {
+  /// Create new instance of RockCorrection.
+  ///
+  /// # Arguments
+  ///
+  /// `left_on_sieve_weight` - A float that corresponds to the measurement: **Left on Sieve Weight**.
+  ///
+  /// `pre_sieve_rock_correction` - A float that corresponds to the measurement: **Pre Sieve Rock Correction**.
+  ///
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn new(left_on_sieve_weight: T, pre_sieve_rock_correction: T) -> Self {
+    Self { left_on_sieve_weight, pre_sieve_rock_correction }
+  }
+
+  /// Create a new instance of `RockCorrection` from a known percent oversize, e.g. `20.` for 20%.
+  ///
+  /// `calculate()` returns `percent / 100`, the same fraction as from the masses.
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn from_percent(percent: T) -> Self {
+    Self::new(percent, T::from_f64(100.))
+  }
+
+  /// Create a new, validated, instance of `RockCorrection`.
+  ///
+  /// Returns a `ValidationError` if any value is `NaN`, infinite, or negative.
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn try_new(left_on_sieve_weight: T, pre_sieve_rock_correction: T) -> Result<Self, ValidationError> {
+    ValidationError::check_non_negative("left_on_sieve_weight", left_on_sieve_weight)?;
+    ValidationError::check_non_negative("pre_sieve_rock_correction", pre_sieve_rock_correction)?;
+
+    Ok(Self::new(left_on_sieve_weight, pre_sieve_rock_correction))
+  }
+
+  /// Calculate the Rock Correction value, as a fraction (decimal) rounded to three decimal places.
+  ///
+  /// Returns `CalcError::DivisionByZero` when the absolute value of `pre_sieve_rock_correction` is less than `T::EPSILON` (about `2.22e-16`), and `CalcError::OutOfRange` when the fraction is outside `0` to `1`, e.g. if `left_on_sieve_weight` is more than `pre_sieve_rock_correction`. The same error is returned by a `LabMaxCorrection` using it as a `Constructor`.
+  ///
+  /// Delegates to the `Calculate` implementation.
+  #[must_use = "the result of a calculation should be used, or its error handled"]
+  pub fn calculate(&self) -> Result<T, CalcError> {
+    Calculate::calculate(self)
+  }
+
+  /// Calculate the Rock Correction value as a percent, rounded to one decimal place.
+  pub fn percent(&self) -> Result<T, CalcError> {
+    self.round_n(self.calculate_raw()? * T::from_f64(100.), 1)
+  }
+
+  /// Calculate the Rock Correction value as a `Percentage`, from the fraction of `calculate()`.
+  pub fn percentage(&self) -> Result<Percentage<T>, CalcError> {
+    Ok(Percentage::from_fraction(self.calculate()?))
+  }
+
+  /// Getter for `left_on_sieve_weight`.
+  pub fn get_left_on_sieve_weight(&self) -> T {
+    self.left_on_sieve_weight
+  }
+
+  /// Getter for `pre_sieve_rock_correction`.
+  pub fn get_pre_sieve_rock_correction(&self) -> T {
+    self.pre_sieve_rock_correction
+  }
+}
+
+impl<T: Float> fmt::Display for RockCorrection<T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "Rock Correction: {} / {} = {}",
+      self.left_on_sieve_weight, self.pre_sieve_rock_correction, DisplayResult(self.calculate(), "")
+    )
+  }
+}
+
+/// Lab Max Correction calculation
+///
+/// # Example 1
+///
+/// Example using a `RockCorrectionChoice::Value()`.
+///
+/// ```
+/// use billios::field_test::LabMaxCorrection;
+/// use billios::field_test::types::RockCorrectionChoice;
+///
+/// let lab_max = 135.6;
+/// let rock_correction = 0.2;
+/// let lab_max_correction = LabMaxCorrection::new(RockCorrectionChoice::Value(rock_correction), lab_max, None);
+///
+/// assert_eq!(Ok(139.7), lab_max_correction.calculate());
+///
+/// ```
+///
+/// # Example 2
+///
+/// Example using a `RockCorrectionChoice::Constructor()`.
+///
+/// ```
+/// use billios::field_test::{LabMaxCorrection, RockCorrection};
+/// use billios::field_test::types::RockCorrectionChoice;
+///
+/// let lab_max = 135.6;
+/// let left_on_sieve_weight = 100.;
+/// let pre_sieve_rock_correction = 500.;
+/// let rock_correction = RockCorrection::new(left_on_sieve_weight, pre_sieve_rock_correction);
+///
+/// let lab_max_correction = LabMaxCorrection::new(RockCorrectionChoice::Constructor(rock_correction), lab_max, None);
+///
+/// assert_eq!(Ok(139.7), lab_max_correction.calculate());
+///
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LabMaxCorrection<T: Float = f64> {
+  rock_correction: RockCorrectionChoice<T>,
+  lab_max: T,
+  specific_gravity: Option<SpecificGravityChoice<T>>,
+}
+
+impl<T: Float> Rounding<T> for LabMaxCorrection<T> {}
+
+impl<T: Float> Calculate<T> for LabMaxCorrection<T> {
+  fn calculate_raw(&self) -> Result<T, CalcError> {
+    let rock_correction = self.get_rock_correction()?;
+    let specific_gravity = self.get_specific_gravity()?;
+
+    if !rock_correction.is_finite() {
+      return Err(CalcError::NonFinite);
+    }
+
+    // More rock than sample, the same check as `RockCorrection`, for a `Value`.
+    if !(T::ZERO..=T::ONE).contains(&rock_correction) {
+      return Err(CalcError::OutOfRange);
+    }
+
+    let volume = checked_div(rock_correction, T::from_f64(UNIT_WEIGHT_WATER) * specific_gravity)? + checked_div(T::ONE - rock_correction, self.lab_max)?;
+
+    let result = checked_div(T::ONE - T::from_f64(0.05) * rock_correction, volume)?;
+
+    Ok(result)
+  }
+
+  fn precision(&self) -> i32 {
+    1
+  }
+
+  fn unit_label(&self) -> &'static str {
+    "pcf"
+  }
+
+  fn terms(&self) -> Result<Vec<(&'static str, T)>, CalcError> {
+    Ok(vec![
+      ("rock_correction", self.get_rock_correction()?),
+      ("lab_max", self.lab_max),
+      ("specific_gravity", self.get_specific_gravity()?),
+      ("unit_weight_water", T::from_f64(UNIT_WEIGHT_WATER)),
+    ])
+  }
+}
+
+impl<T: Float> LabMaxCorrection<T> {
+  /// Create new instance of LabMaxCorrection.
+  ///
+  /// # Arguments
   ///
-  /// `rock_correction` - A `RockCorrectionChoice` for the measurement: **Rock Correction**. This offers the ability to use either a value (float), or pass an existing constructor (`RockCorrection::new()`).
+  /// `rock_correction` - A `RockCorrectionChoice` for the measurement: **Rock Correction**, as a fraction (decimal), e.g. `0.2` for 20%. This offers the ability to use either a value (float), or pass an existing constructor (`RockCorrection::new()`).
   ///
   /// `lab_max` - A float corresponding to the measurement: **Lab Max**.
   ///
-  /// `specific_gravity` - An `Option<f64>` that when `None` defaults to the constant value `SPECIFIC_GRAVITY`. This can be modified as needed by setting a `Some()` value.
+  /// `specific_gravity` - An `Option<SpecificGravityChoice>` that when `None` defaults to the constant value `SPECIFIC_GRAVITY`. This can be modified as needed by setting a `Some()` value, either a value (float), an existing `SpecificGravity::new()` constructor, or the typical value of a `SoilType`.
   ///
-  pub fn new(rock_correction: RockCorrectionChoice, lab_max: f64, specific_gravity: Option<f64>) -> Self {
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn new(rock_correction: RockCorrectionChoice<T>, lab_max: T, specific_gravity: Option<SpecificGravityChoice<T>>) -> Self {
     Self { rock_correction, lab_max, specific_gravity }
   }
 
-  /// Calculate the Lab Max Correction value.
-  pub fn calculate(&self) -> f64 {
-    let rock_correction = self.get_rock_correction();
-    let specific_gravity = self.get_specific_gravity();
+  /// Create a new instance of `LabMaxCorrection`, using the `specific_gravity` from a `CalibrationProfile`.
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn with_profile(rock_correction: RockCorrectionChoice<T>, lab_max: T, profile: &CalibrationProfile<T>) -> Self {
+    Self::new(rock_correction, lab_max, Some(SpecificGravityChoice::Value(profile.get_specific_gravity())))
+  }
+
+  /// Create a new, validated, instance of `LabMaxCorrection`.
+  ///
+  /// Returns a `ValidationError` if a value is `NaN`, infinite, or negative. `Constructor` values are not checked, use their own `try_new()` to validate them.
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn try_new(rock_correction: RockCorrectionChoice<T>, lab_max: T, specific_gravity: Option<SpecificGravityChoice<T>>) -> Result<Self, ValidationError> {
+    if let RockCorrectionChoice::Value(v) = rock_correction {
+      ValidationError::check_non_negative("rock_correction", v)?;
+    }
+
+    ValidationError::check_non_negative("lab_max", lab_max)?;
+
+    if let Some(SpecificGravityChoice::Value(v)) = specific_gravity {
+      ValidationError::check_non_negative("specific_gravity", v)?;
+    }
+
+    Ok(Self::new(rock_correction, lab_max, specific_gravity))
+  }
+
+  /// Create a `LabMaxCorrectionBuilder`, for setting each value by name.
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn builder() -> LabMaxCorrectionBuilder<T> {
+    LabMaxCorrectionBuilder::new()
+  }
+
+  /// Create a `LabMaxCorrectionBuilder` set to the values of this instance, for changing some of them and building again.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use billios::field_test::LabMaxCorrection;
+  /// use billios::field_test::types::RockCorrectionChoice;
+  ///
+  /// let lab_max_correction = LabMaxCorrection::new(RockCorrectionChoice::Value(0.2), 135.6, None);
+  /// let retested = lab_max_correction.to_builder().lab_max(131.2).build().unwrap();
+  ///
+  /// assert_eq!(Ok(0.2), retested.get_rock_correction());
+  /// assert_eq!(Ok(135.9), retested.calculate());
+  /// ```
+  pub fn to_builder(&self) -> LabMaxCorrectionBuilder<T> {
+    let builder = Self::builder().rock_correction(self.rock_correction).lab_max(self.lab_max);
+
+    match self.specific_gravity {
+      Some(specific_gravity) => builder.specific_gravity(specific_gravity),
+      None => builder,
+    }
+  }
+
+  /// Calculate the Lab Max Correction value.
+  ///
+  /// Returns `CalcError::DivisionByZero` when the absolute value of `lab_max`, or of `specific_gravity * UNIT_WEIGHT_WATER`, is less than `T::EPSILON`, and `CalcError::OutOfRange` when the `rock_correction` is outside `0` to `1`.
+  ///
+  /// Delegates to the `Calculate` implementation.
+  #[must_use = "the result of a calculation should be used, or its error handled"]
+  pub fn calculate(&self) -> Result<T, CalcError> {
+    Calculate::calculate(self)
+  }
+
+  /// Getter for `rock_correction` value.
+  ///
+  /// A `Constructor` value is calculated without rounding, so any error from its calculation is returned.
+  pub fn get_rock_correction(&self) -> Result<T, CalcError> {
+    self.rock_correction.resolve()
+  }
+
+  /// Getter for `lab_max` value.
+  pub fn get_lab_max(&self) -> T {
+    self.lab_max
+  }
+
+  /// Returns a copy of the `LabMaxCorrection` with `specific_gravity` set to `value`, in place of the `Option` passed to `new()`.
+  pub fn with_specific_gravity(self, value: impl Into<SpecificGravityChoice<T>>) -> Self {
+    Self { specific_gravity: Some(value.into()), ..self }
+  }
+
+  /// Getter for `specific_gravity`.
+  ///
+  /// If no value is provided, the default is set to the `SPECIFIC_GRAVITY` constant. A `Constructor` value is calculated without rounding, so any error from its calculation is returned.
+  pub fn get_specific_gravity(&self) -> Result<T, CalcError> {
+    match self.specific_gravity {
+      Some(v) => v.resolve(),
+      None => Ok(T::from_f64(SPECIFIC_GRAVITY)),
+    }
+  }
+}
+
+impl<T: Float> fmt::Display for LabMaxCorrection<T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let rock_correction = DisplayResult(self.rock_correction.resolve_rounded(), "");
+    let specific_gravity = match self.specific_gravity {
+      Some(v) => v.resolve_rounded(),
+      None => Ok(T::from_f64(SPECIFIC_GRAVITY)),
+    };
+
+    write!(
+      f,
+      "Lab Max Correction: (1 - 0.05 * {}) / ({} / ({} * {}) + (1 - {}) / {}) = {}",
+      rock_correction, rock_correction, UNIT_WEIGHT_WATER, DisplayResult(specific_gravity, ""), rock_correction, self.lab_max, DisplayResult(self.calculate(), " pcf")
+    )
+  }
+}
+
+/// Corrected Compaction calculation
+///
+/// The oversize corrected compaction, in one step. `lab_max` is corrected for the `rock_correction` with `LabMaxCorrection`, and the field `dry_density` is compared to the corrected lab max with `Compaction`. The corrected lab max is used unrounded, so the result is only rounded once.
+///
+/// # Example
+///
+/// ```
+/// use billios::field_test::{CorrectedCompaction, RockCorrection};
+/// use billios::field_test::types::{DryDensityChoice, RockCorrectionChoice};
+///
+/// let rock_correction = RockCorrection::new(100., 500.);
+/// let corrected_compaction = CorrectedCompaction::new(DryDensityChoice::Value(133.), 135.6, RockCorrectionChoice::Constructor(rock_correction), None);
+///
+/// assert_eq!(Ok(139.7), corrected_compaction.corrected_lab_max());
+/// assert_eq!(Ok(95.2), corrected_compaction.calculate());
+/// ```
+///
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CorrectedCompaction<T: Float = f64> {
+  dry_density: DryDensityChoice<T>,
+  lab_max: T,
+  rock_correction: RockCorrectionChoice<T>,
+  specific_gravity: Option<SpecificGravityChoice<T>>,
+}
+
+impl<T: Float> Rounding<T> for CorrectedCompaction<T> {}
+
+impl<T: Float> Calculate<T> for CorrectedCompaction<T> {
+  fn calculate_raw(&self) -> Result<T, CalcError> {
+    self.compaction()?.calculate_raw()
+  }
+
+  fn precision(&self) -> i32 {
+    1
+  }
+
+  fn unit_label(&self) -> &'static str {
+    "%"
+  }
+
+  fn terms(&self) -> Result<Vec<(&'static str, T)>, CalcError> {
+    let lab_max_correction = self.lab_max_correction();
+
+    Ok(vec![
+      ("dry_density", self.get_dry_density()?),
+      ("lab_max", self.lab_max),
+      ("rock_correction", lab_max_correction.get_rock_correction()?),
+      ("specific_gravity", lab_max_correction.get_specific_gravity()?),
+      ("corrected_lab_max", lab_max_correction.calculate_raw()?),
+    ])
+  }
+}
+
+impl<T: Float> CorrectedCompaction<T> {
+  /// Create new instance of CorrectedCompaction.
+  ///
+  /// # Arguments
+  ///
+  /// `dry_density` - A `DryDensityChoice` for the measurement: **Dry Density**, of the field test.
+  ///
+  /// `lab_max` - A float corresponding to the measurement: **Lab Max**, before it is corrected.
+  ///
+  /// `rock_correction` - A `RockCorrectionChoice` for the measurement: **Rock Correction**, as a fraction (decimal), e.g. `0.2` for 20%.
+  ///
+  /// `specific_gravity` - An `Option<SpecificGravityChoice>` of the oversize, that when `None` defaults to the constant value `SPECIFIC_GRAVITY`.
+  ///
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn new(dry_density: DryDensityChoice<T>, lab_max: T, rock_correction: RockCorrectionChoice<T>, specific_gravity: Option<SpecificGravityChoice<T>>) -> Self {
+    Self { dry_density, lab_max, rock_correction, specific_gravity }
+  }
+
+  /// Create a new, validated, instance of `CorrectedCompaction`.
+  ///
+  /// Returns a `ValidationError` if a value is `NaN`, infinite, or negative. `Constructor` values are not checked, use their own `try_new()` to validate them.
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn try_new(dry_density: DryDensityChoice<T>, lab_max: T, rock_correction: RockCorrectionChoice<T>, specific_gravity: Option<SpecificGravityChoice<T>>) -> Result<Self, ValidationError> {
+    Compaction::try_new(dry_density, lab_max)?;
+    LabMaxCorrection::try_new(rock_correction, lab_max, specific_gravity)?;
+
+    Ok(Self::new(dry_density, lab_max, rock_correction, specific_gravity))
+  }
+
+  /// Calculate the Corrected Compaction value, as a percent rounded to one decimal place.
+  ///
+  /// Returns any error from `LabMaxCorrection::calculate()`, e.g. `CalcError::OutOfRange` when the `rock_correction` is outside `0` to `1`.
+  ///
+  /// Delegates to the `Calculate` implementation.
+  #[must_use = "the result of a calculation should be used, or its error handled"]
+  pub fn calculate(&self) -> Result<T, CalcError> {
+    Calculate::calculate(self)
+  }
+
+  /// Calculate the corrected lab max, rounded to one decimal place, the same as `LabMaxCorrection::calculate()`.
+  pub fn corrected_lab_max(&self) -> Result<T, CalcError> {
+    self.lab_max_correction().calculate()
+  }
+
+  /// The `LabMaxCorrection` used to correct `lab_max`.
+  pub fn lab_max_correction(&self) -> LabMaxCorrection<T> {
+    LabMaxCorrection::new(self.rock_correction, self.lab_max, self.specific_gravity)
+  }
+
+  /// The `Compaction` of `dry_density` against the unrounded, corrected lab max.
+  ///
+  /// Returns any error from calculating the corrected lab max.
+  pub fn compaction(&self) -> Result<Compaction<T>, CalcError> {
+    Ok(Compaction::new(self.dry_density, self.lab_max_correction().calculate_raw()?))
+  }
+
+  /// Getter for `dry_density`.
+  ///
+  /// A `Constructor` value is calculated without rounding, so any error from its calculation is returned.
+  pub fn get_dry_density(&self) -> Result<T, CalcError> {
+    self.dry_density.resolve()
+  }
+
+  /// Getter for `lab_max`, before it is corrected.
+  pub fn get_lab_max(&self) -> T {
+    self.lab_max
+  }
+
+  /// Getter for `rock_correction`.
+  ///
+  /// A `Constructor` value is calculated without rounding, so any error from its calculation is returned.
+  pub fn get_rock_correction(&self) -> Result<T, CalcError> {
+    self.rock_correction.resolve()
+  }
+
+  /// Getter for `specific_gravity`.
+  ///
+  /// If no value is provided, the default is set to the `SPECIFIC_GRAVITY` constant.
+  pub fn get_specific_gravity(&self) -> Result<T, CalcError> {
+    self.lab_max_correction().get_specific_gravity()
+  }
+}
+
+impl<T: Float> fmt::Display for CorrectedCompaction<T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "Corrected Compaction: {} / {} * 100 = {}",
+      DisplayResult(self.dry_density.resolve_rounded(), ""), DisplayResult(self.corrected_lab_max(), ""), DisplayResult(self.calculate(), "%")
+    )
+  }
+}
+
+/// Interpolated Lab Max calculation
+///
+/// The lab max of fill with a rock content between two tested Proctor curves, interpolated linearly by the rock correction of the field test. Each reference point is a `(rock correction, lab max)` pair, with the rock correction as a fraction (decimal), the same as the result of `RockCorrection`.
+///
+/// The reference points are the ends of the range, so a rock correction outside of it is an error rather than extrapolated.
+///
+/// # Example
+///
+/// ```
+/// use billios::field_test::{Compaction, InterpolatedLabMax, RockCorrection};
+/// use billios::field_test::types::{DryDensityChoice, RockCorrectionChoice};
+///
+/// let rock_correction = RockCorrection::new(105., 500.);
+/// let lab_max = InterpolatedLabMax::new((0.1, 130.), (0.3, 138.), RockCorrectionChoice::Constructor(rock_correction));
+///
+/// assert_eq!(Ok(134.4), lab_max.calculate());
+///
+/// let compaction = Compaction::new(DryDensityChoice::Value(128.), lab_max.calculate().unwrap());
+/// assert_eq!(Ok(95.2), compaction.calculate());
+/// ```
+///
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InterpolatedLabMax<T: Float = f64> {
+  lower: (T, T),
+  upper: (T, T),
+  rock_correction: RockCorrectionChoice<T>,
+}
+
+impl<T: Float> Rounding<T> for InterpolatedLabMax<T> {}
+
+impl<T: Float> Calculate<T> for InterpolatedLabMax<T> {
+  fn calculate_raw(&self) -> Result<T, CalcError> {
+    let rock_correction = self.get_rock_correction()?;
+    let ((x0, y0), (x1, y1)) = (self.lower, self.upper);
+
+    if !rock_correction.is_finite() {
+      return Err(CalcError::NonFinite);
+    }
+
+    if !(T::ZERO..=T::ONE).contains(&rock_correction) {
+      return Err(CalcError::OutOfRange);
+    }
+
+    let within = if x0 <= x1 { x0 <= rock_correction && rock_correction <= x1 } else { x1 <= rock_correction && rock_correction <= x0 };
+
+    if !within {
+      return Err(CalcError::OutOfRange);
+    }
+
+    let result = y0 + (y1 - y0) * checked_div(rock_correction - x0, x1 - x0)?;
+
+    Ok(result)
+  }
+
+  fn precision(&self) -> i32 {
+    1
+  }
+
+  fn unit_label(&self) -> &'static str {
+    "pcf"
+  }
+
+  fn terms(&self) -> Result<Vec<(&'static str, T)>, CalcError> {
+    Ok(vec![
+      ("lower_rock_correction", self.lower.0),
+      ("lower_lab_max", self.lower.1),
+      ("upper_rock_correction", self.upper.0),
+      ("upper_lab_max", self.upper.1),
+      ("rock_correction", self.get_rock_correction()?),
+    ])
+  }
+}
+
+impl<T: Float> InterpolatedLabMax<T> {
+  /// Create new instance of InterpolatedLabMax.
+  ///
+  /// # Arguments
+  ///
+  /// `lower` - The `(rock correction, lab max)` of the Proctor curve with the least rock, with the rock correction as a fraction (decimal).
+  ///
+  /// `upper` - The `(rock correction, lab max)` of the Proctor curve with the most rock.
+  ///
+  /// `rock_correction` - A `RockCorrectionChoice` for the measurement: **Rock Correction**, of the field test, as a fraction (decimal). This offers the ability to use either a value (float), or pass an existing constructor (`RockCorrection::new()`).
+  ///
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn new(lower: (T, T), upper: (T, T), rock_correction: RockCorrectionChoice<T>) -> Self {
+    Self { lower, upper, rock_correction }
+  }
+
+  /// Create a new, validated, instance of `InterpolatedLabMax`.
+  ///
+  /// Returns a `ValidationError` if a value is `NaN`, infinite, or negative. `Constructor` values are not checked, use their own `try_new()` to validate them.
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn try_new(lower: (T, T), upper: (T, T), rock_correction: RockCorrectionChoice<T>) -> Result<Self, ValidationError> {
+    ValidationError::check_non_negative("lower_rock_correction", lower.0)?;
+    ValidationError::check_non_negative("lower_lab_max", lower.1)?;
+    ValidationError::check_non_negative("upper_rock_correction", upper.0)?;
+    ValidationError::check_non_negative("upper_lab_max", upper.1)?;
+
+    if let RockCorrectionChoice::Value(v) = rock_correction {
+      ValidationError::check_non_negative("rock_correction", v)?;
+    }
+
+    Ok(Self::new(lower, upper, rock_correction))
+  }
+
+  /// Calculate the Interpolated Lab Max value, rounded to one decimal place.
+  ///
+  /// Returns `CalcError::OutOfRange` if the `rock_correction` is outside of the reference points, or of `0` to `1`, and `CalcError::DivisionByZero` if the reference points have the same rock correction.
+  ///
+  /// Delegates to the `Calculate` implementation.
+  #[must_use = "the result of a calculation should be used, or its error handled"]
+  pub fn calculate(&self) -> Result<T, CalcError> {
+    Calculate::calculate(self)
+  }
+
+  /// Getter for `lower`
+  pub fn get_lower(&self) -> (T, T) {
+    self.lower
+  }
+
+  /// Getter for `upper`
+  pub fn get_upper(&self) -> (T, T) {
+    self.upper
+  }
+
+  /// Getter for `rock_correction` value.
+  ///
+  /// A `Constructor` value is calculated without rounding, so any error from its calculation is returned.
+  pub fn get_rock_correction(&self) -> Result<T, CalcError> {
+    self.rock_correction.resolve()
+  }
+}
+
+impl<T: Float> fmt::Display for InterpolatedLabMax<T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let ((x0, y0), (x1, y1)) = (self.lower, self.upper);
+
+    write!(
+      f,
+      "Interpolated Lab Max: {} + ({} - {}) * ({} - {}) / ({} - {}) = {}",
+      y0, y1, y0, DisplayResult(self.rock_correction.resolve_rounded(), ""), x0, x1, x0, DisplayResult(self.calculate(), " pcf")
+    )
+  }
+}
+
+/// Plasticity Index calculation
+///
+/// The plasticity index (`PI = LL - PL`) is calculated from the Atterberg limits, with the liquid limit and plastic limit given as percents.
+///
+/// # Example
+///
+/// ```
+/// use billios::field_test::PlasticityIndex;
+/// use billios::field_test::types::MoistureContentChoice;
+///
+/// let plasticity_index = PlasticityIndex::new(45., 22.);
+/// assert_eq!(Ok(23.), plasticity_index.calculate());
+///
+/// let liquidity_index = plasticity_index.liquidity_index(MoistureContentChoice::Value(0.3));
+/// assert_eq!(Ok(0.35), liquidity_index);
+/// ```
+///
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PlasticityIndex<T: Float = f64> {
+  liquid_limit: T,
+  plastic_limit: T,
+}
+
+impl<T: Float> Rounding<T> for PlasticityIndex<T> {}
+
+impl<T: Float> Calculate<T> for PlasticityIndex<T> {
+  fn calculate_raw(&self) -> Result<T, CalcError> {
+    let result = self.liquid_limit - self.plastic_limit;
+
+    Ok(result)
+  }
+
+  fn precision(&self) -> i32 {
+    0
+  }
+
+  fn unit_label(&self) -> &'static str {
+    "%"
+  }
+
+  fn terms(&self) -> Result<Vec<(&'static str, T)>, CalcError> {
+    Ok(vec![
+      ("liquid_limit", self.liquid_limit),
+      ("plastic_limit", self.plastic_limit),
+    ])
+  }
+}
+
+impl<T: Float> PlasticityIndex<T> {
+  /// Create new instance of PlasticityIndex.
+  ///
+  /// # Arguments
+  ///
+  /// - `liquid_limit` - A float that corresponds to the measurement: **Liquid Limit**, as a percent.
+  ///
+  /// - `plastic_limit` - A float that corresponds to the measurement: **Plastic Limit**, as a percent.
+  ///
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn new(liquid_limit: T, plastic_limit: T) -> Self {
+    Self { liquid_limit, plastic_limit }
+  }
+
+  /// Create a new, validated, instance of `PlasticityIndex`.
+  ///
+  /// Returns a `ValidationError` if any value is `NaN`, infinite, or negative.
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn try_new(liquid_limit: T, plastic_limit: T) -> Result<Self, ValidationError> {
+    ValidationError::check_non_negative("liquid_limit", liquid_limit)?;
+    ValidationError::check_non_negative("plastic_limit", plastic_limit)?;
+
+    Ok(Self::new(liquid_limit, plastic_limit))
+  }
+
+  /// Calculate the Plasticity Index value.
+  ///
+  /// Delegates to the `Calculate` implementation.
+  #[must_use = "the result of a calculation should be used, or its error handled"]
+  pub fn calculate(&self) -> Result<T, CalcError> {
+    Calculate::calculate(self)
+  }
+
+  /// Calculate the Liquidity Index, `LI = (w - PL) / PI`, rounded to two decimal places.
+  ///
+  /// The natural moisture content `w` is a fraction, the same as the result of `MoistureContent`, and is converted to a percent to match the limits.
+  ///
+  /// Returns `CalcError::DivisionByZero` when the absolute value of the plasticity index is less than `T::EPSILON`.
+  pub fn liquidity_index(&self, natural_moisture: MoistureContentChoice<T>) -> Result<T, CalcError> {
+    let natural_moisture = natural_moisture.resolve()?;
+
+    let plasticity_index = self.liquid_limit - self.plastic_limit;
+
+    let result = checked_div(natural_moisture * T::from_f64(100.) - self.plastic_limit, plasticity_index)?;
+
+    self.round_n(result, 2)
+  }
+
+  /// Screen the swell potential of the soil with `classification::swell_potential()`, from the rounded plasticity index and `percent_fines`.
+  pub fn swell_potential(&self, percent_fines: T) -> Result<SwellPotential, CalcError> {
+    Ok(swell_potential(self.calculate()?.to_f64(), percent_fines.to_f64()))
+  }
+
+  /// Getter for `liquid_limit`.
+  pub fn get_liquid_limit(&self) -> T {
+    self.liquid_limit
+  }
+
+  /// Getter for `plastic_limit`.
+  pub fn get_plastic_limit(&self) -> T {
+    self.plastic_limit
+  }
+}
+
+impl<T: Float> fmt::Display for PlasticityIndex<T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "Plasticity Index: {} - {} = {}",
+      self.liquid_limit, self.plastic_limit, DisplayResult(self.calculate(), "")
+    )
+  }
+}
+
+/// Shrinkage Limit calculation
+///
+/// The shrinkage limit (`SL = (w - (V - Vf) * ρw / Ws) * 100`) is the moisture content, as a percent, below which the soil no longer shrinks as it dries. The volumes are in cm³ and the dry mass in grams, so the density of water is taken as `WATER_DENSITY`.
+///
+/// # Example
+///
+/// ```
+/// use billios::field_test::ShrinkageLimit;
+/// use billios::field_test::types::MoistureContentChoice;
+///
+/// let shrinkage_limit = ShrinkageLimit::new(MoistureContentChoice::Value(0.35), 16.2, 10.8, 21.6);
+/// assert_eq!(Ok(10.), shrinkage_limit.calculate());
+/// ```
+///
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ShrinkageLimit<T: Float = f64> {
+  moisture_content: MoistureContentChoice<T>,
+  initial_volume: T,
+  dry_volume: T,
+  dry_soil: T,
+}
+
+impl<T: Float> Rounding<T> for ShrinkageLimit<T> {}
+
+impl<T: Float> Calculate<T> for ShrinkageLimit<T> {
+  fn calculate_raw(&self) -> Result<T, CalcError> {
+    let moisture_content = self.moisture_content.resolve()?;
+
+    let result = (moisture_content - checked_div((self.initial_volume - self.dry_volume) * T::from_f64(WATER_DENSITY), self.dry_soil)?) * T::from_f64(100.);
+
+    Ok(result)
+  }
+
+  fn precision(&self) -> i32 {
+    2
+  }
+
+  fn unit_label(&self) -> &'static str {
+    "%"
+  }
+
+  fn terms(&self) -> Result<Vec<(&'static str, T)>, CalcError> {
+    Ok(vec![
+      ("moisture_content", self.moisture_content.resolve()?),
+      ("initial_volume", self.initial_volume),
+      ("dry_volume", self.dry_volume),
+      ("dry_soil", self.dry_soil),
+    ])
+  }
+}
+
+impl<T: Float> ShrinkageLimit<T> {
+  /// Create new instance of ShrinkageLimit.
+  ///
+  /// # Arguments
+  ///
+  /// - `moisture_content` - A `MoistureContentChoice` for the measurement: **Moisture Content** of the soil pat when placed in the dish. This offers the ability to use either a value (float), or pass an existing `MoistureContent::new()` constructor.
+  ///
+  /// - `initial_volume` - A float that corresponds to the measurement: **Initial Volume** of the wet soil pat, in cm³.
+  ///
+  /// - `dry_volume` - A float that corresponds to the measurement: **Dry Volume** of the oven-dried soil pat, in cm³.
+  ///
+  /// - `dry_soil` - A float that corresponds to the measurement: **Dry Soil**, the mass of the oven-dried soil pat, in grams.
+  ///
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn new(moisture_content: MoistureContentChoice<T>, initial_volume: T, dry_volume: T, dry_soil: T) -> Self {
+    Self { moisture_content, initial_volume, dry_volume, dry_soil }
+  }
+
+  /// Create a new, validated, instance of `ShrinkageLimit`.
+  ///
+  /// Returns a `ValidationError` if a value is `NaN`, infinite, or negative. `Constructor` values are not checked, use their own `try_new()` to validate them.
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn try_new(moisture_content: MoistureContentChoice<T>, initial_volume: T, dry_volume: T, dry_soil: T) -> Result<Self, ValidationError> {
+    if let MoistureContentChoice::Value(v) | MoistureContentChoice::Percent(v) = moisture_content {
+      ValidationError::check_non_negative("moisture_content", v)?;
+    }
+
+    ValidationError::check_non_negative("initial_volume", initial_volume)?;
+    ValidationError::check_non_negative("dry_volume", dry_volume)?;
+    ValidationError::check_non_negative("dry_soil", dry_soil)?;
+
+    Ok(Self::new(moisture_content, initial_volume, dry_volume, dry_soil))
+  }
+
+  /// Calculate the Shrinkage Limit value, as a percent.
+  ///
+  /// Returns `CalcError::DivisionByZero` when the absolute value of `dry_soil` is less than `T::EPSILON`.
+  ///
+  /// Delegates to the `Calculate` implementation.
+  #[must_use = "the result of a calculation should be used, or its error handled"]
+  pub fn calculate(&self) -> Result<T, CalcError> {
+    Calculate::calculate(self)
+  }
+
+  /// Getter for `moisture_content`
+  pub fn get_moisture_content(&self) -> MoistureContentChoice<T> {
+    self.moisture_content
+  }
+
+  /// Getter for `initial_volume`
+  pub fn get_initial_volume(&self) -> T {
+    self.initial_volume
+  }
+
+  /// Getter for `dry_volume`
+  pub fn get_dry_volume(&self) -> T {
+    self.dry_volume
+  }
+
+  /// Getter for `dry_soil`
+  pub fn get_dry_soil(&self) -> T {
+    self.dry_soil
+  }
+}
+
+impl<T: Float> fmt::Display for ShrinkageLimit<T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "Shrinkage Limit: ({} - ({} - {}) / {}) * 100 = {}",
+      DisplayResult(self.moisture_content.resolve_rounded(), ""), self.initial_volume, self.dry_volume, self.dry_soil, DisplayResult(self.calculate(), "")
+    )
+  }
+}
+
+/// Void Ratio calculation
+///
+/// The void ratio, `e = (Gs * γw / γd) - 1`, where `γw` is the `UNIT_WEIGHT_WATER` constant.
+///
+/// # Example
+///
+/// ```
+/// use billios::field_test::VoidRatio;
+/// use billios::field_test::types::DryDensityChoice;
+///
+/// let void_ratio = VoidRatio::new(DryDensityChoice::Value(110.), None);
+/// assert_eq!(Ok(0.532), void_ratio.calculate());
+/// ```
+///
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VoidRatio<T: Float = f64> {
+  dry_density: DryDensityChoice<T>,
+  specific_gravity: Option<T>,
+}
+
+impl<T: Float> Rounding<T> for VoidRatio<T> {}
+
+impl<T: Float> Calculate<T> for VoidRatio<T> {
+  fn calculate_raw(&self) -> Result<T, CalcError> {
+    let dry_density = self.get_dry_density()?;
+    let specific_gravity = self.get_specific_gravity();
+
+    let result = checked_div(specific_gravity * T::from_f64(UNIT_WEIGHT_WATER), dry_density)? - T::ONE;
+
+    Ok(result)
+  }
+
+  fn precision(&self) -> i32 {
+    3
+  }
+
+  fn terms(&self) -> Result<Vec<(&'static str, T)>, CalcError> {
+    Ok(vec![
+      ("dry_density", self.get_dry_density()?),
+      ("specific_gravity", self.get_specific_gravity()),
+      ("unit_weight_water", T::from_f64(UNIT_WEIGHT_WATER)),
+    ])
+  }
+}
+
+impl<T: Float> VoidRatio<T> {
+  /// Create new instance of VoidRatio.
+  ///
+  /// # Arguments
+  ///
+  /// - `dry_density` - A `DryDensityChoice` for the measurement: **Dry Density**. This offers the ability to use either a value (float), or pass an existing `DryDensity::new()` constructor.
+  ///
+  /// - `specific_gravity` - An `Option<T>` that when `None` defaults to the constant value `SPECIFIC_GRAVITY`. This can be modified as needed by setting a `Some()` value.
+  ///
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn new(dry_density: DryDensityChoice<T>, specific_gravity: Option<T>) -> Self {
+    Self { dry_density, specific_gravity }
+  }
+
+  /// Create a new instance of `VoidRatio`, using the `specific_gravity` from a `CalibrationProfile`.
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn with_profile(dry_density: DryDensityChoice<T>, profile: &CalibrationProfile<T>) -> Self {
+    Self::new(dry_density, Some(profile.get_specific_gravity()))
+  }
+
+  /// Create a new, validated, instance of `VoidRatio`.
+  ///
+  /// Returns a `ValidationError` if a value is `NaN`, infinite, or negative. `Constructor` values are not checked, use their own `try_new()` to validate them.
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn try_new(dry_density: DryDensityChoice<T>, specific_gravity: Option<T>) -> Result<Self, ValidationError> {
+    if let DryDensityChoice::Value(v) = dry_density {
+      ValidationError::check_non_negative("dry_density", v)?;
+    }
+
+    ValidationError::check_optional_non_negative("specific_gravity", specific_gravity)?;
+
+    Ok(Self::new(dry_density, specific_gravity))
+  }
+
+  /// Calculate the Void Ratio value.
+  ///
+  /// Returns `CalcError::DivisionByZero` when the absolute value of `dry_density` is less than `T::EPSILON`.
+  ///
+  /// Delegates to the `Calculate` implementation.
+  #[must_use = "the result of a calculation should be used, or its error handled"]
+  pub fn calculate(&self) -> Result<T, CalcError> {
+    Calculate::calculate(self)
+  }
+
+  /// Getter for `dry_density`.
+  ///
+  /// A `Constructor` value is calculated without rounding, so any error from its calculation is returned.
+  pub fn get_dry_density(&self) -> Result<T, CalcError> {
+    self.dry_density.resolve()
+  }
+
+  /// Returns a copy of the `VoidRatio` with `specific_gravity` set to `value`, in place of the `Option` passed to `new()`.
+  pub fn with_specific_gravity(self, value: T) -> Self {
+    Self { specific_gravity: Some(value), ..self }
+  }
+
+  /// Getter for `specific_gravity`.
+  ///
+  /// If no value is provided, the default is set to the `SPECIFIC_GRAVITY` constant.
+  pub fn get_specific_gravity(&self) -> T {
+    match self.specific_gravity {
+      Some(v) => v,
+      None => T::from_f64(SPECIFIC_GRAVITY),
+    }
+  }
+}
+
+impl<T: Float> fmt::Display for VoidRatio<T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "Void Ratio: ({} * {} / {}) - 1 = {}",
+      self.get_specific_gravity(), UNIT_WEIGHT_WATER, DisplayResult(self.dry_density.resolve_rounded(), ""), DisplayResult(self.calculate(), "")
+    )
+  }
+}
+
+/// Porosity calculation
+///
+/// The porosity, `n = e / (1 + e)`, calculated from the same inputs as `VoidRatio`.
+///
+/// # Example
+///
+/// ```
+/// use billios::field_test::Porosity;
+/// use billios::field_test::types::DryDensityChoice;
+///
+/// let porosity = Porosity::new(DryDensityChoice::Value(110.), None);
+/// assert_eq!(Ok(0.347), porosity.calculate());
+/// ```
+///
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Porosity<T: Float = f64> {
+  dry_density: DryDensityChoice<T>,
+  specific_gravity: Option<T>,
+}
+
+impl<T: Float> Rounding<T> for Porosity<T> {}
+
+impl<T: Float> Calculate<T> for Porosity<T> {
+  fn calculate_raw(&self) -> Result<T, CalcError> {
+    let dry_density = self.get_dry_density()?;
+    let specific_gravity = self.get_specific_gravity();
+
+    let solids = specific_gravity * T::from_f64(UNIT_WEIGHT_WATER);
+
+    // Equivalent to `e / (1 + e)`, without rounding the void ratio first.
+    let result = T::ONE - checked_div(dry_density, solids)?;
+
+    Ok(result)
+  }
+
+  fn precision(&self) -> i32 {
+    3
+  }
+
+  fn unit_label(&self) -> &'static str {
+    "fraction"
+  }
+
+  fn terms(&self) -> Result<Vec<(&'static str, T)>, CalcError> {
+    Ok(vec![
+      ("dry_density", self.get_dry_density()?),
+      ("specific_gravity", self.get_specific_gravity()),
+      ("unit_weight_water", T::from_f64(UNIT_WEIGHT_WATER)),
+    ])
+  }
+}
+
+impl<T: Float> Porosity<T> {
+  /// Create new instance of Porosity.
+  ///
+  /// # Arguments
+  ///
+  /// - `dry_density` - A `DryDensityChoice` for the measurement: **Dry Density**. This offers the ability to use either a value (float), or pass an existing `DryDensity::new()` constructor.
+  ///
+  /// - `specific_gravity` - An `Option<T>` that when `None` defaults to the constant value `SPECIFIC_GRAVITY`. This can be modified as needed by setting a `Some()` value.
+  ///
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn new(dry_density: DryDensityChoice<T>, specific_gravity: Option<T>) -> Self {
+    Self { dry_density, specific_gravity }
+  }
+
+  /// Create a new instance of `Porosity`, using the `specific_gravity` from a `CalibrationProfile`.
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn with_profile(dry_density: DryDensityChoice<T>, profile: &CalibrationProfile<T>) -> Self {
+    Self::new(dry_density, Some(profile.get_specific_gravity()))
+  }
+
+  /// Create a new, validated, instance of `Porosity`.
+  ///
+  /// Returns a `ValidationError` if a value is `NaN`, infinite, or negative. `Constructor` values are not checked, use their own `try_new()` to validate them.
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn try_new(dry_density: DryDensityChoice<T>, specific_gravity: Option<T>) -> Result<Self, ValidationError> {
+    if let DryDensityChoice::Value(v) = dry_density {
+      ValidationError::check_non_negative("dry_density", v)?;
+    }
+
+    ValidationError::check_optional_non_negative("specific_gravity", specific_gravity)?;
+
+    Ok(Self::new(dry_density, specific_gravity))
+  }
+
+  /// Calculate the Porosity value.
+  ///
+  /// Returns `CalcError::DivisionByZero` when the absolute value of `specific_gravity` is less than `T::EPSILON`.
+  ///
+  /// Delegates to the `Calculate` implementation.
+  #[must_use = "the result of a calculation should be used, or its error handled"]
+  pub fn calculate(&self) -> Result<T, CalcError> {
+    Calculate::calculate(self)
+  }
+
+  /// Getter for `dry_density`.
+  ///
+  /// A `Constructor` value is calculated without rounding, so any error from its calculation is returned.
+  pub fn get_dry_density(&self) -> Result<T, CalcError> {
+    self.dry_density.resolve()
+  }
+
+  /// Returns a copy of the `Porosity` with `specific_gravity` set to `value`, in place of the `Option` passed to `new()`.
+  pub fn with_specific_gravity(self, value: T) -> Self {
+    Self { specific_gravity: Some(value), ..self }
+  }
+
+  /// Getter for `specific_gravity`.
+  ///
+  /// If no value is provided, the default is set to the `SPECIFIC_GRAVITY` constant.
+  pub fn get_specific_gravity(&self) -> T {
+    match self.specific_gravity {
+      Some(v) => v,
+      None => T::from_f64(SPECIFIC_GRAVITY),
+    }
+  }
+}
+
+impl<T: Float> fmt::Display for Porosity<T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "Porosity: 1 - {} / ({} * {}) = {}",
+      DisplayResult(self.dry_density.resolve_rounded(), ""), self.get_specific_gravity(), UNIT_WEIGHT_WATER, DisplayResult(self.calculate(), "")
+    )
+  }
+}
+
+/// Degree of Saturation calculation
+///
+/// The degree of saturation, `S = (w * Gs) / e`, as a percent.
+///
+/// # Example
+///
+/// ```
+/// use billios::field_test::{Saturation, VoidRatio};
+/// use billios::field_test::types::{DryDensityChoice, MoistureContentChoice, VoidRatioChoice};
+///
+/// let void_ratio = VoidRatio::new(DryDensityChoice::Value(110.), None);
+/// let saturation = Saturation::new(MoistureContentChoice::Value(0.1428571), VoidRatioChoice::Constructor(void_ratio), None);
+///
+/// assert_eq!(Ok(72.6), saturation.calculate());
+/// ```
+///
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Saturation<T: Float = f64> {
+  moisture_content: MoistureContentChoice<T>,
+  void_ratio: VoidRatioChoice<T>,
+  specific_gravity: Option<T>,
+}
+
+impl<T: Float> Rounding<T> for Saturation<T> {}
+
+impl<T: Float> Calculate<T> for Saturation<T> {
+  fn calculate_raw(&self) -> Result<T, CalcError> {
+    let moisture_content = self.get_moisture_content()?;
+    let void_ratio = self.get_void_ratio()?;
+    let specific_gravity = self.get_specific_gravity();
+
+    let result = checked_div(moisture_content * specific_gravity, void_ratio)? * T::from_f64(100.);
+
+    Ok(result)
+  }
+
+  fn precision(&self) -> i32 {
+    1
+  }
+
+  fn unit_label(&self) -> &'static str {
+    "%"
+  }
+
+  fn terms(&self) -> Result<Vec<(&'static str, T)>, CalcError> {
+    Ok(vec![
+      ("moisture_content", self.get_moisture_content()?),
+      ("void_ratio", self.get_void_ratio()?),
+      ("specific_gravity", self.get_specific_gravity()),
+    ])
+  }
+}
+
+impl<T: Float> Saturation<T> {
+  /// Create new instance of Saturation.
+  ///
+  /// # Arguments
+  ///
+  /// - `moisture_content` - A `MoistureContentChoice` for the measurement: **Moisture Content**. This offers the ability to use either a value (float), or pass an existing `MoistureContent::new()` constructor.
+  ///
+  /// - `void_ratio` - A `VoidRatioChoice` for the measurement: **Void Ratio**. This offers the ability to use either a value (float), or pass an existing `VoidRatio::new()` constructor.
+  ///
+  /// - `specific_gravity` - An `Option<T>` that when `None` defaults to the constant value `SPECIFIC_GRAVITY`. This can be modified as needed by setting a `Some()` value.
+  ///
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn new(moisture_content: MoistureContentChoice<T>, void_ratio: VoidRatioChoice<T>, specific_gravity: Option<T>) -> Self {
+    Self { moisture_content, void_ratio, specific_gravity }
+  }
+
+  /// Create a new instance of `Saturation`, using the `specific_gravity` from a `CalibrationProfile`.
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn with_profile(moisture_content: MoistureContentChoice<T>, void_ratio: VoidRatioChoice<T>, profile: &CalibrationProfile<T>) -> Self {
+    Self::new(moisture_content, void_ratio, Some(profile.get_specific_gravity()))
+  }
+
+  /// Create a new, validated, instance of `Saturation`.
+  ///
+  /// Returns a `ValidationError` if a value is `NaN`, infinite, or negative. `Constructor` values are not checked, use their own `try_new()` to validate them.
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn try_new(moisture_content: MoistureContentChoice<T>, void_ratio: VoidRatioChoice<T>, specific_gravity: Option<T>) -> Result<Self, ValidationError> {
+    if let MoistureContentChoice::Value(v) | MoistureContentChoice::Percent(v) = moisture_content {
+      ValidationError::check_non_negative("moisture_content", v)?;
+    }
+
+    if let VoidRatioChoice::Value(v) = void_ratio {
+      ValidationError::check_non_negative("void_ratio", v)?;
+    }
+
+    ValidationError::check_optional_non_negative("specific_gravity", specific_gravity)?;
+
+    Ok(Self::new(moisture_content, void_ratio, specific_gravity))
+  }
+
+  /// Calculate the Saturation value, as a percent.
+  ///
+  /// Returns `CalcError::DivisionByZero` when the absolute value of `void_ratio` is less than `T::EPSILON`.
+  ///
+  /// Delegates to the `Calculate` implementation.
+  #[must_use = "the result of a calculation should be used, or its error handled"]
+  pub fn calculate(&self) -> Result<T, CalcError> {
+    Calculate::calculate(self)
+  }
+
+  /// Calculate the Saturation value, checking that it is plausible.
+  ///
+  /// Returns `RangeWarning::BelowRange` or `RangeWarning::AboveRange`, with the rounded result, if it is outside `SATURATION_MIN_PLAUSIBLE` to `SATURATION_MAX_PLAUSIBLE`.
+  pub fn calculate_checked(&self) -> Result<T, RangeWarning<T>> {
+    RangeWarning::check(self.calculate()?, T::from_f64(SATURATION_MIN_PLAUSIBLE), T::from_f64(SATURATION_MAX_PLAUSIBLE))
+  }
+
+  /// Getter for `moisture_content`.
+  ///
+  /// A `Constructor` value is calculated without rounding, so any error from its calculation is returned.
+  pub fn get_moisture_content(&self) -> Result<T, CalcError> {
+    self.moisture_content.resolve()
+  }
+
+  /// Getter for `void_ratio`.
+  ///
+  /// A `Constructor` value is calculated without rounding, so any error from its calculation is returned.
+  pub fn get_void_ratio(&self) -> Result<T, CalcError> {
+    self.void_ratio.resolve()
+  }
+
+  /// Returns a copy of the `Saturation` with `specific_gravity` set to `value`, in place of the `Option` passed to `new()`.
+  pub fn with_specific_gravity(self, value: T) -> Self {
+    Self { specific_gravity: Some(value), ..self }
+  }
+
+  /// Getter for `specific_gravity`.
+  ///
+  /// If no value is provided, the default is set to the `SPECIFIC_GRAVITY` constant.
+  pub fn get_specific_gravity(&self) -> T {
+    match self.specific_gravity {
+      Some(v) => v,
+      None => T::from_f64(SPECIFIC_GRAVITY),
+    }
+  }
+}
+
+impl<T: Float> fmt::Display for Saturation<T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "Saturation: ({} * {} / {}) * 100 = {}",
+      DisplayResult(self.moisture_content.resolve_rounded(), ""), self.get_specific_gravity(), DisplayResult(self.void_ratio.resolve_rounded(), ""), DisplayResult(self.calculate(), "%")
+    )
+  }
+}
+
+/// Relative Density calculation
+///
+/// Used for granular soils in place of `Compaction`. Calculated from void ratios, `Dr = (e_max - e) / (e_max - e_min)`, or from dry densities, `Dr = γd_max * (γd - γd_min) / (γd * (γd_max - γd_min))`, as a percent.
+///
+/// # Example
+///
+/// ```
+/// use billios::field_test::RelativeDensity;
+/// use billios::field_test::types::{DryDensityChoice, VoidRatioChoice};
+///
+/// let from_void_ratios = RelativeDensity::new(0.8, 0.4, VoidRatioChoice::Value(0.55));
+/// assert_eq!(Ok(62.5), from_void_ratios.calculate());
+///
+/// let from_dry_densities = RelativeDensity::from_dry_densities(110., 90., DryDensityChoice::Value(100.));
+/// assert_eq!(Ok(55.), from_dry_densities.calculate());
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RelativeDensity<T: Float = f64> {
+  input: RelativeDensityInput<T>,
+}
+
+/// The values a `RelativeDensity` is calculated from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum RelativeDensityInput<T: Float> {
+  VoidRatio { max_void_ratio: T, min_void_ratio: T, void_ratio: VoidRatioChoice<T> },
+  DryDensity { max_dry_density: T, min_dry_density: T, dry_density: DryDensityChoice<T> },
+}
+
+impl<T: Float> Default for RelativeDensity<T> {
+  fn default() -> Self {
+    Self::new(T::ZERO, T::ZERO, VoidRatioChoice::default())
+  }
+}
+
+impl<T: Float> Rounding<T> for RelativeDensity<T> {}
+
+impl<T: Float> Calculate<T> for RelativeDensity<T> {
+  fn calculate_raw(&self) -> Result<T, CalcError> {
+    let (numerator, denominator) = match self.input {
+      RelativeDensityInput::VoidRatio { max_void_ratio, min_void_ratio, void_ratio } => {
+        (max_void_ratio - void_ratio.resolve()?, max_void_ratio - min_void_ratio)
+      }
+      RelativeDensityInput::DryDensity { max_dry_density, min_dry_density, dry_density } => {
+        let dry_density = dry_density.resolve()?;
+
+        (max_dry_density * (dry_density - min_dry_density), dry_density * (max_dry_density - min_dry_density))
+      }
+    };
+
+    let result = checked_div(numerator, denominator)? * T::from_f64(100.);
+
+    Ok(result)
+  }
+
+  fn precision(&self) -> i32 {
+    1
+  }
+
+  fn unit_label(&self) -> &'static str {
+    "%"
+  }
+
+  fn terms(&self) -> Result<Vec<(&'static str, T)>, CalcError> {
+    Ok(match self.input {
+      RelativeDensityInput::VoidRatio { max_void_ratio, min_void_ratio, void_ratio } => vec![
+        ("max_void_ratio", max_void_ratio),
+        ("min_void_ratio", min_void_ratio),
+        ("void_ratio", void_ratio.resolve()?),
+      ],
+      RelativeDensityInput::DryDensity { max_dry_density, min_dry_density, dry_density } => vec![
+        ("max_dry_density", max_dry_density),
+        ("min_dry_density", min_dry_density),
+        ("dry_density", dry_density.resolve()?),
+      ],
+    })
+  }
+}
+
+impl<T: Float> RelativeDensity<T> {
+  /// Create new instance of Relative Density from void ratios.
+  ///
+  /// # Arguments
+  ///
+  /// - `max_void_ratio` - A float that corresponds to the measurement: **Maximum Void Ratio**, in the loosest state.
+  ///
+  /// - `min_void_ratio` - A float that corresponds to the measurement: **Minimum Void Ratio**, in the densest state.
+  ///
+  /// - `void_ratio` - A `VoidRatioChoice` for the measurement: **In Place Void Ratio**. This offers the ability to use either a value (float), or pass an existing `VoidRatio::new()` constructor.
+  ///
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn new(max_void_ratio: T, min_void_ratio: T, void_ratio: VoidRatioChoice<T>) -> Self {
+    Self { input: RelativeDensityInput::VoidRatio { max_void_ratio, min_void_ratio, void_ratio } }
+  }
+
+  /// Create new instance of Relative Density from dry densities.
+  ///
+  /// # Arguments
+  ///
+  /// - `max_dry_density` - A float that corresponds to the measurement: **Maximum Dry Density**, in the densest state.
+  ///
+  /// - `min_dry_density` - A float that corresponds to the measurement: **Minimum Dry Density**, in the loosest state.
+  ///
+  /// - `dry_density` - A `DryDensityChoice` for the measurement: **In Place Dry Density**. This offers the ability to use either a value (float), or pass an existing `DryDensity::new()` constructor.
+  ///
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn from_dry_densities(max_dry_density: T, min_dry_density: T, dry_density: DryDensityChoice<T>) -> Self {
+    Self { input: RelativeDensityInput::DryDensity { max_dry_density, min_dry_density, dry_density } }
+  }
+
+  /// Create a new, validated, instance of `RelativeDensity` from void ratios.
+  ///
+  /// Returns a `ValidationError` if a value is `NaN`, infinite, or negative. `Constructor` values are not checked, use their own `try_new()` to validate them.
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn try_new(max_void_ratio: T, min_void_ratio: T, void_ratio: VoidRatioChoice<T>) -> Result<Self, ValidationError> {
+    ValidationError::check_non_negative("max_void_ratio", max_void_ratio)?;
+    ValidationError::check_non_negative("min_void_ratio", min_void_ratio)?;
+
+    if let VoidRatioChoice::Value(v) = void_ratio {
+      ValidationError::check_non_negative("void_ratio", v)?;
+    }
+
+    Ok(Self::new(max_void_ratio, min_void_ratio, void_ratio))
+  }
+
+  /// Create a new, validated, instance of `RelativeDensity` from dry densities.
+  ///
+  /// Returns a `ValidationError` if a value is `NaN`, infinite, or negative. `Constructor` values are not checked, use their own `try_new()` to validate them.
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn try_from_dry_densities(max_dry_density: T, min_dry_density: T, dry_density: DryDensityChoice<T>) -> Result<Self, ValidationError> {
+    ValidationError::check_non_negative("max_dry_density", max_dry_density)?;
+    ValidationError::check_non_negative("min_dry_density", min_dry_density)?;
+
+    if let DryDensityChoice::Value(v) = dry_density {
+      ValidationError::check_non_negative("dry_density", v)?;
+    }
+
+    Ok(Self::from_dry_densities(max_dry_density, min_dry_density, dry_density))
+  }
+
+  /// Create new instance of Relative Density from the `MaxIndexDensity` and `MinIndexDensity` tests.
+  ///
+  /// The index densities are calculated without rounding, the same as a `Constructor` value, and any error from their calculation is returned.
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn from_index_densities(max_index_density: MaxIndexDensity<T>, min_index_density: MinIndexDensity<T>, dry_density: DryDensityChoice<T>) -> Result<Self, CalcError> {
+    Ok(Self::from_dry_densities(max_index_density.calculate_raw()?, min_index_density.calculate_raw()?, dry_density))
+  }
+
+  /// Calculate the Relative Density value, as a percent.
+  ///
+  /// Returns `CalcError::DivisionByZero` when the absolute value of the denominator is less than `T::EPSILON`.
+  ///
+  /// Delegates to the `Calculate` implementation.
+  #[must_use = "the result of a calculation should be used, or its error handled"]
+  pub fn calculate(&self) -> Result<T, CalcError> {
+    Calculate::calculate(self)
+  }
+}
+
+impl<T: Float> fmt::Display for RelativeDensity<T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self.input {
+      RelativeDensityInput::VoidRatio { max_void_ratio, min_void_ratio, void_ratio } => write!(
+        f,
+        "Relative Density: ({} - {}) / ({} - {}) * 100 = {}",
+        max_void_ratio, DisplayResult(void_ratio.resolve_rounded(), ""), max_void_ratio, min_void_ratio, DisplayResult(self.calculate(), "%")
+      ),
+      RelativeDensityInput::DryDensity { max_dry_density, min_dry_density, dry_density } => {
+        let dry_density = DisplayResult(dry_density.resolve_rounded(), "");
+
+        write!(
+          f,
+          "Relative Density: {} * ({} - {}) / ({} * ({} - {})) * 100 = {}",
+          max_dry_density, dry_density, min_dry_density, dry_density, max_dry_density, min_dry_density, DisplayResult(self.calculate(), "%")
+        )
+      }
+    }
+  }
+}
+
+/// Specific Gravity calculation (pycnometer method)
+///
+/// The specific gravity of the soil solids, `Gs = K * Ws / (Ws + Wbw - Wbws)`.
+///
+/// # Example
+///
+/// ```
+/// use billios::field_test::{LabMaxCorrection, SpecificGravity};
+/// use billios::field_test::types::{RockCorrectionChoice, SpecificGravityChoice};
+///
+/// let specific_gravity = SpecificGravity::new(100., 650., 712.5, None);
+/// assert_eq!(Ok(2.67), specific_gravity.calculate());
+///
+/// let lab_max_correction = LabMaxCorrection::new(RockCorrectionChoice::Value(0.2), 135.6, Some(SpecificGravityChoice::Constructor(specific_gravity)));
+/// assert_eq!(Ok(139.4), lab_max_correction.calculate());
+/// ```
+///
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpecificGravity<T: Float = f64> {
+  dry_soil: T,
+  bottle_water: T,
+  bottle_water_soil: T,
+  temperature_correction: Option<T>,
+}
+
+impl<T: Float> Rounding<T> for SpecificGravity<T> {}
+
+impl<T: Float> Calculate<T> for SpecificGravity<T> {
+  fn calculate_raw(&self) -> Result<T, CalcError> {
+    let displaced_water = self.dry_soil + self.bottle_water - self.bottle_water_soil;
+
+    let result = checked_div(self.get_temperature_correction() * self.dry_soil, displaced_water)?;
+
+    Ok(result)
+  }
+
+  fn precision(&self) -> i32 {
+    2
+  }
+
+  fn terms(&self) -> Result<Vec<(&'static str, T)>, CalcError> {
+    Ok(vec![
+      ("dry_soil", self.dry_soil),
+      ("bottle_water", self.bottle_water),
+      ("bottle_water_soil", self.bottle_water_soil),
+      ("temperature_correction", self.get_temperature_correction()),
+    ])
+  }
+}
+
+impl<T: Float> SpecificGravity<T> {
+  /// Create new instance of Specific Gravity.
+  ///
+  /// # Arguments
+  ///
+  /// - `dry_soil` - A float that corresponds to the measurement: **Dry Soil**, `Ws`.
+  ///
+  /// - `bottle_water` - A float that corresponds to the measurement: **Pycnometer + Water**, `Wbw`.
+  ///
+  /// - `bottle_water_soil` - A float that corresponds to the measurement: **Pycnometer + Water + Soil**, `Wbws`.
+  ///
+  /// - `temperature_correction` - An `Option<T>` for the temperature correction factor, `K`, that when `None` defaults to `1.0`.
+  ///
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn new(dry_soil: T, bottle_water: T, bottle_water_soil: T, temperature_correction: Option<T>) -> Self {
+    Self { dry_soil, bottle_water, bottle_water_soil, temperature_correction }
+  }
+
+  /// Create a new, validated, instance of `SpecificGravity`.
+  ///
+  /// Returns a `ValidationError` if any value is `NaN`, infinite, or negative.
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn try_new(dry_soil: T, bottle_water: T, bottle_water_soil: T, temperature_correction: Option<T>) -> Result<Self, ValidationError> {
+    ValidationError::check_non_negative("dry_soil", dry_soil)?;
+    ValidationError::check_non_negative("bottle_water", bottle_water)?;
+    ValidationError::check_non_negative("bottle_water_soil", bottle_water_soil)?;
+    ValidationError::check_optional_non_negative("temperature_correction", temperature_correction)?;
+
+    Ok(Self::new(dry_soil, bottle_water, bottle_water_soil, temperature_correction))
+  }
+
+  /// Calculate the Specific Gravity value.
+  ///
+  /// Returns `CalcError::DivisionByZero` when the absolute value of the displaced water, `Ws + Wbw - Wbws`, is less than `T::EPSILON`.
+  ///
+  /// Delegates to the `Calculate` implementation.
+  #[must_use = "the result of a calculation should be used, or its error handled"]
+  pub fn calculate(&self) -> Result<T, CalcError> {
+    Calculate::calculate(self)
+  }
+
+  /// Getter for `dry_soil`
+  pub fn get_dry_soil(&self) -> T {
+    self.dry_soil
+  }
+
+  /// Getter for `bottle_water`
+  pub fn get_bottle_water(&self) -> T {
+    self.bottle_water
+  }
+
+  /// Getter for `bottle_water_soil`
+  pub fn get_bottle_water_soil(&self) -> T {
+    self.bottle_water_soil
+  }
+
+  /// Getter for `temperature_correction`
+  ///
+  /// If no value is provided, the default is `1.0`.
+  pub fn get_temperature_correction(&self) -> T {
+    self.temperature_correction.unwrap_or(T::ONE)
+  }
+}
+
+impl<T: Float> fmt::Display for SpecificGravity<T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "Specific Gravity: {} * {} / ({} + {} - {}) = {}",
+      self.get_temperature_correction(), self.dry_soil, self.dry_soil, self.bottle_water, self.bottle_water_soil, DisplayResult(self.calculate(), "")
+    )
+  }
+}
+
+/// One Point Proctor calculation
+///
+/// Estimates the maximum dry density from a single field point, when a full Proctor test is not available.
+///
+/// The family of curves is assumed to be parabolas of the same shape, `γd = γd_max - C * (w - w_opt)²` with moisture in percent, whose peaks lie on the line of optimums at `OPTIMUM_SATURATION`, `w_opt = S * (γw / γd_max - 1 / Gs) * 100`.
+/// The nearest curve passing through the field point, with the smallest maximum dry density at or above the field dry density, is found by bisection.
+///
+/// # Example
+///
+/// ```
+/// use billios::field_test::{Compaction, OnePointProctor};
+/// use billios::field_test::types::{DryDensityChoice, MoistureContentChoice};
+///
+/// let one_point = OnePointProctor::new(DryDensityChoice::Value(118.06), MoistureContentChoice::Value(0.1), None, None);
+/// assert_eq!(Ok(120.), one_point.calculate());
+///
+/// let compaction = Compaction::new(DryDensityChoice::Value(114.), one_point.calculate().unwrap());
+/// assert_eq!(Ok(95.), compaction.calculate());
+/// ```
+///
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OnePointProctor<T: Float = f64> {
+  dry_density: DryDensityChoice<T>,
+  moisture_content: MoistureContentChoice<T>,
+  coefficient: Option<T>,
+  specific_gravity: Option<T>,
+}
+
+impl<T: Float> Rounding<T> for OnePointProctor<T> {}
+
+impl<T: Float> Calculate<T> for OnePointProctor<T> {
+  fn calculate_raw(&self) -> Result<T, CalcError> {
+    let dry_density = self.get_dry_density()?;
+    let moisture = self.get_moisture_content()? * T::from_f64(100.);
+    let coefficient = self.get_coefficient();
+    let specific_gravity = self.get_specific_gravity();
+
+    // The search below would otherwise report a non-finite input as out of range.
+    if ![dry_density, moisture, coefficient, specific_gravity].iter().all(|v| v.is_finite()) {
+      return Err(CalcError::NonFinite);
+    }
+
+    let inverse_specific_gravity = checked_div(T::ONE, specific_gravity)?;
+
+    let unit_weight_water = T::from_f64(UNIT_WEIGHT_WATER);
+    let saturation = T::from_f64(OPTIMUM_SATURATION);
+    let hundred = T::from_f64(100.);
+
+    // The field point lies on the curve with this maximum dry density when it is zero.
+    let residual = |max_dry_density: T| {
+      let optimum = saturation * (unit_weight_water / max_dry_density - inverse_specific_gravity) * hundred;
+      let offset = moisture - optimum;
+
+      max_dry_density - coefficient * offset * offset - dry_density
+    };
+
+    // The maximum dry density is at least the field dry density, and less than the density of the solids.
+    let solids = specific_gravity * unit_weight_water;
+
+    if dry_density <= T::ZERO || dry_density >= solids {
+      return Err(CalcError::OutOfRange);
+    }
+
+    if residual(dry_density) >= T::ZERO {
+      return Ok(dry_density);
+    }
+
+    // Step up from the field dry density to bracket the nearest curve through the point.
+    let steps = 200;
+    let step = (solids - dry_density) / T::from_f64(steps as f64);
+
+    let mut low = dry_density;
+    let mut bracket = None;
+
+    for i in 1..=steps {
+      let candidate = dry_density + step * T::from_f64(i as f64);
+
+      if residual(candidate) >= T::ZERO {
+        bracket = Some(candidate);
+        break;
+      }
+
+      low = candidate;
+    }
+
+    let mut high = bracket.ok_or(CalcError::OutOfRange)?;
+
+    let two = T::from_f64(2.);
+
+    for _ in 0..100 {
+      let mid = (low + high) / two;
+
+      if residual(mid) < T::ZERO {
+        low = mid;
+      } else {
+        high = mid;
+      }
+    }
+
+    Ok((low + high) / two)
+  }
+
+  fn precision(&self) -> i32 {
+    1
+  }
+
+  fn unit_label(&self) -> &'static str {
+    "pcf"
+  }
+
+  fn terms(&self) -> Result<Vec<(&'static str, T)>, CalcError> {
+    Ok(vec![
+      ("dry_density", self.get_dry_density()?),
+      ("moisture_content", self.get_moisture_content()?),
+      ("coefficient", self.get_coefficient()),
+      ("specific_gravity", self.get_specific_gravity()),
+    ])
+  }
+}
+
+impl<T: Float> OnePointProctor<T> {
+  /// Create new instance of One Point Proctor.
+  ///
+  /// # Arguments
+  ///
+  /// - `dry_density` - A `DryDensityChoice` for the measurement: **Dry Density** of the field point. This offers the ability to use either a value (float), or pass an existing `DryDensity::new()` constructor.
+  ///
+  /// - `moisture_content` - A `MoistureContentChoice` for the measurement: **Moisture Content** (decimal) of the field point. This offers the ability to use either a value (float), or pass an existing `MoistureContent::new()` constructor.
+  ///
+  /// - `coefficient` - An `Option<T>` for the curvature of the family of curves, that when `None` defaults to the constant value `PROCTOR_CURVE_COEFFICIENT`.
+  ///
+  /// - `specific_gravity` - An `Option<T>` that when `None` defaults to the constant value `SPECIFIC_GRAVITY`. This can be modified as needed by setting a `Some()` value.
+  ///
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn new(dry_density: DryDensityChoice<T>, moisture_content: MoistureContentChoice<T>, coefficient: Option<T>, specific_gravity: Option<T>) -> Self {
+    Self { dry_density, moisture_content, coefficient, specific_gravity }
+  }
+
+  /// Create a new instance of `OnePointProctor`, using the `specific_gravity` from a `CalibrationProfile`.
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn with_profile(dry_density: DryDensityChoice<T>, moisture_content: MoistureContentChoice<T>, coefficient: Option<T>, profile: &CalibrationProfile<T>) -> Self {
+    Self::new(dry_density, moisture_content, coefficient, Some(profile.get_specific_gravity()))
+  }
+
+  /// Create a new, validated, instance of `OnePointProctor`.
+  ///
+  /// Returns a `ValidationError` if a value is `NaN`, infinite, or negative. `Constructor` values are not checked, use their own `try_new()` to validate them.
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn try_new(dry_density: DryDensityChoice<T>, moisture_content: MoistureContentChoice<T>, coefficient: Option<T>, specific_gravity: Option<T>) -> Result<Self, ValidationError> {
+    if let DryDensityChoice::Value(v) = dry_density {
+      ValidationError::check_non_negative("dry_density", v)?;
+    }
+
+    if let MoistureContentChoice::Value(v) | MoistureContentChoice::Percent(v) = moisture_content {
+      ValidationError::check_non_negative("moisture_content", v)?;
+    }
+
+    ValidationError::check_optional_non_negative("coefficient", coefficient)?;
+    ValidationError::check_optional_non_negative("specific_gravity", specific_gravity)?;
+
+    Ok(Self::new(dry_density, moisture_content, coefficient, specific_gravity))
+  }
+
+  /// Calculate the estimated maximum dry density.
+  ///
+  /// Returns `CalcError::OutOfRange` when no curve in the family passes through the field point, and `CalcError::DivisionByZero` when the absolute value of `specific_gravity` is less than `T::EPSILON`.
+  ///
+  /// Delegates to the `Calculate` implementation.
+  #[must_use = "the result of a calculation should be used, or its error handled"]
+  pub fn calculate(&self) -> Result<T, CalcError> {
+    Calculate::calculate(self)
+  }
+
+  /// Getter for `dry_density`.
+  ///
+  /// A `Constructor` value is calculated without rounding, so any error from its calculation is returned.
+  pub fn get_dry_density(&self) -> Result<T, CalcError> {
+    self.dry_density.resolve()
+  }
+
+  /// Getter for `moisture_content`.
+  ///
+  /// A `Constructor` value is calculated without rounding, so any error from its calculation is returned.
+  pub fn get_moisture_content(&self) -> Result<T, CalcError> {
+    self.moisture_content.resolve()
+  }
+
+  /// Getter for `coefficient`.
+  ///
+  /// If no value is provided, the default is set to the `PROCTOR_CURVE_COEFFICIENT` constant.
+  pub fn get_coefficient(&self) -> T {
+    match self.coefficient {
+      Some(v) => v,
+      None => T::from_f64(PROCTOR_CURVE_COEFFICIENT),
+    }
+  }
+
+  /// Returns a copy of the `OnePointProctor` with `specific_gravity` set to `value`, in place of the `Option` passed to `new()`.
+  pub fn with_specific_gravity(self, value: T) -> Self {
+    Self { specific_gravity: Some(value), ..self }
+  }
+
+  /// Getter for `specific_gravity`.
+  ///
+  /// If no value is provided, the default is set to the `SPECIFIC_GRAVITY` constant.
+  pub fn get_specific_gravity(&self) -> T {
+    match self.specific_gravity {
+      Some(v) => v,
+      None => T::from_f64(SPECIFIC_GRAVITY),
+    }
+  }
+}
+
+impl<T: Float> fmt::Display for OnePointProctor<T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "One Point Proctor: {} at {} moisture = {}",
+      DisplayResult(self.dry_density.resolve_rounded(), " pcf"), DisplayResult(self.moisture_content.resolve_rounded(), ""), DisplayResult(self.calculate(), " pcf")
+    )
+  }
+}
+
+/// Core sample (drive cylinder) wet density calculation
+///
+/// An alternative to the sand cone `WetDensity`, where the volume of the sample is known from the cylinder rather than the sand used. The result can be passed on as a `WetDensityChoice::Value()`.
+///
+/// # Example
+///
+/// ```
+/// use billios::field_test::{CoreSampleDensity, DryDensity};
+/// use billios::field_test::types::{WetDensityChoice, MoistureContentChoice};
+///
+/// let core_sample = CoreSampleDensity::new(3.1, 0.025);
+/// assert_eq!(Ok(124.), core_sample.calculate());
+///
+/// let dry_density = DryDensity::new(WetDensityChoice::Value(core_sample.calculate().unwrap()), MoistureContentChoice::Value(0.1));
+/// assert_eq!(Ok(113.), dry_density.calculate());
+/// ```
+///
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CoreSampleDensity<T: Float = f64> {
+  soil: T,
+  volume: T,
+}
+
+impl<T: Float> Rounding<T> for CoreSampleDensity<T> {}
+
+impl<T: Float> Calculate<T> for CoreSampleDensity<T> {
+  fn calculate_raw(&self) -> Result<T, CalcError> {
+    let result = checked_div(self.soil, self.volume)?;
+
+    Ok(result)
+  }
+
+  fn precision(&self) -> i32 {
+    4
+  }
+
+  fn unit_label(&self) -> &'static str {
+    "pcf"
+  }
+
+  fn terms(&self) -> Result<Vec<(&'static str, T)>, CalcError> {
+    Ok(vec![
+      ("soil", self.soil),
+      ("volume", self.volume),
+    ])
+  }
+}
+
+impl<T: Float> CoreSampleDensity<T> {
+  /// Create a new instance of `CoreSampleDensity`.
+  ///
+  /// # Arguments
+  ///
+  /// - `soil` - A float that corresponds to the measurement: **Soil**, the moist mass of the sample, in lb.
+  ///
+  /// - `volume` - A float that corresponds to the measurement: **Volume** of the cylinder, in ft³.
+  ///
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn new(soil: T, volume: T) -> Self {
+    Self { soil, volume }
+  }
+
+  /// Create a new, validated, instance of `CoreSampleDensity`.
+  ///
+  /// Returns a `ValidationError` if any value is `NaN`, infinite, or negative.
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn try_new(soil: T, volume: T) -> Result<Self, ValidationError> {
+    ValidationError::check_non_negative("soil", soil)?;
+    ValidationError::check_non_negative("volume", volume)?;
+
+    Ok(Self::new(soil, volume))
+  }
+
+  /// Calculate the Wet Density of the core sample.
+  ///
+  /// Returns `CalcError::DivisionByZero` when the absolute value of `volume` is less than `T::EPSILON`.
+  ///
+  /// Delegates to the `Calculate` implementation.
+  #[must_use = "the result of a calculation should be used, or its error handled"]
+  pub fn calculate(&self) -> Result<T, CalcError> {
+    Calculate::calculate(self)
+  }
+
+  /// Getter for `soil`
+  pub fn get_soil(&self) -> T {
+    self.soil
+  }
+
+  /// Getter for `volume`
+  pub fn get_volume(&self) -> T {
+    self.volume
+  }
+}
+
+impl<T: Float> fmt::Display for CoreSampleDensity<T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "Core Sample Density: {} / {} = {}", self.soil, self.volume, DisplayResult(self.calculate(), " pcf"))
+  }
+}
+
+/// Max Index Density calculation, ASTM D4253
+///
+/// The dry density of a clean, free-draining soil in its densest state, `(Wms - Wm) / V`, from the mass of the oven-dry soil in a mold of known volume. Passed to `RelativeDensity::from_index_densities()` as the maximum dry density.
+///
+/// # Example
+///
+/// ```
+/// use billios::field_test::{MaxIndexDensity, MinIndexDensity, RelativeDensity};
+/// use billios::field_test::types::DryDensityChoice;
+///
+/// let max_index_density = MaxIndexDensity::new(13.3, 10., 0.03);
+/// let min_index_density = MinIndexDensity::new(12.7, 10., 0.03);
+///
+/// assert_eq!(Ok(110.), max_index_density.calculate());
+///
+/// let relative_density = RelativeDensity::from_index_densities(max_index_density, min_index_density, DryDensityChoice::Value(100.)).unwrap();
+/// assert_eq!(Ok(55.), relative_density.calculate());
+/// ```
+///
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MaxIndexDensity<T: Float = f64> {
+  mold_and_soil: T,
+  mold: T,
+  volume: T,
+}
+
+impl<T: Float> Rounding<T> for MaxIndexDensity<T> {}
+
+impl<T: Float> Calculate<T> for MaxIndexDensity<T> {
+  fn calculate_raw(&self) -> Result<T, CalcError> {
+    let result = checked_div(self.mold_and_soil - self.mold, self.volume)?;
+
+    Ok(result)
+  }
+
+  fn precision(&self) -> i32 {
+    4
+  }
+
+  fn unit_label(&self) -> &'static str {
+    "pcf"
+  }
+
+  fn terms(&self) -> Result<Vec<(&'static str, T)>, CalcError> {
+    Ok(vec![
+      ("mold_and_soil", self.mold_and_soil),
+      ("mold", self.mold),
+      ("volume", self.volume),
+    ])
+  }
+}
+
+impl<T: Float> MaxIndexDensity<T> {
+  /// Create a new instance of `MaxIndexDensity`.
+  ///
+  /// # Arguments
+  ///
+  /// - `mold_and_soil` - A float that corresponds to the measurement: **Mold and Soil**, the mass of the mold and the oven-dry soil after densification on the vibratory table, in lb.
+  ///
+  /// - `mold` - A float that corresponds to the measurement: **Mold**, the mass of the empty mold, in lb.
+  ///
+  /// - `volume` - A float that corresponds to the measurement: **Volume** of the mold, in ft³.
+  ///
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn new(mold_and_soil: T, mold: T, volume: T) -> Self {
+    Self { mold_and_soil, mold, volume }
+  }
+
+  /// Create a new, validated, instance of `MaxIndexDensity`.
+  ///
+  /// Returns a `ValidationError` if any value is `NaN`, infinite, or negative.
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn try_new(mold_and_soil: T, mold: T, volume: T) -> Result<Self, ValidationError> {
+    ValidationError::check_non_negative("mold_and_soil", mold_and_soil)?;
+    ValidationError::check_non_negative("mold", mold)?;
+    ValidationError::check_non_negative("volume", volume)?;
+
+    Ok(Self::new(mold_and_soil, mold, volume))
+  }
+
+  /// Calculate the Max Index Density, in pcf, rounded to four decimal places.
+  ///
+  /// Returns `CalcError::DivisionByZero` when the absolute value of `volume` is less than `T::EPSILON`.
+  ///
+  /// Delegates to the `Calculate` implementation.
+  #[must_use = "the result of a calculation should be used, or its error handled"]
+  pub fn calculate(&self) -> Result<T, CalcError> {
+    Calculate::calculate(self)
+  }
+
+  /// Getter for `mold_and_soil`
+  pub fn get_mold_and_soil(&self) -> T {
+    self.mold_and_soil
+  }
+
+  /// Getter for `mold`
+  pub fn get_mold(&self) -> T {
+    self.mold
+  }
+
+  /// Getter for `volume`
+  pub fn get_volume(&self) -> T {
+    self.volume
+  }
+}
+
+impl<T: Float> fmt::Display for MaxIndexDensity<T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "Max Index Density: ({} - {}) / {} = {}", self.mold_and_soil, self.mold, self.volume, DisplayResult(self.calculate(), " pcf"))
+  }
+}
+
+/// Min Index Density calculation, ASTM D4254
+///
+/// The dry density of a clean, free-draining soil in its loosest state, `(Wms - Wm) / V`, from the mass of the oven-dry soil in a mold of known volume. Passed to `RelativeDensity::from_index_densities()` as the minimum dry density.
+///
+/// # Example
+///
+/// ```
+/// use billios::field_test::{MaxIndexDensity, MinIndexDensity, RelativeDensity};
+/// use billios::field_test::types::DryDensityChoice;
+///
+/// let max_index_density = MaxIndexDensity::new(13.3, 10., 0.03);
+/// let min_index_density = MinIndexDensity::new(12.7, 10., 0.03);
+///
+/// assert_eq!(Ok(90.), min_index_density.calculate());
+///
+/// let relative_density = RelativeDensity::from_index_densities(max_index_density, min_index_density, DryDensityChoice::Value(100.)).unwrap();
+/// assert_eq!(Ok(55.), relative_density.calculate());
+/// ```
+///
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MinIndexDensity<T: Float = f64> {
+  mold_and_soil: T,
+  mold: T,
+  volume: T,
+}
+
+impl<T: Float> Rounding<T> for MinIndexDensity<T> {}
+
+impl<T: Float> Calculate<T> for MinIndexDensity<T> {
+  fn calculate_raw(&self) -> Result<T, CalcError> {
+    let result = checked_div(self.mold_and_soil - self.mold, self.volume)?;
+
+    Ok(result)
+  }
+
+  fn precision(&self) -> i32 {
+    4
+  }
+
+  fn unit_label(&self) -> &'static str {
+    "pcf"
+  }
+
+  fn terms(&self) -> Result<Vec<(&'static str, T)>, CalcError> {
+    Ok(vec![
+      ("mold_and_soil", self.mold_and_soil),
+      ("mold", self.mold),
+      ("volume", self.volume),
+    ])
+  }
+}
+
+impl<T: Float> MinIndexDensity<T> {
+  /// Create a new instance of `MinIndexDensity`.
+  ///
+  /// # Arguments
+  ///
+  /// - `mold_and_soil` - A float that corresponds to the measurement: **Mold and Soil**, the mass of the mold and the oven-dry soil placed loosely, e.g. by funnel, in lb.
+  ///
+  /// - `mold` - A float that corresponds to the measurement: **Mold**, the mass of the empty mold, in lb.
+  ///
+  /// - `volume` - A float that corresponds to the measurement: **Volume** of the mold, in ft³.
+  ///
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn new(mold_and_soil: T, mold: T, volume: T) -> Self {
+    Self { mold_and_soil, mold, volume }
+  }
+
+  /// Create a new, validated, instance of `MinIndexDensity`.
+  ///
+  /// Returns a `ValidationError` if any value is `NaN`, infinite, or negative.
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn try_new(mold_and_soil: T, mold: T, volume: T) -> Result<Self, ValidationError> {
+    ValidationError::check_non_negative("mold_and_soil", mold_and_soil)?;
+    ValidationError::check_non_negative("mold", mold)?;
+    ValidationError::check_non_negative("volume", volume)?;
+
+    Ok(Self::new(mold_and_soil, mold, volume))
+  }
+
+  /// Calculate the Min Index Density, in pcf, rounded to four decimal places.
+  ///
+  /// Returns `CalcError::DivisionByZero` when the absolute value of `volume` is less than `T::EPSILON`.
+  ///
+  /// Delegates to the `Calculate` implementation.
+  #[must_use = "the result of a calculation should be used, or its error handled"]
+  pub fn calculate(&self) -> Result<T, CalcError> {
+    Calculate::calculate(self)
+  }
+
+  /// Getter for `mold_and_soil`
+  pub fn get_mold_and_soil(&self) -> T {
+    self.mold_and_soil
+  }
+
+  /// Getter for `mold`
+  pub fn get_mold(&self) -> T {
+    self.mold
+  }
+
+  /// Getter for `volume`
+  pub fn get_volume(&self) -> T {
+    self.volume
+  }
+}
+
+impl<T: Float> fmt::Display for MinIndexDensity<T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "Min Index Density: ({} - {}) / {} = {}", self.mold_and_soil, self.mold, self.volume, DisplayResult(self.calculate(), " pcf"))
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::domain::errors::ValidationErrorKind;
+
+  #[test]
+  fn test_sand_used_new() {
+    // Test with Some() value.
+    let sand_used_some = SandUsed::new(10., 15., Some(20.));
+
+    assert_eq!(10., sand_used_some.get_cone_pre_test());
+    assert_eq!(15., sand_used_some.get_cone_post_test());
+    assert_eq!(20., sand_used_some.get_sand_in_cone());
+
+    // Test with None, (default), value.
+    let sand_used_none = SandUsed::new(10., 15., None);
+
+    assert_eq!(10., sand_used_none.get_cone_pre_test());
+    assert_eq!(15., sand_used_none.get_cone_post_test());
+    assert_eq!(SAND_IN_CONE, sand_used_none.get_sand_in_cone());
+  }
+
+  #[test]
+  fn test_sand_used_calculate() {
+    let setup = Setup::new();
+
+    // Test with a Some() value.
+    let sand_used_some = SandUsed::new(setup.cone_pre_test, setup.cone_post_test, Some(3.59));
+    assert_eq!(Ok(2.31), sand_used_some.calculate());
+
+    // Test with a None, (default), value.
+    let sand_used_none = SandUsed::new(setup.cone_pre_test, setup.cone_post_test, None);
+    assert_eq!(Ok(2.31), sand_used_none.calculate());
+  }
+
+  #[test]
+  fn test_wet_density_new() {
+    let some = WetDensity::new(10., SandUsedChoice::Value(15.), Some(20.));
+
+    assert_eq!(10., some.get_soil());
+    assert_eq!(Ok(15.), some.get_sand_used());
+    assert_eq!(20., some.get_sand_density());
+
+    let none = WetDensity::new(10., SandUsedChoice::Value(15.), None);
+
+    assert_eq!(10., none.get_soil());
+    assert_eq!(Ok(15.), some.get_sand_used());
+    assert_eq!(20., some.get_sand_density());
+  }
+
+  #[test]
+  fn test_wet_density_calculate() {
+    let setup = Setup::new();
+
+    let sand_used = SandUsed::new(setup.cone_pre_test, setup.cone_post_test, None);
+    let constructor = WetDensity::new(setup.soil, SandUsedChoice::Constructor(sand_used), None);
+    assert_eq!(sand_used.calculate_raw(), constructor.get_sand_used());
+    assert_eq!(Ok(177.1429), constructor.calculate());
+
+    let some = WetDensity::new(setup.soil, SandUsedChoice::Value(2.31), Some(88.));
+    assert_eq!(Ok(177.1429), some.calculate());
+
+    let none = WetDensity::new(setup.soil, SandUsedChoice::Value(2.31), None);
+    assert_eq!(Ok(177.1429), none.calculate());
+  }
+
+  #[test]
+  fn test_moisture_content_new() {
+    let new = MoistureContent::new(10., 15., 20.);
+
+    assert_eq!(10., new.get_wet_weight());
+    assert_eq!(15., new.get_dry_weight());
+    assert_eq!(20., new.get_tare_pan());
+  }
+
+  #[test]
+  fn test_moisture_content_calculate() {
+    let setup = Setup::new();
+
+    let calc = MoistureContent::new(setup.wet_weight, setup.dry_weight, setup.tare_pan);
+    assert_eq!(Ok(0.14285714), calc.calculate());
+    assert_eq!(Ok(14.285714), calc.calculate_percent());
+  }
+
+  #[test]
+  fn test_dry_density_new() {
+    let setup = Setup::new();
+
+    let value = DryDensity::new(WetDensityChoice::Value(10.), MoistureContentChoice::Value(15.));
+
+    assert_eq!(Ok(10.), value.get_wet_density());
+    assert_eq!(Ok(15.), value.get_moisture_content());
+
+    let wet_density = WetDensity::new(setup.soil, SandUsedChoice::Value(2.31), None);
+    let moisture_content = MoistureContent::new(setup.wet_weight, setup.dry_weight, setup.tare_pan);
+
+    let constructor = DryDensity::new(WetDensityChoice::Constructor(wet_density), MoistureContentChoice::Constructor(moisture_content));
+
+    assert_eq!(wet_density.calculate_raw(), constructor.get_wet_density());
+    assert_eq!(moisture_content.calculate_raw(), constructor.get_moisture_content());
+  }
+
+  #[test]
+  fn test_dry_density_calculate() {
+    let setup = Setup::new();
+
+    let value = DryDensity::new(WetDensityChoice::Value(177.1429), MoistureContentChoice::Value(0.1428571));
+    assert_eq!(Ok(155.), value.calculate());
+
+    let wet_density = WetDensity::new(setup.soil, SandUsedChoice::Value(2.31), None);
+    let moisture_content = MoistureContent::new(setup.wet_weight, setup.dry_weight, setup.tare_pan);
+
+    let constructor = DryDensity::new(WetDensityChoice::Constructor(wet_density), MoistureContentChoice::Constructor(moisture_content));
+    assert_eq!(Ok(155.), constructor.calculate());
+
+    let percent = DryDensity::new(WetDensityChoice::Value(177.1429), MoistureContentChoice::Percent(14.28571));
+    assert_eq!(Ok(155.), percent.calculate());
+    assert_eq!("moisture_content", DryDensity::try_new(WetDensityChoice::Value(177.1429), MoistureContentChoice::Percent(-14.3)).unwrap_err().field);
+  }
+
+  #[test]
+  fn test_compaction_new() {
+    let value = Compaction::new(DryDensityChoice::Value(10.), 15.);
+    assert_eq!(Ok(10.), value.get_dry_density());
+    assert_eq!(15., value.get_lab_max());
+
+    let dry_density = DryDensity::new(WetDensityChoice::Value(177.1429), MoistureContentChoice::Value(0.1428571));
+
+    let constructor = Compaction::new(DryDensityChoice::Constructor(dry_density), 15.);
+    assert_eq!(dry_density.calculate_raw(), constructor.get_dry_density());
+    assert_eq!(15., constructor.get_lab_max());
+  }
+
+  #[test]
+  fn test_compaction_calculate() {
+    let setup = Setup::new();
+
+    let value = Compaction::new(DryDensityChoice::Value(155.), setup.lab_max);
+    assert_eq!(Ok(114.3), value.calculate());
+
+    let dry_density = DryDensity::new(WetDensityChoice::Value(177.1429), MoistureContentChoice::Value(0.1428571));
+    let constructor = Compaction::new(DryDensityChoice::Constructor(dry_density), setup.lab_max);
+
+    assert_eq!(Ok(114.3), constructor.calculate());
+  }
+
+  #[test]
+  fn test_rock_correction_new() {
+    let new = RockCorrection::new(10., 15.);
+
+    assert_eq!(10., new.get_left_on_sieve_weight());
+    assert_eq!(15., new.get_pre_sieve_rock_correction());
+  }
+
+  #[test]
+  fn test_rock_correction_calculate() {
+    let setup = Setup::new();
+
+    let rc = RockCorrection::new(setup.left_on_sieve_weight, setup.pre_sieve_rock_correction);
+
+    assert_eq!(Ok(0.2), rc.calculate());
+    assert_eq!(Ok(20.), rc.percent());
+    assert_eq!(Ok(0.235), RockCorrection::new(23.5, 100.).calculate());
+    assert_eq!(Ok(1.), RockCorrection::new(100., 100.).calculate());
+  }
+
+  #[test]
+  fn test_rock_correction_out_of_range() {
+    let swapped = RockCorrection::new(500., 100.);
+
+    assert_eq!(Err(CalcError::OutOfRange), swapped.calculate());
+    assert_eq!(Err(CalcError::OutOfRange), swapped.percent());
+    assert_eq!(Err(CalcError::OutOfRange), RockCorrection::new(-100., 500.).calculate());
+    assert_eq!(Err(CalcError::OutOfRange), RockCorrection::from_percent(120.).calculate());
+
+    let lab_max_correction = LabMaxCorrection::new(RockCorrectionChoice::Constructor(swapped), 135.6, None);
+    assert_eq!(Err(CalcError::OutOfRange), lab_max_correction.calculate());
+  }
+
+  #[test]
+  fn test_rock_correction_from_percent() {
+    let rc = RockCorrection::from_percent(23.5);
+
+    assert_eq!(Ok(0.235), rc.calculate());
+    assert_eq!(Ok(23.5), rc.percent());
+    assert_eq!(100., rc.get_pre_sieve_rock_correction());
+
+    let lab_max_correction = LabMaxCorrection::new(RockCorrectionChoice::Constructor(RockCorrection::from_percent(20.)), 135.6, None);
+    assert_eq!(LabMaxCorrection::new(RockCorrectionChoice::Value(0.2), 135.6, None).calculate(), lab_max_correction.calculate());
+  }
+
+  #[test]
+  fn test_lab_max_correction_new() {
+    let setup = Setup::new();
+
+    let value = LabMaxCorrection::new(RockCorrectionChoice::Value(10.), 15., Some(SpecificGravityChoice::Value(20.)));
+
+    assert_eq!(Ok(10.), value.get_rock_correction());
+    assert_eq!(15., value.get_lab_max());
+    assert_eq!(Ok(20.), value.get_specific_gravity());
+
+    let rock_correction = RockCorrection::new(setup.left_on_sieve_weight, setup.pre_sieve_rock_correction);
+    let constructor = LabMaxCorrection::new(RockCorrectionChoice::Constructor(rock_correction), 15., Some(SpecificGravityChoice::Value(20.)));
+
+    assert_eq!(Ok(0.2), constructor.get_rock_correction());
+    assert_eq!(15., constructor.get_lab_max());
+    assert_eq!(Ok(20.), constructor.get_specific_gravity());
+
+    let none = LabMaxCorrection::new(RockCorrectionChoice::Value(10.), 15., None);
+
+    assert_eq!(Ok(2.7), none.get_specific_gravity());
+  }
+
+  #[test]
+  fn test_lab_max_correction_calculate() {
+    let setup = Setup::new();
+
+    let value = LabMaxCorrection::new(RockCorrectionChoice::Value(0.2), setup.lab_max, None);
+
+    assert_eq!(Ok(139.7), value.calculate());
+
+    let rock_correction = RockCorrection::new(setup.left_on_sieve_weight, setup.pre_sieve_rock_correction);
+    let constructor = LabMaxCorrection::new(RockCorrectionChoice::Constructor(rock_correction), setup.lab_max, None);
+
+     assert_eq!(Ok(139.7), constructor.calculate());
+
+    assert_eq!(Err(CalcError::OutOfRange), LabMaxCorrection::new(RockCorrectionChoice::Value(1.2), setup.lab_max, None).calculate());
+    assert_eq!(Err(CalcError::OutOfRange), LabMaxCorrection::new(RockCorrectionChoice::Value(-0.2), setup.lab_max, None).calculate());
+    assert!(LabMaxCorrection::new(RockCorrectionChoice::Value(1.), setup.lab_max, None).calculate().is_ok());
+  }
+
+  #[test]
+  fn test_try_new() {
+    let setup = Setup::new();
+
+    assert!(SandUsed::try_new(setup.cone_pre_test, setup.cone_post_test, None).is_ok());
+    assert_eq!(Err(ValidationError::new("cone_pre_test", ValidationErrorKind::NaN)), SandUsed::try_new(f64::NAN, setup.cone_post_test, None).map(|_| ()));
+    assert_eq!(Err(ValidationError::new("sand_in_cone", ValidationErrorKind::Negative)), SandUsed::try_new(setup.cone_pre_test, setup.cone_post_test, Some(-1.)).map(|_| ()));
+
+    assert!(WetDensity::try_new(setup.soil, SandUsedChoice::Value(2.31), None).is_ok());
+    assert_eq!(Err(ValidationError::new("sand_used", ValidationErrorKind::Infinite)), WetDensity::try_new(setup.soil, SandUsedChoice::Value(f64::INFINITY), None).map(|_| ()));
+    assert_eq!(Err(ValidationError::new("sand_density", ValidationErrorKind::Negative)), WetDensity::try_new(setup.soil, SandUsedChoice::Value(2.31), Some(-88.)).map(|_| ()));
+
+    assert!(MoistureContent::try_new(setup.wet_weight, setup.dry_weight, setup.tare_pan).is_ok());
+    assert_eq!(Err(ValidationError::new("tare_pan", ValidationErrorKind::Negative)), MoistureContent::try_new(setup.wet_weight, setup.dry_weight, -1.).map(|_| ()));
+
+    assert!(DryDensity::try_new(WetDensityChoice::Value(177.1429), MoistureContentChoice::Value(0.1428571)).is_ok());
+    assert_eq!(Err(ValidationError::new("moisture_content", ValidationErrorKind::NaN)), DryDensity::try_new(WetDensityChoice::Value(177.1429), MoistureContentChoice::Value(f64::NAN)).map(|_| ()));
+
+    assert!(Compaction::try_new(DryDensityChoice::Value(155.), setup.lab_max).is_ok());
+    assert_eq!(Err(ValidationError::new("dry_density", ValidationErrorKind::Negative)), Compaction::try_new(DryDensityChoice::Value(-155.), setup.lab_max).map(|_| ()));
+
+    assert!(RockCorrection::try_new(setup.left_on_sieve_weight, setup.pre_sieve_rock_correction).is_ok());
+    assert_eq!(Err(ValidationError::new("left_on_sieve_weight", ValidationErrorKind::Negative)), RockCorrection::try_new(-100., setup.pre_sieve_rock_correction).map(|_| ()));
+
+    assert!(LabMaxCorrection::try_new(RockCorrectionChoice::Value(0.2), setup.lab_max, None).is_ok());
+    assert_eq!(Err(ValidationError::new("specific_gravity", ValidationErrorKind::Infinite)), LabMaxCorrection::try_new(RockCorrectionChoice::Value(0.2), setup.lab_max, Some(SpecificGravityChoice::Value(f64::INFINITY))).map(|_| ()));
+  }
+
+  #[test]
+  fn test_calculate_trait() {
+    let setup = Setup::new();
+
+    fn run<T: Calculate>(c: &T) -> Result<f64, CalcError> {
+      c.calculate()
+    }
+
+    let sand_used = SandUsed::new(setup.cone_pre_test, setup.cone_post_test, None);
+    assert_eq!(sand_used.calculate(), run(&sand_used));
+
+    let calculations: Vec<Box<dyn Calculate>> = vec![
+      Box::new(sand_used),
+      Box::new(WetDensity::new(setup.soil, SandUsedChoice::Value(2.31), None)),
+      Box::new(MoistureContent::new(setup.wet_weight, setup.dry_weight, setup.tare_pan)),
+      Box::new(DryDensity::new(WetDensityChoice::Value(177.1429), MoistureContentChoice::Value(0.1428571))),
+      Box::new(Compaction::new(DryDensityChoice::Value(155.), setup.lab_max)),
+      Box::new(RockCorrection::new(setup.left_on_sieve_weight, setup.pre_sieve_rock_correction)),
+      Box::new(LabMaxCorrection::new(RockCorrectionChoice::Value(0.2), setup.lab_max, None)),
+    ];
+
+    let results: Vec<f64> = calculations.iter().map(|c| c.calculate_or_panic()).collect();
+    assert_eq!(vec![2.31, 177.1429, 0.14285714, 155., 114.3, 0.2, 139.7], results);
+  }
+
+  #[test]
+  fn test_calculate_errors() {
+    let setup = Setup::new();
+
+    // A non-finite result is reported.
+    let sand_used = SandUsed::new(f64::INFINITY, setup.cone_post_test, None);
+    assert_eq!(Err(CalcError::NonFinite), sand_used.calculate());
+
+    // A zero `sand_used` would produce an infinite wet density.
+    let wet_density = WetDensity::new(setup.soil, SandUsedChoice::Value(0.), None);
+    assert_eq!(Err(CalcError::DivisionByZero), wet_density.calculate());
+
+    // The error bubbles up through the constructor chain.
+    let dry_density = DryDensity::new(WetDensityChoice::Constructor(wet_density), MoistureContentChoice::Value(0.1428571));
+    assert_eq!(Err(CalcError::DivisionByZero), dry_density.get_wet_density());
+    assert_eq!(Err(CalcError::DivisionByZero), dry_density.calculate());
+
+    let compaction = Compaction::new(DryDensityChoice::Constructor(dry_density), setup.lab_max);
+    assert_eq!(Err(CalcError::DivisionByZero), compaction.calculate());
+  }
+
+  #[test]
+  fn test_division_by_zero() {
+    let setup = Setup::new();
+
+    // Values within `f64::EPSILON` of zero are treated as zero.
+    let wet_density = WetDensity::new(setup.soil, SandUsedChoice::Value(f64::EPSILON / 2.), None);
+    assert_eq!(Err(CalcError::DivisionByZero), wet_density.calculate());
+
+    let moisture_content = MoistureContent::new(setup.wet_weight, setup.tare_pan, setup.tare_pan);
+    assert_eq!(Err(CalcError::DivisionByZero), moisture_content.calculate());
+
+    let rock_correction = RockCorrection::new(setup.left_on_sieve_weight, 0.);
+    assert_eq!(Err(CalcError::DivisionByZero), rock_correction.calculate());
+
+    let lab_max_correction = LabMaxCorrection::new(RockCorrectionChoice::Constructor(rock_correction), setup.lab_max, None);
+    assert_eq!(Err(CalcError::DivisionByZero), lab_max_correction.calculate());
+
+    let dry_density = DryDensity::new(WetDensityChoice::Value(177.1429), MoistureContentChoice::Value(-1.));
+    assert_eq!(Err(CalcError::DivisionByZero), dry_density.calculate());
+
+    let compaction = Compaction::new(DryDensityChoice::Value(155.), 0.);
+    assert_eq!(Err(CalcError::DivisionByZero), compaction.calculate());
+
+    let lab_max_correction = LabMaxCorrection::new(RockCorrectionChoice::Value(0.2), 0., None);
+    assert_eq!(Err(CalcError::DivisionByZero), lab_max_correction.calculate());
+
+    let lab_max_correction = LabMaxCorrection::new(RockCorrectionChoice::Value(0.2), setup.lab_max, Some(SpecificGravityChoice::Value(0.)));
+    assert_eq!(Err(CalcError::DivisionByZero), lab_max_correction.calculate());
+
+    let corrected_compaction = CorrectedCompaction::new(DryDensityChoice::Value(133.), 0., RockCorrectionChoice::Value(0.2), None);
+    assert_eq!(Err(CalcError::DivisionByZero), corrected_compaction.calculate());
+
+    assert_eq!(Err(CalcError::DivisionByZero), VoidRatio::new(DryDensityChoice::Value(0.), None).calculate());
+    assert_eq!(Err(CalcError::DivisionByZero), Porosity::new(DryDensityChoice::Value(110.), Some(0.)).calculate());
+    assert_eq!(Err(CalcError::DivisionByZero), Saturation::new(MoistureContentChoice::Value(0.1), VoidRatioChoice::Value(0.), None).calculate());
+    assert_eq!(Err(CalcError::DivisionByZero), CoreSampleDensity::new(4.65, 0.).calculate());
+  }
+
+  #[test]
+  fn test_division_non_finite() {
+    // A `NaN` or infinite input is an error from the division, rather than a `NaN` or infinite result.
+    let wet_density = WetDensity::new(f64::NAN, SandUsedChoice::Value(2.31), None);
+    assert_eq!(Err(CalcError::NonFinite), wet_density.calculate_raw());
+    assert_eq!(Err(CalcError::NonFinite), wet_density.calculate());
+
+    let dry_density = DryDensity::new(WetDensityChoice::Value(f64::INFINITY), MoistureContentChoice::Value(0.1));
+    assert_eq!(Err(CalcError::NonFinite), dry_density.calculate_raw());
+
+    let compaction = Compaction::new(DryDensityChoice::Value(155.), f64::NAN);
+    assert_eq!(Err(CalcError::NonFinite), compaction.calculate_raw());
+
+    let moisture_content = MoistureContent::new(f64::NAN, 1575., 1400.);
+    assert_eq!(Err(CalcError::NonFinite), moisture_content.calculate_raw());
+
+    assert_eq!(Err(CalcError::NonFinite), RockCorrection::new(f64::INFINITY, 500.).calculate_raw());
+    assert_eq!(Err(CalcError::NonFinite), LabMaxCorrection::new(RockCorrectionChoice::Value(f64::NAN), 135.6, None).calculate_raw());
+  }
+
+  #[test]
+  fn test_calculate_or_panic() {
+    let sand_used = SandUsed::new(14.65, 8.75, None);
+    assert_eq!(2.31, sand_used.calculate_or_panic());
+  }
+
+  #[test]
+  #[should_panic(expected = "Error calculating the result.")]
+  fn test_calculate_or_panic_error() {
+    WetDensity::new(4.65, SandUsedChoice::Value(0.), None).calculate_or_panic();
+  }
+
+  #[cfg(feature = "approx")]
+  #[test]
+  fn test_approx() {
+    use approx::{assert_abs_diff_eq, assert_relative_eq, assert_relative_ne, assert_ulps_eq};
+    use crate::domain::types::CalcResult;
+
+    let detailed = SandUsed::new(14.65, 8.75, None).calculate_detailed().unwrap();
+    let expected = CalcResult::new(2.31, vec![("cone_pre_test", 14.65), ("cone_post_test", 8.75), ("sand_in_cone", 3.59)]);
 
-    let result = (1. - 0.05 * rock_correction) / (rock_correction / (62.4 * specific_gravity) + (1. - rock_correction) / self.lab_max);
+    assert_relative_eq!(expected, detailed);
+    assert_ulps_eq!(expected, detailed);
 
-    self.round_n(result, 1).expect("Error rounding the result.")
+    let drifted = CalcResult::new(2.3100001, vec![("cone_pre_test", 14.65), ("cone_post_test", 8.75), ("sand_in_cone", 3.59)]);
+    assert_relative_ne!(drifted, detailed);
+    assert_abs_diff_eq!(drifted, detailed, epsilon = 1e-6);
+
+    // The terms are compared by label as well as value.
+    let relabelled = CalcResult::new(2.31, vec![("cone_post_test", 8.75), ("cone_pre_test", 14.65), ("sand_in_cone", 3.59)]);
+    assert_relative_ne!(relabelled, detailed, epsilon = 10.);
+    assert_relative_ne!(CalcResult::new(2.31, Vec::new()), detailed);
   }
 
-  /// Getter for `rock_correction` value.
-  pub fn get_rock_correction(&self) -> f64 {
-    match self.rock_correction {
-      RockCorrectionChoice::Value(v) => v,
-      RockCorrectionChoice::Constructor(c) => c.calculate(),
-    }
+  #[cfg(feature = "serde")]
+  #[test]
+  fn test_serde_round_trip() {
+    let setup = Setup::new();
+
+    let rock_correction = RockCorrection::new(setup.left_on_sieve_weight, setup.pre_sieve_rock_correction);
+    let lab_max_correction = LabMaxCorrection::new(RockCorrectionChoice::Constructor(rock_correction), setup.lab_max, None);
+
+    let json = serde_json::to_string(&lab_max_correction).unwrap();
+    assert_eq!(
+      r#"{"rock_correction":{"Constructor":{"left_on_sieve_weight":100.0,"pre_sieve_rock_correction":500.0}},"lab_max":135.6,"specific_gravity":null}"#,
+      json
+    );
+
+    let deserialized: LabMaxCorrection = serde_json::from_str(&json).unwrap();
+    assert_eq!(lab_max_correction.calculate(), deserialized.calculate());
+
+    let value: RockCorrectionChoice = serde_json::from_str(r#"{"Value":0.2}"#).unwrap();
+    assert_eq!(Ok(0.2), LabMaxCorrection::new(value, setup.lab_max, None).get_rock_correction());
+
+    let sand_used: SandUsed = serde_json::from_str(r#"{"cone_pre_test":14.65,"cone_post_test":8.75,"sand_in_cone":null}"#).unwrap();
+    assert_eq!(Ok(2.31), sand_used.calculate());
   }
 
-  /// Getter for `lab_max` value.
-  pub fn get_lab_max(&self) -> f64 {
-    self.lab_max
+  #[test]
+  fn test_corrected_compaction() {
+    let setup = Setup::new();
+
+    let rock_correction = RockCorrection::new(setup.left_on_sieve_weight, setup.pre_sieve_rock_correction);
+    let corrected_compaction = CorrectedCompaction::new(DryDensityChoice::Value(133.), setup.lab_max, RockCorrectionChoice::Constructor(rock_correction), None);
+
+    let lab_max_correction = LabMaxCorrection::new(RockCorrectionChoice::Constructor(rock_correction), setup.lab_max, None);
+    let compaction = Compaction::new(DryDensityChoice::Value(133.), lab_max_correction.calculate_raw().unwrap());
+
+    assert_eq!(lab_max_correction, corrected_compaction.lab_max_correction());
+    assert_eq!(Ok(compaction), corrected_compaction.compaction());
+    assert_eq!(compaction.calculate(), corrected_compaction.calculate());
+    assert_eq!(Ok(95.2), corrected_compaction.calculate());
+    assert_eq!(Ok(139.7), corrected_compaction.corrected_lab_max());
+    assert_eq!(Ok(2.7), corrected_compaction.get_specific_gravity());
+    assert_eq!(Ok(0.2), corrected_compaction.get_rock_correction());
+    assert_eq!("Corrected Compaction: 133 / 139.7 * 100 = 95.2%", corrected_compaction.to_string());
+
+    let detailed = corrected_compaction.calculate_detailed().unwrap();
+    assert_eq!(lab_max_correction.calculate_raw().ok(), detailed.get("corrected_lab_max"));
+
+    // Heavier oversize raises the corrected lab max, lowering the compaction.
+    let heavier = CorrectedCompaction::new(DryDensityChoice::Value(133.), setup.lab_max, RockCorrectionChoice::Value(0.2), Some(SpecificGravityChoice::Value(2.8)));
+    assert!(heavier.calculate().unwrap() < corrected_compaction.calculate().unwrap());
   }
 
-  /// Getter for `specific_gravity`.
-  ///
-  /// If no value is provided, the default is set to the `SPECIFIC_GRAVITY` constant.
-  pub fn get_specific_gravity(&self) -> f64 {
-    match self.specific_gravity {
-      Some(v) => v,
-      None => SPECIFIC_GRAVITY,
-    }
+  #[test]
+  fn test_corrected_compaction_errors() {
+    let corrected_compaction = CorrectedCompaction::new(DryDensityChoice::Value(133.), 135.6, RockCorrectionChoice::Constructor(RockCorrection::new(100., 0.)), None);
+
+    assert_eq!(Err(CalcError::DivisionByZero), corrected_compaction.calculate());
+    assert_eq!(Err(CalcError::DivisionByZero), corrected_compaction.compaction());
+    assert_eq!("Corrected Compaction: 133 / [division by zero] * 100 = [division by zero]", corrected_compaction.to_string());
+    assert_eq!(Err(CalcError::OutOfRange), CorrectedCompaction::new(DryDensityChoice::Value(133.), 135.6, RockCorrectionChoice::Value(1.2), None).calculate());
+
+    assert!(CorrectedCompaction::try_new(DryDensityChoice::Value(-133.), 135.6, RockCorrectionChoice::Value(0.2), None).is_err());
+    assert!(CorrectedCompaction::try_new(DryDensityChoice::Value(133.), 135.6, RockCorrectionChoice::Value(f64::NAN), None).is_err());
+    assert!(CorrectedCompaction::try_new(DryDensityChoice::Value(133.), 135.6, RockCorrectionChoice::Value(0.2), None).is_ok());
   }
-}
 
+  #[test]
+  fn test_interpolated_lab_max() {
+    let interpolated = InterpolatedLabMax::new((0.1, 130.), (0.3, 138.), RockCorrectionChoice::Constructor(RockCorrection::new(100., 500.)));
 
-#[cfg(test)]
-mod tests {
-  use super::*;
+    assert_eq!(Ok(134.), interpolated.calculate());
+    assert_eq!((0.1, 130.), interpolated.get_lower());
+    assert_eq!((0.3, 138.), interpolated.get_upper());
+    assert_eq!(Ok(0.2), interpolated.get_rock_correction());
+    assert_eq!("Interpolated Lab Max: 130 + (138 - 130) * (0.2 - 0.1) / (0.3 - 0.1) = 134 pcf", interpolated.to_string());
+    assert_eq!(Some(0.2), interpolated.calculate_detailed().unwrap().get("rock_correction"));
+
+    // The ends of the range are the reference lab maxes, in either order.
+    assert_eq!(Ok(130.), InterpolatedLabMax::new((0.1, 130.), (0.3, 138.), RockCorrectionChoice::Value(0.1)).calculate());
+    assert_eq!(Ok(138.), InterpolatedLabMax::new((0.3, 138.), (0.1, 130.), RockCorrectionChoice::Value(0.3)).calculate());
+    assert_eq!(Ok(135.6), InterpolatedLabMax::new((0.3, 138.), (0.1, 130.), RockCorrectionChoice::Value(0.24)).calculate());
+  }
 
   #[test]
-  fn test_sand_used_new() {
-    // Test with Some() value.
-    let sand_used_some = SandUsed::new(10., 15., Some(20.));
+  fn test_interpolated_lab_max_errors() {
+    assert_eq!(Err(CalcError::OutOfRange), InterpolatedLabMax::new((0.1, 130.), (0.3, 138.), RockCorrectionChoice::Value(0.35)).calculate());
+    assert_eq!(Err(CalcError::OutOfRange), InterpolatedLabMax::new((0.1, 130.), (0.3, 138.), RockCorrectionChoice::Value(0.05)).calculate());
+    assert_eq!(Err(CalcError::OutOfRange), InterpolatedLabMax::new((0.1, 130.), (1.5, 150.), RockCorrectionChoice::Value(1.2)).calculate());
+    assert_eq!(Err(CalcError::DivisionByZero), InterpolatedLabMax::new((0.2, 130.), (0.2, 138.), RockCorrectionChoice::Value(0.2)).calculate());
+    assert_eq!(Err(CalcError::DivisionByZero), InterpolatedLabMax::new((0.1, 130.), (0.3, 138.), RockCorrectionChoice::Constructor(RockCorrection::new(100., 0.))).calculate());
 
-    assert_eq!(10., sand_used_some.get_cone_pre_test());
-    assert_eq!(15., sand_used_some.get_cone_post_test());
-    assert_eq!(20., sand_used_some.get_sand_in_cone());
+    assert_eq!("upper_lab_max", InterpolatedLabMax::try_new((0.1, 130.), (0.3, -138.), RockCorrectionChoice::Value(0.2)).unwrap_err().field);
+    assert!(InterpolatedLabMax::try_new((0.1, 130.), (0.3, 138.), RockCorrectionChoice::Value(0.2)).is_ok());
+  }
 
-    // Test with None, (default), value.
-    let sand_used_none = SandUsed::new(10., 15., None);
+  #[test]
+  fn test_from_drying_series() {
+    let moisture_content = MoistureContent::from_drying_series(1620., &[1420., 1402., 1400.5, 1400.2], 200.).unwrap();
 
-    assert_eq!(10., sand_used_none.get_cone_pre_test());
-    assert_eq!(15., sand_used_none.get_cone_post_test());
-    assert_eq!(SAND_IN_CONE, sand_used_none.get_sand_in_cone());
+    assert_eq!(MoistureContent::new(1620., 1400.2, 200.), moisture_content);
+
+    // An unchanged last reading is at constant mass.
+    assert!(MoistureContent::from_drying_series(1620., &[1420., 1400., 1400.], 200.).is_ok());
+
+    assert_eq!(Err(CalcError::NotStabilized), MoistureContent::from_drying_series(1620., &[1420., 1402., 1400.], 200.));
+    assert_eq!(Err(CalcError::NotStabilized), MoistureContent::from_drying_series(1620., &[1420., 1400., 1400.5, 1400.2], 200.));
+    assert_eq!(Err(CalcError::InsufficientData), MoistureContent::from_drying_series(1620., &[1400.], 200.));
+    assert_eq!(Err(CalcError::InsufficientData), MoistureContent::from_drying_series(1620., &[], 200.));
+    assert_eq!(Err(CalcError::DivisionByZero), MoistureContent::from_drying_series(1620., &[200., 200.], 200.));
   }
 
   #[test]
-  fn test_sand_used_calculate() {
-    let setup = Setup::new();
+  fn test_compaction_from_wet() {
+    let from_wet = Compaction::from_wet(WetDensityChoice::Value(177.1429), MoistureContentChoice::Value(0.14285714), 135.6);
+    let dry_density = DryDensity::new(WetDensityChoice::Value(177.1429), MoistureContentChoice::Value(0.14285714));
 
-    // Test with a Some() value.
-    let sand_used_some = SandUsed::new(setup.cone_pre_test, setup.cone_post_test, Some(3.59));
-    assert_eq!(2.31, sand_used_some.calculate());
+    assert_eq!(Compaction::new(DryDensityChoice::Constructor(dry_density), 135.6), from_wet);
+    assert_eq!(Ok(114.3), from_wet.calculate());
+    assert_eq!(dry_density.calculate_raw(), from_wet.get_dry_density());
 
-    // Test with a None, (default), value.
-    let sand_used_none = SandUsed::new(setup.cone_pre_test, setup.cone_post_test, None);
-    assert_eq!(2.31, sand_used_none.calculate());
+    let moisture_content = MoistureContent::new(1600., 1575., 1575.);
+    assert_eq!(Err(CalcError::DivisionByZero), Compaction::from_wet(WetDensityChoice::Value(177.1429), moisture_content.into(), 135.6).calculate());
   }
 
   #[test]
-  fn test_wet_density_new() {
-    let some = WetDensity::new(10., 15., Some(20.));
+  fn test_percentage() {
+    let setup = Setup::new();
 
-    assert_eq!(10., some.get_soil());
-    assert_eq!(15., some.get_sand_used());
-    assert_eq!(20., some.get_sand_density());
+    let moisture_content = MoistureContent::new(setup.wet_weight, setup.dry_weight, setup.tare_pan);
+    assert_eq!(Ok(0.14285714), moisture_content.percentage().map(|p| p.as_fraction()));
 
-    let none = WetDensity::new(10., 15., None);
+    let rock_correction = RockCorrection::new(setup.left_on_sieve_weight, setup.pre_sieve_rock_correction);
+    assert_eq!(Ok(Percentage::from_fraction(0.2)), rock_correction.percentage());
+    assert_eq!(Ok(20.), rock_correction.percentage().map(|p| p.as_percent()));
 
-    assert_eq!(10., none.get_soil());
-    assert_eq!(15., some.get_sand_used());
-    assert_eq!(20., some.get_sand_density());
+    let compaction = Compaction::new(DryDensityChoice::Value(155.), setup.lab_max);
+    assert_eq!(Ok(Percentage::from_percent(114.3)), compaction.percentage());
+
+    // Either form passes on the fraction.
+    let from_fraction = LabMaxCorrection::new(Percentage::from_fraction(0.2).into(), setup.lab_max, None);
+    let from_percent = LabMaxCorrection::new(Percentage::from_percent(20.).into(), setup.lab_max, None);
+    assert_eq!(Ok(139.7), from_fraction.calculate());
+    assert_eq!(from_fraction.calculate(), from_percent.calculate());
+
+    assert_eq!(Err(CalcError::DivisionByZero), RockCorrection::new(100., 0.).percentage());
   }
 
   #[test]
-  fn test_wet_density_calculate() {
+  fn test_plasticity_index_new() {
+    let new = PlasticityIndex::new(10., 15.);
+
+    assert_eq!(10., new.get_liquid_limit());
+    assert_eq!(15., new.get_plastic_limit());
+
+    assert_eq!(Err(ValidationError::new("plastic_limit", ValidationErrorKind::NaN)), PlasticityIndex::try_new(45., f64::NAN).map(|_| ()));
+  }
+
+  #[test]
+  fn test_plasticity_index_calculate() {
     let setup = Setup::new();
 
-    let some = WetDensity::new(setup.soil, 2.31, Some(88.));
-    assert_eq!(177.1429, some.calculate());
+    let plasticity_index = PlasticityIndex::new(setup.liquid_limit, setup.plastic_limit);
+    assert_eq!(Ok(23.), plasticity_index.calculate());
 
-    let none = WetDensity::new(setup.soil, 2.31, None);
-    assert_eq!(177.1429, none.calculate());
+    let value = plasticity_index.liquidity_index(MoistureContentChoice::Value(0.3));
+    assert_eq!(Ok(0.35), value);
+
+    // 0.14285714 as a percent is 14.29, below the plastic limit.
+    let moisture_content = MoistureContent::new(setup.wet_weight, setup.dry_weight, setup.tare_pan);
+    let constructor = plasticity_index.liquidity_index(MoistureContentChoice::Constructor(moisture_content));
+    assert_eq!(Ok(-0.34), constructor);
+
+    let non_plastic = PlasticityIndex::new(setup.plastic_limit, setup.plastic_limit);
+    assert_eq!(Err(CalcError::DivisionByZero), non_plastic.liquidity_index(MoistureContentChoice::Value(0.3)));
+    assert_eq!(Ok(SwellPotential::Low), non_plastic.swell_potential(60.));
+    assert_eq!(Ok(SwellPotential::High), PlasticityIndex::new(45., 22.).swell_potential(60.));
   }
 
   #[test]
-  fn test_moisture_content_new() {
-    let new = MoistureContent::new(10., 15., 20.);
+  fn test_void_ratio_new() {
+    let value = VoidRatio::new(DryDensityChoice::Value(10.), Some(2.65));
 
-    assert_eq!(10., new.get_wet_weight());
-    assert_eq!(15., new.get_dry_weight());
-    assert_eq!(20., new.get_tare_pan());
+    assert_eq!(Ok(10.), value.get_dry_density());
+    assert_eq!(2.65, value.get_specific_gravity());
+
+    let none = VoidRatio::new(DryDensityChoice::Value(10.), None);
+    assert_eq!(SPECIFIC_GRAVITY, none.get_specific_gravity());
+
+    assert_eq!(Err(ValidationError::new("dry_density", ValidationErrorKind::Negative)), VoidRatio::try_new(DryDensityChoice::Value(-10.), None).map(|_| ()));
   }
 
   #[test]
-  fn test_moisture_content_calculate() {
+  fn test_void_ratio_calculate() {
+    let value = VoidRatio::new(DryDensityChoice::Value(110.), None);
+    assert_eq!(Ok(0.532), value.calculate());
+
+    let dry_density = DryDensity::new(WetDensityChoice::Value(177.1429), MoistureContentChoice::Value(0.1428571));
+    let constructor = VoidRatio::new(DryDensityChoice::Constructor(dry_density), None);
+    assert_eq!(Ok(0.087), constructor.calculate());
+
+    let zero = VoidRatio::new(DryDensityChoice::Value(0.), None);
+    assert_eq!(Err(CalcError::DivisionByZero), zero.calculate());
+  }
+
+  #[test]
+  fn test_porosity_new() {
+    let value = Porosity::new(DryDensityChoice::Value(10.), Some(2.65));
+
+    assert_eq!(Ok(10.), value.get_dry_density());
+    assert_eq!(2.65, value.get_specific_gravity());
+
+    let none = Porosity::new(DryDensityChoice::Value(10.), None);
+    assert_eq!(SPECIFIC_GRAVITY, none.get_specific_gravity());
+  }
+
+  #[test]
+  fn test_porosity_calculate() {
+    let value = Porosity::new(DryDensityChoice::Value(110.), None);
+    assert_eq!(Ok(0.347), value.calculate());
+
+    // Matches `e / (1 + e)` for the same inputs.
+    let e = 2.7 * UNIT_WEIGHT_WATER / 110. - 1.;
+    assert_eq!(value.calculate(), value.round_n(e / (1. + e), 3));
+
+    let zero = Porosity::new(DryDensityChoice::Value(110.), Some(0.));
+    assert_eq!(Err(CalcError::DivisionByZero), zero.calculate());
+  }
+
+  #[test]
+  fn test_saturation_new() {
+    let value = Saturation::new(MoistureContentChoice::Value(10.), VoidRatioChoice::Value(15.), Some(2.65));
+
+    assert_eq!(Ok(10.), value.get_moisture_content());
+    assert_eq!(Ok(15.), value.get_void_ratio());
+    assert_eq!(2.65, value.get_specific_gravity());
+
+    let none = Saturation::new(MoistureContentChoice::Value(10.), VoidRatioChoice::Value(15.), None);
+    assert_eq!(SPECIFIC_GRAVITY, none.get_specific_gravity());
+
+    assert_eq!(Err(ValidationError::new("void_ratio", ValidationErrorKind::NaN)), Saturation::try_new(MoistureContentChoice::Value(10.), VoidRatioChoice::Value(f64::NAN), None).map(|_| ()));
+  }
+
+  #[test]
+  fn test_saturation_calculate() {
     let setup = Setup::new();
 
-    let calc = MoistureContent::new(setup.wet_weight, setup.dry_weight, setup.tare_pan);
-    assert_eq!(0.14285714, calc.calculate());
+    let value = Saturation::new(MoistureContentChoice::Value(0.1428571), VoidRatioChoice::Value(0.532), None);
+    assert_eq!(Ok(72.5), value.calculate());
+
+    let moisture_content = MoistureContent::new(setup.wet_weight, setup.dry_weight, setup.tare_pan);
+    let void_ratio = VoidRatio::new(DryDensityChoice::Value(110.), None);
+    let constructor = Saturation::new(MoistureContentChoice::Constructor(moisture_content), VoidRatioChoice::Constructor(void_ratio), None);
+    assert_eq!(Ok(72.6), constructor.calculate());
+
+    let zero = Saturation::new(MoistureContentChoice::Value(0.1428571), VoidRatioChoice::Value(0.), None);
+    assert_eq!(Err(CalcError::DivisionByZero), zero.calculate());
   }
 
   #[test]
-  fn test_dry_density_new() {
+  fn test_display() {
     let setup = Setup::new();
 
-    let value = DryDensity::new(WetDensityChoice::Value(10.), MoistureContentChoice::Value(15.));
+    let sand_used = SandUsed::new(setup.cone_pre_test, setup.cone_post_test, None);
+    assert_eq!("Sand Used: 14.65 - (8.75 + 3.59) = 2.31 lb", sand_used.to_string());
 
-    assert_eq!(10., value.get_wet_density());
-    assert_eq!(15., value.get_moisture_content());
+    let wet_density = WetDensity::new(setup.soil, SandUsedChoice::Constructor(sand_used), None);
+    assert_eq!("Wet Density: (4.65 / 2.31) * 88 = 177.1429 pcf", wet_density.to_string());
 
-    let wet_density = WetDensity::new(setup.soil, 2.31, None);
     let moisture_content = MoistureContent::new(setup.wet_weight, setup.dry_weight, setup.tare_pan);
+    assert_eq!("Moisture Content: (1600 - 1575) / (1575 - 1400) = 0.14285714", moisture_content.to_string());
 
-    let constructor = DryDensity::new(WetDensityChoice::Constructor(wet_density), MoistureContentChoice::Constructor(moisture_content));
+    let dry_density = DryDensity::new(WetDensityChoice::Value(177.1429), MoistureContentChoice::Value(0.1428571));
+    assert_eq!("Dry Density: 177.1429 / (1 + 0.1428571) = 155 pcf", dry_density.to_string());
+
+    let compaction = Compaction::new(DryDensityChoice::Constructor(dry_density), setup.lab_max);
+    assert_eq!("Compaction: 155 / 135.6 * 100 = 114.3%", compaction.to_string());
+
+    let rock_correction = RockCorrection::new(setup.left_on_sieve_weight, setup.pre_sieve_rock_correction);
+    assert_eq!("Rock Correction: 100 / 500 = 0.2", rock_correction.to_string());
 
-    assert_eq!(177.1429, constructor.get_wet_density());
-    assert_eq!(0.14285714, constructor.get_moisture_content());
+    let lab_max_correction = LabMaxCorrection::new(RockCorrectionChoice::Constructor(rock_correction), setup.lab_max, None);
+    assert_eq!("Lab Max Correction: (1 - 0.05 * 0.2) / (0.2 / (62.4 * 2.7) + (1 - 0.2) / 135.6) = 139.7 pcf", lab_max_correction.to_string());
+
+    let plasticity_index = PlasticityIndex::new(setup.liquid_limit, setup.plastic_limit);
+    assert_eq!("Plasticity Index: 45 - 22 = 23", plasticity_index.to_string());
+
+    let void_ratio = VoidRatio::new(DryDensityChoice::Value(110.), None);
+    assert_eq!("Void Ratio: (2.7 * 62.4 / 110) - 1 = 0.532", void_ratio.to_string());
+
+    let porosity = Porosity::new(DryDensityChoice::Value(110.), None);
+    assert_eq!("Porosity: 1 - 110 / (2.7 * 62.4) = 0.347", porosity.to_string());
+
+    let saturation = Saturation::new(MoistureContentChoice::Value(0.1428571), VoidRatioChoice::Constructor(void_ratio), None);
+    assert_eq!("Saturation: (0.1428571 * 2.7 / 0.532) * 100 = 72.6%", saturation.to_string());
   }
 
   #[test]
-  fn test_dry_density_calculate() {
+  fn test_display_error() {
+    let wet_density = WetDensity::new(4.65, SandUsedChoice::Value(0.), None);
+    assert_eq!("Wet Density: (4.65 / 0) * 88 = [division by zero]", wet_density.to_string());
+
+    let dry_density = DryDensity::new(WetDensityChoice::Constructor(wet_density), MoistureContentChoice::Value(0.1428571));
+    assert_eq!("Dry Density: [division by zero] / (1 + 0.1428571) = [division by zero]", dry_density.to_string());
+  }
+
+  #[test]
+  fn test_f32() {
+    let sand_used: SandUsed<f32> = SandUsed::new(14.65, 8.75, None);
+    assert_eq!(Ok(2.31_f32), sand_used.calculate());
+
+    let wet_density = WetDensity::new(4.65_f32, SandUsedChoice::Constructor(sand_used), None);
+    assert_eq!(Ok(177.1429_f32), wet_density.calculate());
+
+    let dry_density = DryDensity::new(WetDensityChoice::Constructor(wet_density), MoistureContentChoice::Value(0.1428571_f32));
+    let compaction = Compaction::new(DryDensityChoice::Constructor(dry_density), 135.6_f32);
+    assert_eq!(Ok(114.3_f32), compaction.calculate());
+
+    let lab_max_correction = LabMaxCorrection::new(RockCorrectionChoice::Value(0.2_f32), 135.6, None);
+    assert_eq!(Ok(139.7_f32), lab_max_correction.calculate());
+
+    assert_eq!(Err(CalcError::DivisionByZero), WetDensity::new(4.65_f32, SandUsedChoice::Value(0.), None).calculate());
+  }
+
+  #[test]
+  fn test_relative_density() {
+    let from_void_ratios = RelativeDensity::new(0.8, 0.4, VoidRatioChoice::Value(0.55));
+    assert_eq!(Ok(62.5), from_void_ratios.calculate());
+    assert_eq!("Relative Density: (0.8 - 0.55) / (0.8 - 0.4) * 100 = 62.5%", from_void_ratios.to_string());
+
+    let void_ratio = VoidRatio::new(DryDensityChoice::Value(110.), None);
+    assert_eq!(Ok(67.1), RelativeDensity::new(0.8, 0.4, VoidRatioChoice::Constructor(void_ratio)).calculate());
+
+    let from_dry_densities = RelativeDensity::from_dry_densities(110., 90., DryDensityChoice::Value(100.));
+    assert_eq!(Ok(55.), from_dry_densities.calculate());
+    assert_eq!("Relative Density: 110 * (100 - 90) / (100 * (110 - 90)) * 100 = 55%", from_dry_densities.to_string());
+
+    assert_eq!(Err(CalcError::DivisionByZero), RelativeDensity::new(0.5, 0.5, VoidRatioChoice::Value(0.5)).calculate());
+    assert_eq!(Err(CalcError::DivisionByZero), RelativeDensity::from_dry_densities(100., 100., DryDensityChoice::Value(100.)).calculate());
+  }
+
+  #[test]
+  fn test_relative_density_try_new() {
+    assert!(RelativeDensity::try_new(0.8, 0.4, VoidRatioChoice::Value(0.55)).is_ok());
+    assert_eq!("min_void_ratio", RelativeDensity::try_new(0.8, -0.4, VoidRatioChoice::Value(0.55)).unwrap_err().field);
+    assert_eq!("dry_density", RelativeDensity::try_from_dry_densities(110., 90., DryDensityChoice::Value(f64::NAN)).unwrap_err().field);
+  }
+
+  #[test]
+  fn test_approx_eq() {
+    let sand_used = SandUsed::new(14.65, 8.75, None);
+    assert!(sand_used.approx_eq(2.31, 1e-9));
+    assert!(!sand_used.approx_eq(2.3, 1e-9));
+    assert!(!WetDensity::new(4.65, SandUsedChoice::Value(0.), None).approx_eq(0., 1.));
+  }
+
+  #[test]
+  fn test_partial_eq() {
     let setup = Setup::new();
 
-    let value = DryDensity::new(WetDensityChoice::Value(177.1429), MoistureContentChoice::Value(0.1428571));
-    assert_eq!(155., value.calculate());
+    let rock_correction = RockCorrection::new(setup.left_on_sieve_weight, setup.pre_sieve_rock_correction);
+    assert_eq!(rock_correction, RockCorrection::new(100., 500.));
+    assert_ne!(rock_correction, RockCorrection::new(100., 400.));
 
-    let wet_density = WetDensity::new(setup.soil, 2.31, None);
-    let moisture_content = MoistureContent::new(setup.wet_weight, setup.dry_weight, setup.tare_pan);
+    let lab_max_correction = LabMaxCorrection::new(RockCorrectionChoice::Constructor(rock_correction), setup.lab_max, None);
+    assert_eq!(Ok(lab_max_correction), LabMaxCorrection::try_new(RockCorrectionChoice::Constructor(rock_correction), setup.lab_max, None));
+    assert_ne!(lab_max_correction, LabMaxCorrection::new(RockCorrectionChoice::Value(0.2), setup.lab_max, None));
+  }
 
-    let constructor = DryDensity::new(WetDensityChoice::Constructor(wet_density), MoistureContentChoice::Constructor(moisture_content));
-    assert_eq!(155., constructor.calculate());
+  #[test]
+  fn test_specific_gravity() {
+    let specific_gravity = SpecificGravity::new(100., 650., 712.5, None);
+    assert_eq!(Ok(2.67), specific_gravity.calculate());
+    assert_eq!(1., specific_gravity.get_temperature_correction());
+    assert_eq!("Specific Gravity: 1 * 100 / (100 + 650 - 712.5) = 2.67", specific_gravity.to_string());
+
+    assert_eq!(Ok(2.66), SpecificGravity::new(100., 650., 712.5, Some(0.9983)).calculate());
+    assert_eq!(Err(CalcError::DivisionByZero), SpecificGravity::new(100., 650., 750., None).calculate());
+    assert_eq!("temperature_correction", SpecificGravity::try_new(100., 650., 712.5, Some(f64::NAN)).unwrap_err().field);
+
+    let lab_max_correction = LabMaxCorrection::new(RockCorrectionChoice::Value(0.2), 135.6, Some(SpecificGravityChoice::Constructor(specific_gravity)));
+    assert_eq!(specific_gravity.calculate_raw(), lab_max_correction.get_specific_gravity());
+    assert_eq!(Ok(139.4), lab_max_correction.calculate());
+
+    let invalid = LabMaxCorrection::new(RockCorrectionChoice::Value(0.2), 135.6, Some(SpecificGravityChoice::Constructor(SpecificGravity::new(100., 650., 750., None))));
+    assert_eq!(Err(CalcError::DivisionByZero), invalid.calculate());
   }
 
   #[test]
-  fn test_compaction_new() {
-    let value = Compaction::new(DryDensityChoice::Value(10.), 15.);
-    assert_eq!(10., value.get_dry_density());
-    assert_eq!(15., value.get_lab_max());
+  fn test_one_point_proctor() {
+    let one_point = OnePointProctor::new(DryDensityChoice::Value(118.06), MoistureContentChoice::Value(0.1), None, None);
+    assert_eq!(Ok(120.), one_point.calculate());
+    assert_eq!(PROCTOR_CURVE_COEFFICIENT, one_point.get_coefficient());
+    assert_eq!("One Point Proctor: 118.06 pcf at 0.1 moisture = 120 pcf", one_point.to_string());
 
-    let dry_density = DryDensity::new(WetDensityChoice::Value(177.1429), MoistureContentChoice::Value(0.1428571));
+    // A flatter family of curves needs a smaller correction.
+    assert_eq!(Ok(119.1), OnePointProctor::new(DryDensityChoice::Value(118.06), MoistureContentChoice::Value(0.1), Some(0.2), None).calculate());
 
-    let constructor = Compaction::new(DryDensityChoice::Constructor(dry_density), 15.);
-    assert_eq!(155., constructor.get_dry_density());
-    assert_eq!(15., constructor.get_lab_max());
+    // A point at optimum is its own maximum.
+    assert_eq!(Ok(118.1), OnePointProctor::new(DryDensityChoice::Value(118.06), MoistureContentChoice::Value(0.1), Some(0.), None).calculate());
+
+    let moisture_content = MoistureContent::new(1600., 1575., 1400.);
+    let chained = OnePointProctor::new(DryDensityChoice::Value(110.), MoistureContentChoice::Constructor(moisture_content), None, None);
+    assert_eq!(Ok(110.7), chained.calculate());
+
+    assert_eq!(Err(CalcError::OutOfRange), OnePointProctor::new(DryDensityChoice::Value(200.), MoistureContentChoice::Value(0.1), None, None).calculate());
+    assert_eq!(Err(CalcError::DivisionByZero), OnePointProctor::new(DryDensityChoice::Value(110.), MoistureContentChoice::Value(0.1), None, Some(0.)).calculate());
+    assert_eq!("coefficient", OnePointProctor::try_new(DryDensityChoice::Value(110.), MoistureContentChoice::Value(0.1), Some(-1.), None).unwrap_err().field);
   }
 
   #[test]
-  fn test_compaction_calculate() {
+  fn test_compaction_spec() {
     let setup = Setup::new();
 
-    let value = Compaction::new(DryDensityChoice::Value(155.), setup.lab_max);
-    assert_eq!(114.3, value.calculate());
+    // 114.3% after rounding.
+    let compaction = Compaction::new(DryDensityChoice::Value(155.), setup.lab_max);
+    assert_eq!(Ok(true), compaction.is_passing(95.));
+    assert_eq!(Ok(true), compaction.is_passing(114.3));
+    assert_eq!(Ok(false), compaction.is_passing(114.4));
+    assert_eq!(Ok(19.3), compaction.margin(95.));
+    assert_eq!(Ok(-0.1), compaction.margin(114.4));
 
-    let dry_density = DryDensity::new(WetDensityChoice::Value(177.1429), MoistureContentChoice::Value(0.1428571));
-    let constructor = Compaction::new(DryDensityChoice::Constructor(dry_density), setup.lab_max);
+    // 94.96% unrounded, which rounds up to a passing 95%.
+    let rounded_up = Compaction::new(DryDensityChoice::Value(128.77), setup.lab_max);
+    assert_eq!(Ok(true), rounded_up.is_passing(95.));
+    assert_eq!(Ok(0.), rounded_up.margin(95.));
 
-    assert_eq!(114.3, constructor.calculate());
+    let invalid = Compaction::new(DryDensityChoice::Constructor(DryDensity::new(WetDensityChoice::Value(177.1429), MoistureContentChoice::Value(-1.))), setup.lab_max);
+    assert_eq!(Err(CalcError::DivisionByZero), invalid.is_passing(95.));
+    assert_eq!(Err(CalcError::DivisionByZero), invalid.margin(95.));
+    assert_eq!(Err(CalcError::DivisionByZero), invalid.format_with_spec(95.));
   }
 
   #[test]
-  fn test_rock_correction_new() {
-    let new = RockCorrection::new(10., 15.);
+  fn test_bulk_specific_gravity() {
+    let bulk_specific_gravity = BulkSpecificGravity::new(1200., 1205.3, 695.1);
 
-    assert_eq!(10., new.get_left_on_sieve_weight());
-    assert_eq!(15., new.get_pre_sieve_rock_correction());
+    assert_eq!(1200., bulk_specific_gravity.get_dry_mass());
+    assert_eq!(1205.3, bulk_specific_gravity.get_ssd_mass());
+    assert_eq!(695.1, bulk_specific_gravity.get_submerged_mass());
+    assert_eq!(Ok(2.352), bulk_specific_gravity.calculate());
+    assert_eq!("Bulk Specific Gravity: 1200 / (1205.3 - 695.1) = 2.352", bulk_specific_gravity.to_string());
+
+    // Feeds `AsphaltCompaction` unrounded.
+    let compaction = AsphaltCompaction::new(BulkSpecificGravityChoice::Constructor(bulk_specific_gravity), 2.498);
+    assert_eq!(bulk_specific_gravity.calculate_raw(), compaction.get_bulk_specific_gravity());
+    assert_eq!(Ok(94.2), compaction.calculate());
+    assert_eq!("Asphalt Compaction: 2.352 / 2.498 * 100 = 94.2% Gmm", compaction.to_string());
+
+    assert_eq!(Err(CalcError::OutOfRange), BulkSpecificGravity::new(1200., 695.1, 1205.3).calculate());
+    assert_eq!(Err(CalcError::OutOfRange), BulkSpecificGravity::new(1200., 700., 700.).calculate());
+    assert_eq!(Err(CalcError::OutOfRange), AsphaltCompaction::new(BulkSpecificGravity::new(1200., 700., 700.).into(), 2.498).calculate());
+
+    assert_eq!("submerged_mass", BulkSpecificGravity::try_new(1200., 1205.3, f64::NAN).unwrap_err().field);
+    assert!(BulkSpecificGravity::try_new(1200., 1205.3, 695.1).is_ok());
   }
 
   #[test]
-  fn test_rock_correction_calculate() {
+  fn test_asphalt_compaction() {
+    let compaction = AsphaltCompaction::new(BulkSpecificGravityChoice::Value(2.4), 2.5);
+
+    assert_eq!(Ok(2.4), compaction.get_bulk_specific_gravity());
+    assert_eq!(2.5, compaction.get_max_specific_gravity());
+    assert_eq!(Ok(96.), compaction.calculate());
+    assert_eq!(Ok(4.), compaction.air_voids());
+    assert_eq!(Ok(true), compaction.is_passing(96.));
+    assert_eq!(Ok(false), compaction.is_passing(96.1));
+    assert_eq!(Ok(4.), compaction.margin(92.));
+    assert_eq!("Asphalt Compaction: 2.4 / 2.5 * 100 = 96% Gmm", compaction.to_string());
+    assert_eq!(Some(2.5), compaction.calculate_detailed().unwrap().get("max_specific_gravity"));
+
+    let zero = AsphaltCompaction::new(BulkSpecificGravityChoice::Value(2.4), 0.);
+    assert_eq!(Err(CalcError::DivisionByZero), zero.calculate());
+    assert_eq!(Err(CalcError::DivisionByZero), zero.is_passing(92.));
+    assert_eq!(Err(CalcError::DivisionByZero), zero.air_voids());
+
+    assert_eq!("max_specific_gravity", AsphaltCompaction::try_new(BulkSpecificGravityChoice::Value(2.4), -2.5).unwrap_err().field);
+    assert!(AsphaltCompaction::try_new(BulkSpecificGravityChoice::Value(2.4), 2.5).is_ok());
+  }
+
+  #[test]
+  fn test_air_voids() {
+    let air_voids = AirVoids::new(BulkSpecificGravityChoice::Value(2.4), 2.5);
+
+    assert_eq!(Ok(2.4), air_voids.get_bulk_specific_gravity());
+    assert_eq!(2.5, air_voids.get_max_specific_gravity());
+    assert_eq!(Ok(4.), air_voids.calculate());
+    assert_eq!("Air Voids: (1 - 2.4 / 2.5) * 100 = 4%", air_voids.to_string());
+    assert_eq!(Some(2.4), air_voids.calculate_detailed().unwrap().get("bulk_specific_gravity"));
+
+    // The companion of `AsphaltCompaction`.
+    let bulk_specific_gravity = BulkSpecificGravityChoice::Constructor(BulkSpecificGravity::new(1200., 1205.3, 695.1));
+    let compaction = AsphaltCompaction::new(bulk_specific_gravity, 2.498);
+    assert_eq!(Ok(5.8), AirVoids::new(bulk_specific_gravity, 2.498).calculate());
+    assert_eq!(compaction.air_voids(), AirVoids::new(bulk_specific_gravity, 2.498).calculate());
+
+    assert_eq!(Ok(-0.9), AirVoids::new(BulkSpecificGravityChoice::Value(2.52), 2.498).calculate());
+    assert_eq!(Err(CalcError::DivisionByZero), AirVoids::new(BulkSpecificGravityChoice::Value(2.4), 0.).calculate());
+    assert_eq!(Err(CalcError::OutOfRange), AirVoids::new(BulkSpecificGravity::new(1200., 700., 700.).into(), 2.498).calculate());
+
+    assert_eq!("bulk_specific_gravity", AirVoids::try_new(BulkSpecificGravityChoice::Value(-2.4), 2.5).unwrap_err().field);
+    assert!(AirVoids::try_new(BulkSpecificGravityChoice::Value(2.4), 2.5).is_ok());
+  }
+
+  #[test]
+  fn test_compaction_format_with_spec() {
     let setup = Setup::new();
 
-    let rc = RockCorrection::new(setup.left_on_sieve_weight, setup.pre_sieve_rock_correction);
+    let passing = Compaction::new(DryDensityChoice::Value(155.), setup.lab_max);
+    assert_eq!(Ok("114.3% (PASS, +19.3)".to_string()), passing.format_with_spec(95.));
+
+    let failing = Compaction::new(DryDensityChoice::Value(124.75), setup.lab_max);
+    assert_eq!(Ok("92.0% (FAIL, -3.0)".to_string()), failing.format_with_spec(95.));
 
-    assert_eq!(0.2, rc.calculate());
+    // Exactly on the spec passes, with a positive zero margin.
+    let on_spec = Compaction::new(DryDensityChoice::Value(128.8), setup.lab_max);
+    assert_eq!(Ok("95.0% (PASS, +0.0)".to_string()), on_spec.format_with_spec(95.));
+
+    let compaction: Compaction<f32> = Compaction::new(DryDensityChoice::Value(124.75), 135.6);
+    assert_eq!(Ok("92.0% (FAIL, -6.0)".to_string()), compaction.format_with_spec(98.));
   }
 
   #[test]
-  fn test_lab_max_correction_new() {
+  fn test_with_profile() {
     let setup = Setup::new();
+    let profile = CalibrationProfile::new(3.62, 89.5, 2.65);
 
-    let value = LabMaxCorrection::new(RockCorrectionChoice::Value(10.), 15., Some(20.));
+    let sand_used = SandUsed::with_profile(setup.cone_pre_test, setup.cone_post_test, &profile);
+    assert_eq!(3.62, sand_used.get_sand_in_cone());
 
-    assert_eq!(10., value.get_rock_correction());
-    assert_eq!(15., value.get_lab_max());
-    assert_eq!(20., value.get_specific_gravity());
+    let wet_density = WetDensity::with_profile(setup.soil, SandUsedChoice::Constructor(sand_used), &profile);
+    assert_eq!(89.5, wet_density.get_sand_density());
 
-    let rock_correction = RockCorrection::new(setup.left_on_sieve_weight, setup.pre_sieve_rock_correction);
-    let constructor = LabMaxCorrection::new(RockCorrectionChoice::Constructor(rock_correction), 15., Some(20.));
+    let lab_max_correction = LabMaxCorrection::with_profile(RockCorrectionChoice::Value(0.2), setup.lab_max, &profile);
+    assert_eq!(Ok(2.65), lab_max_correction.get_specific_gravity());
 
-    assert_eq!(0.2, constructor.get_rock_correction());
-    assert_eq!(15., constructor.get_lab_max());
-    assert_eq!(20., constructor.get_specific_gravity());
+    assert_eq!(2.65, VoidRatio::with_profile(DryDensityChoice::Value(110.), &profile).get_specific_gravity());
+    assert_eq!(2.65, Porosity::with_profile(DryDensityChoice::Value(110.), &profile).get_specific_gravity());
+    assert_eq!(2.65, Saturation::with_profile(MoistureContentChoice::Value(0.1), VoidRatioChoice::Value(0.5), &profile).get_specific_gravity());
+    assert_eq!(2.65, OnePointProctor::with_profile(DryDensityChoice::Value(110.), MoistureContentChoice::Value(0.1), None, &profile).get_specific_gravity());
 
-    let none = LabMaxCorrection::new(RockCorrectionChoice::Value(10.), 15., None);
+    // The default profile matches the constants.
+    let default = CalibrationProfile::default();
+    assert_eq!(SandUsed::new(setup.cone_pre_test, setup.cone_post_test, None).calculate(), SandUsed::with_profile(setup.cone_pre_test, setup.cone_post_test, &default).calculate());
+  }
+
+  #[test]
+  fn test_calculate_with_precision() {
+    let setup = Setup::new();
+
+    let wet_density = WetDensity::new(setup.soil, SandUsedChoice::Value(2.31), None);
+
+    assert_eq!(4, wet_density.precision());
+    assert_eq!(Ok(177.1429), wet_density.calculate());
+    assert_eq!(Ok(177.14), wet_density.calculate_with_precision(2));
+    assert_eq!(Ok(180.), wet_density.calculate_with_precision(-1));
+    assert_eq!(Ok(setup.soil / 2.31 * 88.), wet_density.calculate_raw());
 
-    assert_eq!(2.7, none.get_specific_gravity());
+    let dry_density = DryDensity::new(WetDensityChoice::Value(f64::NAN), MoistureContentChoice::Value(0.1));
+    assert_eq!(Err(CalcError::NonFinite), dry_density.calculate_raw());
+    assert_eq!(Err(CalcError::NonFinite), dry_density.calculate_with_precision(2));
   }
 
   #[test]
-  fn test_lab_max_correction_calculate() {
+  fn test_chained_rounding() {
+    // 132.44 / (1 + 0.1) = 120.4 pcf, which rounds to 120 pcf on its own.
+    let dry_density = DryDensity::new(WetDensityChoice::Value(132.44), MoistureContentChoice::Value(0.1));
+    assert_eq!(Ok(120.), dry_density.calculate());
+
+    // The chain is only rounded once, matching 120.4 / 135.6 * 100 = 88.79%, rather than 120 / 135.6 * 100 = 88.5%.
+    let chained = Compaction::new(DryDensityChoice::Constructor(dry_density), 135.6);
+    let exact = Compaction::new(DryDensityChoice::Value(132.44 / 1.1), 135.6);
+
+    assert_eq!(Ok(88.8), chained.calculate());
+    assert_eq!(exact.calculate(), chained.calculate());
+    assert_eq!("Compaction: 120 / 135.6 * 100 = 88.8%", chained.to_string());
+  }
+
+  #[test]
+  fn test_core_sample_density() {
+    let core_sample = CoreSampleDensity::new(3.1, 0.025);
+
+    assert_eq!(3.1, core_sample.get_soil());
+    assert_eq!(0.025, core_sample.get_volume());
+    assert_eq!(Ok(124.), core_sample.calculate());
+    assert_eq!("Core Sample Density: 3.1 / 0.025 = 124 pcf", core_sample.to_string());
+
+    let compaction = Compaction::new(DryDensityChoice::Value(DryDensity::new(WetDensityChoice::Value(124.), MoistureContentChoice::Value(0.1)).calculate().unwrap()), 120.);
+    assert_eq!(Ok(94.2), compaction.calculate());
+
+    assert_eq!(Err(CalcError::DivisionByZero), CoreSampleDensity::new(3.1, 0.).calculate());
+    assert_eq!("volume", CoreSampleDensity::try_new(3.1, -0.025).unwrap_err().field);
+  }
+
+  #[test]
+  fn test_index_densities() {
+    let max_index_density = MaxIndexDensity::new(13.3, 10., 0.03);
+    let min_index_density = MinIndexDensity::new(12.7, 10., 0.03);
+
+    assert_eq!(13.3, max_index_density.get_mold_and_soil());
+    assert_eq!(10., max_index_density.get_mold());
+    assert_eq!(0.03, min_index_density.get_volume());
+    assert_eq!(Ok(110.), max_index_density.calculate());
+    assert_eq!(Ok(90.), min_index_density.calculate());
+    assert_eq!("Max Index Density: (13.3 - 10) / 0.03 = 110 pcf", max_index_density.to_string());
+    assert_eq!("Min Index Density: (12.7 - 10) / 0.03 = 90 pcf", min_index_density.to_string());
+
+    let relative_density = RelativeDensity::from_index_densities(max_index_density, min_index_density, DryDensityChoice::Value(100.)).unwrap();
+    assert_eq!(RelativeDensity::from_dry_densities(110., 90., DryDensityChoice::Value(100.)).calculate(), relative_density.calculate());
+
+    assert_eq!(Err(CalcError::DivisionByZero), RelativeDensity::from_index_densities(max_index_density, MinIndexDensity::new(12.7, 10., 0.), DryDensityChoice::Value(100.)));
+    assert_eq!("mold", MaxIndexDensity::try_new(13.3, -10., 0.03).unwrap_err().field);
+  }
+
+  #[test]
+  fn test_calculate_checked() {
+    assert_eq!(Ok(114.3), Compaction::new(DryDensityChoice::Value(155.), 135.6).calculate_checked());
+    assert_eq!(Err(RangeWarning::AboveRange(142.7)), Compaction::new(DryDensityChoice::Value(135.6), 95.).calculate_checked());
+    assert_eq!(Err(RangeWarning::BelowRange(36.9)), Compaction::new(DryDensityChoice::Value(50.), 135.6).calculate_checked());
+    assert_eq!(Err(RangeWarning::Calc(CalcError::DivisionByZero)), Compaction::new(DryDensityChoice::Value(155.), 0.).calculate_checked());
+
+    let saturation = Saturation::new(MoistureContentChoice::Value(0.1428571), VoidRatioChoice::Value(0.532), None);
+    assert_eq!(Ok(72.5), saturation.calculate_checked());
+
+    let oversaturated = Saturation::new(MoistureContentChoice::Value(0.25), VoidRatioChoice::Value(0.532), None);
+    assert_eq!(Err(RangeWarning::AboveRange(126.9)), oversaturated.calculate_checked());
+    assert_eq!(Some(126.9), oversaturated.calculate_checked().unwrap_err().value());
+  }
+
+  #[test]
+  fn test_default() {
+    let lab_max_correction: LabMaxCorrection = Default::default();
+    assert_eq!(Ok(0.), lab_max_correction.get_rock_correction());
+    assert_eq!(0., lab_max_correction.get_lab_max());
+    assert_eq!(Ok(2.7), lab_max_correction.get_specific_gravity());
+
+    let sand_used = SandUsed::<f64>::default();
+    assert_eq!(3.59, sand_used.get_sand_in_cone());
+    assert_eq!(SandUsed::new(0., 0., None), sand_used);
+
+    assert_eq!(WetDensity::new(0., SandUsedChoice::Value(0.), None), WetDensity::default());
+    assert_eq!(Err(CalcError::DivisionByZero), WetDensity::<f64>::default().calculate());
+    assert_eq!(1., SpecificGravity::<f32>::default().get_temperature_correction());
+    assert_eq!(RelativeDensity::new(0., 0., VoidRatioChoice::Value(0.)), RelativeDensity::default());
+  }
+
+  #[test]
+  fn test_calculate_detailed() {
     let setup = Setup::new();
 
-    let value = LabMaxCorrection::new(RockCorrectionChoice::Value(0.2), setup.lab_max, None);
+    let wet_density = WetDensity::new(setup.soil, SandUsedChoice::Value(2.31), None);
+    let dry_density = DryDensity::new(WetDensityChoice::Constructor(wet_density), MoistureContentChoice::Value(0.1428571));
+    let detailed = dry_density.calculate_detailed().unwrap();
 
-    assert_eq!(139.7, value.calculate());
+    assert_eq!(155., detailed.value());
+    assert_eq!(wet_density.calculate_raw().ok(), detailed.get("wet_density"));
+    assert_eq!(Some(0.1428571), detailed.get("moisture_content"));
+    assert_eq!(None, detailed.get("lab_max"));
 
-    let rock_correction = RockCorrection::new(setup.left_on_sieve_weight, setup.pre_sieve_rock_correction);
-    let constructor = LabMaxCorrection::new(RockCorrectionChoice::Constructor(rock_correction), setup.lab_max, None);
+    let lab_max_correction = LabMaxCorrection::new(RockCorrectionChoice::Value(0.2), setup.lab_max, None);
+    let labels: Vec<&str> = lab_max_correction.calculate_detailed().unwrap().terms().iter().map(|&(label, _)| label).collect();
+    assert_eq!(vec!["rock_correction", "lab_max", "specific_gravity", "unit_weight_water"], labels);
+
+    let relative_density = RelativeDensity::from_dry_densities(110., 90., DryDensityChoice::Value(100.)).calculate_detailed().unwrap();
+    assert_eq!(Some(110.), relative_density.get("max_dry_density"));
+
+    assert_eq!(Err(CalcError::DivisionByZero), WetDensity::new(setup.soil, SandUsedChoice::Value(0.), None).calculate_detailed());
+  }
+
+  #[test]
+  fn test_shrinkage_limit() {
+    let shrinkage_limit = ShrinkageLimit::new(MoistureContentChoice::Value(0.35), 16.2, 10.8, 21.6);
+
+    assert_eq!(MoistureContentChoice::Value(0.35), shrinkage_limit.get_moisture_content());
+    assert_eq!(16.2, shrinkage_limit.get_initial_volume());
+    assert_eq!(10.8, shrinkage_limit.get_dry_volume());
+    assert_eq!(21.6, shrinkage_limit.get_dry_soil());
+    assert_eq!(Ok(10.), shrinkage_limit.calculate());
+    assert_eq!("Shrinkage Limit: (0.35 - (16.2 - 10.8) / 21.6) * 100 = 10", shrinkage_limit.to_string());
+
+    let moisture_content = MoistureContent::new(29.16, 21.6, 0.);
+    let from_constructor = ShrinkageLimit::new(MoistureContentChoice::Constructor(moisture_content), 16.2, 10.8, 21.6);
+    assert_eq!(Ok(10.), from_constructor.calculate());
+
+    assert_eq!(Err(CalcError::DivisionByZero), ShrinkageLimit::new(MoistureContentChoice::Value(0.35), 16.2, 10.8, 0.).calculate());
+    assert_eq!("dry_volume", ShrinkageLimit::try_new(MoistureContentChoice::Value(0.35), 16.2, -10.8, 21.6).unwrap_err().field);
+  }
+
+  #[test]
+  fn test_with_defaults() {
+    assert_eq!(SandUsed::new(14.65, 8.75, Some(3.6)), SandUsed::new(14.65, 8.75, None).with_sand_in_cone(3.6));
+    assert_eq!(Ok(2.3), SandUsed::new(14.65, 8.75, None).with_sand_in_cone(3.6).calculate());
+
+    let wet_density = WetDensity::new(4.65, SandUsedChoice::Value(2.31), None).with_sand_density(90.);
+    assert_eq!(90., wet_density.get_sand_density());
+
+    let lab_max_correction = LabMaxCorrection::new(RockCorrectionChoice::Value(0.2), 135.6, None).with_specific_gravity(2.65);
+    assert_eq!(Ok(2.65), lab_max_correction.get_specific_gravity());
 
-     assert_eq!(139.7, constructor.calculate());
+    assert_eq!(2.65, VoidRatio::new(DryDensityChoice::Value(110.), None).with_specific_gravity(2.65).get_specific_gravity());
+    assert_eq!(2.65, Porosity::new(DryDensityChoice::Value(110.), None).with_specific_gravity(2.65).get_specific_gravity());
+    assert_eq!(2.65, Saturation::new(MoistureContentChoice::Value(0.1), VoidRatioChoice::Value(0.5), None).with_specific_gravity(2.65).get_specific_gravity());
+    assert_eq!(2.65, OnePointProctor::new(DryDensityChoice::Value(110.), MoistureContentChoice::Value(0.1), None, None).with_specific_gravity(2.65).get_specific_gravity());
   }
 
   struct Setup {
@@ -669,6 +4432,8 @@ mod tests {
     dry_weight: f64,
     pre_sieve_rock_correction: f64,
     left_on_sieve_weight: f64,
+    liquid_limit: f64,
+    plastic_limit: f64,
   }
 
   impl Setup {
@@ -684,6 +4449,8 @@ mod tests {
         dry_weight: 1575.,
         pre_sieve_rock_correction: 500.,
         left_on_sieve_weight: 100.,
+        liquid_limit: 45.,
+        plastic_limit: 22.,
       }
     }
   }
@@ -0,0 +1,170 @@
+//! Composite
+use alloc::vec::Vec;
+use crate::domain::errors::{CalcError, ValidationError, ValidationErrorKind};
+use crate::domain::traits::{Calculate, Rounding};
+use crate::math::float::{checked_div, Float};
+use super::constants::FRACTION_SUM_TOLERANCE;
+use core::fmt;
+
+/// Composite lab max calculation, for fill blended from several materials.
+///
+/// The composite is the mass-weighted harmonic mean, `1 / Σ (fraction / lab max)`, normalized by the sum of the fractions. Blending by mass adds the volumes of each material, `fraction / lab max` per unit mass, so the arithmetic mean would overestimate the density of the blend.
+///
+/// # Example
+///
+/// ```
+/// use billios::field_test::{Compaction, CompositeLabMax};
+/// use billios::field_test::types::DryDensityChoice;
+///
+/// let composite = CompositeLabMax::new(vec![(130., 0.6), (120., 0.4)]);
+/// assert_eq!(Ok(125.8), composite.calculate());
+///
+/// let compaction = Compaction::new(DryDensityChoice::Value(120.), composite.calculate().unwrap());
+/// assert_eq!(Ok(95.4), compaction.calculate());
+/// ```
+///
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CompositeLabMax<T: Float = f64> {
+  materials: Vec<(T, T)>,
+}
+
+impl<T: Float> Rounding<T> for CompositeLabMax<T> {}
+
+impl<T: Float> Calculate<T> for CompositeLabMax<T> {
+  fn calculate_raw(&self) -> Result<T, CalcError> {
+    if self.materials.is_empty() {
+      return Err(CalcError::InsufficientData);
+    }
+
+    let total = self.total_fraction();
+
+    if !total.is_finite() {
+      return Err(CalcError::NonFinite);
+    }
+
+    if (total - T::ONE).abs() > T::from_f64(FRACTION_SUM_TOLERANCE) {
+      return Err(CalcError::OutOfRange);
+    }
+
+    let mut volume = T::ZERO;
+
+    for &(lab_max, fraction) in &self.materials {
+      volume = volume + checked_div(fraction, lab_max)?;
+    }
+
+    // Normalized by the total, so fractions within the tolerance are not biased.
+    checked_div(total, volume)
+  }
+
+  fn precision(&self) -> i32 {
+    1
+  }
+
+  fn unit_label(&self) -> &'static str {
+    "pcf"
+  }
+}
+
+impl<T: Float> CompositeLabMax<T> {
+  /// Create a new instance of `CompositeLabMax`.
+  ///
+  /// # Arguments
+  ///
+  /// - `materials` - A list of `(lab max, fraction)` pairs, where the fraction (decimal) is of the total mass, and the fractions sum to `1`.
+  ///
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn new(materials: Vec<(T, T)>) -> Self {
+    Self { materials }
+  }
+
+  /// Create a new, validated, instance of `CompositeLabMax`.
+  ///
+  /// Returns a `ValidationError` if any value is `NaN`, infinite, or negative, or `ValidationErrorKind::NotNormalized` if the fractions do not sum to `1`, within `FRACTION_SUM_TOLERANCE`.
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn try_new(materials: Vec<(T, T)>) -> Result<Self, ValidationError> {
+    for &(lab_max, fraction) in &materials {
+      ValidationError::check_non_negative("lab_max", lab_max)?;
+      ValidationError::check_non_negative("fraction", fraction)?;
+    }
+
+    let composite = Self::new(materials);
+
+    if (composite.total_fraction() - T::ONE).abs() > T::from_f64(FRACTION_SUM_TOLERANCE) {
+      return Err(ValidationError::new("fraction", ValidationErrorKind::NotNormalized));
+    }
+
+    Ok(composite)
+  }
+
+  /// Calculate the composite lab max, rounded to one decimal place.
+  ///
+  /// Returns `CalcError::InsufficientData` if no materials were given, `CalcError::OutOfRange` if the fractions do not sum to `1`, within `FRACTION_SUM_TOLERANCE`, and `CalcError::DivisionByZero` if a lab max is less than `T::EPSILON`.
+  ///
+  /// Delegates to the `Calculate` implementation.
+  #[must_use = "the result of a calculation should be used, or its error handled"]
+  pub fn calculate(&self) -> Result<T, CalcError> {
+    Calculate::calculate(self)
+  }
+
+  /// Getter for `materials`
+  pub fn get_materials(&self) -> &[(T, T)] {
+    &self.materials
+  }
+
+  /// The sum of the fractions.
+  pub fn total_fraction(&self) -> T {
+    self.materials.iter().map(|&(_, fraction)| fraction).sum()
+  }
+}
+
+impl<T: Float> fmt::Display for CompositeLabMax<T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "Composite Lab Max: 1 / (")?;
+
+    for (i, (lab_max, fraction)) in self.materials.iter().enumerate() {
+      if i > 0 {
+        write!(f, " + ")?;
+      }
+
+      write!(f, "{} / {}", fraction, lab_max)?;
+    }
+
+    match self.calculate() {
+      Ok(v) => write!(f, ") = {} pcf", v),
+      Err(e) => write!(f, ") = [{}]", e),
+    }
+  }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_composite_lab_max() {
+    let composite = CompositeLabMax::new(vec![(130., 0.6), (120., 0.4)]);
+
+    assert_eq!(Ok(125.8), composite.calculate());
+    assert!(composite.calculate_raw().unwrap() < 130. * 0.6 + 120. * 0.4);
+    assert_eq!("Composite Lab Max: 1 / (0.6 / 130 + 0.4 / 120) = 125.8 pcf", composite.to_string());
+
+    assert_eq!(Ok(135.6), CompositeLabMax::new(vec![(135.6, 1.)]).calculate());
+    assert_eq!(Ok(125.8), CompositeLabMax::new(vec![(130., 0.6), (120., 0.3995)]).calculate());
+  }
+
+  #[test]
+  fn test_errors() {
+    assert_eq!(Err(CalcError::InsufficientData), CompositeLabMax::<f64>::new(vec![]).calculate());
+    assert_eq!(Err(CalcError::OutOfRange), CompositeLabMax::new(vec![(130., 0.6), (120., 0.6)]).calculate());
+    assert_eq!(Err(CalcError::DivisionByZero), CompositeLabMax::new(vec![(0., 0.6), (120., 0.4)]).calculate());
+    assert_eq!("Composite Lab Max: 1 / (0.6 / 130 + 0.6 / 120) = [value is outside the range of the data]", CompositeLabMax::new(vec![(130., 0.6), (120., 0.6)]).to_string());
+
+    let error = CompositeLabMax::try_new(vec![(130., 0.6), (120., 0.5)]).unwrap_err();
+    assert_eq!(ValidationError::new("fraction", ValidationErrorKind::NotNormalized), error);
+    assert_eq!("`fraction` must sum to 1", error.to_string());
+    assert_eq!("lab_max", CompositeLabMax::try_new(vec![(-130., 1.)]).unwrap_err().field);
+  }
+}
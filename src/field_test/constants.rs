@@ -1,8 +1,96 @@
 //! Constants
+//!
+//! These values are used as defaults when `None` is passed to a calculation. They are re-exported from the `field_test` module.
+//!
+//! # Example
+//!
+//! ```
+//! use billios::field_test::{default_specific_gravity, SPECIFIC_GRAVITY};
+//!
+//! assert_eq!(SPECIFIC_GRAVITY, default_specific_gravity());
+//! assert_eq!("using default 2.7 specific gravity", format!("using default {} specific gravity", default_specific_gravity()));
+//! ```
 
 
+/// Weight of the sand that fills the cone, in pounds (lb).
 pub const SAND_IN_CONE: f64 = 3.59;
 
+/// Density of the calibrated sand, in pounds per cubic foot (pcf).
 pub const SAND_DENSITY: f64 = 88.;
 
-pub const SPECIFIC_GRAVITY: f64 = 2.7;
\ No newline at end of file
+/// Specific gravity of the soil solids (dimensionless).
+pub const SPECIFIC_GRAVITY: f64 = 2.7;
+
+/// Unit weight of water, in pounds per cubic foot (pcf).
+pub const UNIT_WEIGHT_WATER: f64 = 62.4;
+
+/// Density of water, in grams per cubic centimetre (g/cm³), used by the laboratory `ShrinkageLimit`.
+pub const WATER_DENSITY: f64 = 1.;
+
+/// Curvature of the family of Proctor curves used by `OnePointProctor`, in pcf per percent moisture squared.
+pub const PROCTOR_CURVE_COEFFICIENT: f64 = 0.5;
+
+/// Degree of saturation (decimal) of the line of optimums used by `OnePointProctor`.
+pub const OPTIMUM_SATURATION: f64 = 0.8;
+
+/// How far the fractions of a `CompositeLabMax` may sum from `1`.
+pub const FRACTION_SUM_TOLERANCE: f64 = 0.001;
+
+/// The largest change between the last two oven-dry masses, as a fraction of the dry soil, for `MoistureContent::from_drying_series()` to be at constant mass, ASTM D2216.
+pub const CONSTANT_MASS_TOLERANCE: f64 = 0.001;
+
+/// The largest friction angle, in degrees, accepted by `BearingCapacity`, the end of Terzaghi's tables.
+pub const BEARING_MAX_FRICTION_ANGLE: f64 = 50.;
+
+/// Hazen's empirical coefficient, for a hydraulic conductivity in cm/s from a `D10` in millimetres, used by `HazenPermeability`.
+pub const HAZEN_COEFFICIENT: f64 = 1.;
+
+/// The blow count of the Casagrande cup at which the liquid limit is read from the flow curve, used by `LiquidLimitFlowCurve`.
+pub const LIQUID_LIMIT_BLOWS: f64 = 25.;
+
+/// The lowest plausible percent compaction, checked by `Compaction::calculate_checked()`.
+pub const COMPACTION_MIN_PLAUSIBLE: f64 = 50.;
+
+/// The highest plausible percent compaction, checked by `Compaction::calculate_checked()`.
+pub const COMPACTION_MAX_PLAUSIBLE: f64 = 130.;
+
+/// The lowest plausible percent saturation, checked by `Saturation::calculate_checked()`.
+pub const SATURATION_MIN_PLAUSIBLE: f64 = 0.;
+
+/// The highest plausible percent saturation, checked by `Saturation::calculate_checked()`.
+pub const SATURATION_MAX_PLAUSIBLE: f64 = 100.;
+
+/// The default `sand_in_cone` value, in pounds (lb).
+///
+/// Returns the `SAND_IN_CONE` constant.
+pub const fn default_sand_in_cone() -> f64 {
+  SAND_IN_CONE
+}
+
+/// The default `sand_density` value, in pounds per cubic foot (pcf).
+///
+/// Returns the `SAND_DENSITY` constant.
+pub const fn default_sand_density() -> f64 {
+  SAND_DENSITY
+}
+
+/// The default `specific_gravity` value (dimensionless).
+///
+/// Returns the `SPECIFIC_GRAVITY` constant.
+pub const fn default_specific_gravity() -> f64 {
+  SPECIFIC_GRAVITY
+}
+
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_defaults() {
+    assert_eq!(SAND_IN_CONE, default_sand_in_cone());
+    assert_eq!(SAND_DENSITY, default_sand_density());
+    assert_eq!(SPECIFIC_GRAVITY, default_specific_gravity());
+  }
+}
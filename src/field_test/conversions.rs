@@ -0,0 +1,148 @@
+//! Conversions
+//!
+//! Convert between wet and dry density using the moisture content, `γd = γwet / (1 + w)`, without building a full `DryDensity`.
+//!
+//! ```
+//! use billios::field_test::{dry_to_wet, wet_to_dry, MoistureContent};
+//! use billios::math::float::approx_eq;
+//!
+//! let moisture_content = MoistureContent::new(1600., 1575., 1400.);
+//! let dry_density = wet_to_dry(177.1429, moisture_content).unwrap();
+//!
+//! assert!(approx_eq(155., dry_density, 1e-4));
+//! assert!(approx_eq(177.1429, dry_to_wet(dry_density, moisture_content).unwrap(), 1e-9));
+//! ```
+//!
+//! Also the saturated and submerged (buoyant) unit weights from the void ratio, for layers below the water table.
+use crate::domain::errors::CalcError;
+use crate::domain::traits::Calculate;
+use crate::math::float::{checked_div, Float};
+use super::calculations::DryDensity;
+use super::constants::{SPECIFIC_GRAVITY, UNIT_WEIGHT_WATER};
+use super::types::{DryDensityChoice, MoistureContentChoice, SpecificGravityChoice, VoidRatioChoice, WetDensityChoice};
+
+/// Convert a wet density to a dry density, `γd = γwet / (1 + w)`.
+///
+/// This is the `DryDensity` formula, and the result is unrounded, as from its `calculate_raw()`. Accepts a value (float), or a constructor, for either argument.
+///
+/// Returns any error from calculating a `Constructor` value.
+pub fn wet_to_dry<T: Float>(wet_density: impl Into<WetDensityChoice<T>>, moisture_content: impl Into<MoistureContentChoice<T>>) -> Result<T, CalcError> {
+  DryDensity::new(wet_density.into(), moisture_content.into()).calculate_raw()
+}
+
+/// Convert a dry density to a wet density, `γwet = γd * (1 + w)`.
+///
+/// The inverse of `wet_to_dry()`, and the result is unrounded. Accepts a value (float), or a constructor, for either argument.
+///
+/// Returns any error from calculating a `Constructor` value.
+pub fn dry_to_wet<T: Float>(dry_density: impl Into<DryDensityChoice<T>>, moisture_content: impl Into<MoistureContentChoice<T>>) -> Result<T, CalcError> {
+  let dry_density = dry_density.into().resolve()?;
+  let moisture_content = moisture_content.into().resolve()?;
+
+  Ok(dry_density * (T::ONE + moisture_content))
+}
+
+/// Calculate the saturated unit weight, `γsat = (Gs + e) * γw / (1 + e)`.
+///
+/// The result is unrounded, and in the units of `unit_weight_water`. Pass it on to a `VerticalStress` layer below the water table as a `WetDensityChoice::Value()`.
+///
+/// # Arguments
+///
+/// - `void_ratio` - The void ratio, `e`, either a value (float), or an existing `VoidRatio::new()` constructor.
+///
+/// - `specific_gravity` - An `Option<SpecificGravityChoice>` that when `None` defaults to the constant value `SPECIFIC_GRAVITY`.
+///
+/// - `unit_weight_water` - An `Option<float>` that when `None` defaults to the constant value `UNIT_WEIGHT_WATER`, 62.4 pcf. Set it to e.g. `9.81` for kN/m³.
+///
+/// Returns `CalcError::DivisionByZero` when the absolute value of `1 + e` is less than `T::EPSILON`, and any error from calculating a `Constructor` value.
+///
+/// # Example
+///
+/// ```
+/// use billios::field_test::{saturated_unit_weight, submerged_unit_weight, VerticalStress};
+/// use billios::math::float::approx_eq;
+///
+/// let saturated = saturated_unit_weight(0.55, None, None).unwrap();
+///
+/// assert!(approx_eq(130.8387, saturated, 1e-4));
+/// assert!(approx_eq(68.4387, submerged_unit_weight(0.55, None, None).unwrap(), 1e-4));
+///
+/// // In kN/m³.
+/// assert!(approx_eq(20.2529, saturated_unit_weight(0.55, Some(2.65.into()), Some(9.81)).unwrap(), 1e-4));
+///
+/// let profile = VerticalStress::new(vec![(10., saturated.into())], Some(0.));
+/// assert_eq!(Ok(684.4), profile.effective_stress(10.));
+/// ```
+pub fn saturated_unit_weight<T: Float>(void_ratio: impl Into<VoidRatioChoice<T>>, specific_gravity: Option<SpecificGravityChoice<T>>, unit_weight_water: Option<T>) -> Result<T, CalcError> {
+  let void_ratio = void_ratio.into().resolve()?;
+  let specific_gravity = match specific_gravity {
+    Some(choice) => choice.resolve()?,
+    None => T::from_f64(SPECIFIC_GRAVITY),
+  };
+  let unit_weight_water = unit_weight_water.unwrap_or(T::from_f64(UNIT_WEIGHT_WATER));
+
+  checked_div((specific_gravity + void_ratio) * unit_weight_water, T::ONE + void_ratio)
+}
+
+/// Calculate the submerged (buoyant) unit weight, `γ' = γsat - γw`.
+///
+/// Takes the same arguments as `saturated_unit_weight()`, and the result is unrounded, in the units of `unit_weight_water`.
+pub fn submerged_unit_weight<T: Float>(void_ratio: impl Into<VoidRatioChoice<T>>, specific_gravity: Option<SpecificGravityChoice<T>>, unit_weight_water: Option<T>) -> Result<T, CalcError> {
+  let saturated = saturated_unit_weight(void_ratio, specific_gravity, unit_weight_water)?;
+
+  Ok(saturated - unit_weight_water.unwrap_or(T::from_f64(UNIT_WEIGHT_WATER)))
+}
+
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::field_test::{MoistureContent, VoidRatio, WetDensity};
+  use crate::field_test::types::SandUsedChoice;
+  use crate::math::float::approx_eq;
+
+  #[test]
+  fn test_round_trip() {
+    let dry_density = wet_to_dry(132., 0.1).unwrap();
+
+    assert!(approx_eq(120., dry_density, 1e-9));
+    assert!(approx_eq(132., dry_to_wet(dry_density, 0.1).unwrap(), 1e-9));
+  }
+
+  #[test]
+  fn test_constructors() {
+    let wet_density = WetDensity::new(4.65, SandUsedChoice::Value(2.31), None);
+    let moisture_content = MoistureContent::new(1600., 1575., 1400.);
+
+    assert!(approx_eq(155., wet_to_dry(wet_density, moisture_content).unwrap(), 1e-9));
+    assert!(approx_eq(wet_density.calculate_raw().unwrap(), dry_to_wet(155., moisture_content).unwrap(), 1e-9));
+    assert_eq!(Ok(DryDensity::new(wet_density.into(), moisture_content.into()).calculate_raw().unwrap()), wet_to_dry(wet_density, moisture_content));
+  }
+
+  #[test]
+  fn test_unit_weights() {
+    assert!(approx_eq(130.83870968, saturated_unit_weight(0.55, None, None).unwrap(), 1e-8));
+    assert!(approx_eq(68.43870968, submerged_unit_weight(0.55, None, None).unwrap(), 1e-8));
+
+    // With no voids, the solids alone.
+    assert!(approx_eq(2.65 * 62.4, saturated_unit_weight(0., Some(2.65.into()), None).unwrap(), 1e-9));
+
+    // The void ratio of a `VoidRatio`, and the SI unit weight of water.
+    let void_ratio = VoidRatio::new(DryDensityChoice::Value(110.), None);
+    let e = void_ratio.calculate_raw().unwrap();
+    assert_eq!(saturated_unit_weight(e, None, Some(9.81)), saturated_unit_weight(void_ratio, None, Some(9.81)));
+    assert!(approx_eq(9.81 * (2.7 + e) / (1. + e), saturated_unit_weight(void_ratio, None, Some(9.81)).unwrap(), 1e-12));
+
+    assert_eq!(Err(CalcError::DivisionByZero), saturated_unit_weight(-1., None, None));
+    assert_eq!(Err(CalcError::DivisionByZero), submerged_unit_weight(VoidRatio::new(DryDensityChoice::Value(0.), None), None, None));
+  }
+
+  #[test]
+  fn test_errors() {
+    let wet_density = WetDensity::new(4.65, SandUsedChoice::Value(0.), None);
+
+    assert_eq!(Err(CalcError::DivisionByZero), wet_to_dry(wet_density, 0.1));
+    assert_eq!(Err(CalcError::DivisionByZero), dry_to_wet(120., MoistureContent::new(1600., 1400., 1400.)));
+  }
+}
@@ -0,0 +1,790 @@
+//! Gradation
+use alloc::vec;
+use alloc::vec::Vec;
+use crate::domain::classification::CoarseGrading;
+use crate::domain::errors::{CalcError, ValidationError};
+use crate::domain::traits::{Calculate, Rounding};
+use crate::math::float::{checked_div, Float};
+use crate::math::utilities::log_interp;
+use super::constants::HAZEN_COEFFICIENT;
+use core::fmt;
+
+/// The opening of the #4 sieve in millimetres. Separates gravel from sand.
+pub const SIEVE_NO_4: f64 = 4.75;
+
+/// The opening of the #200 sieve in millimetres. Separates sand from fines.
+pub const SIEVE_NO_200: f64 = 0.075;
+
+/// The fraction of a soil retained on a sieve: gravel above the #4, and sand above the #200, sieve.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GrainFraction {
+  /// Retained on the #4 sieve, or larger.
+  Gravel,
+  /// Passing the #4 sieve and retained on the #200 sieve.
+  Sand,
+}
+
+/// The exponent, `n`, of the Fuller maximum density curve, `p = 100 * (d / D)^n`.
+pub const FULLER_EXPONENT: f64 = 0.5;
+
+/// A standard US sieve size, ASTM E11.
+///
+/// # Example
+///
+/// ```
+/// use billios::field_test::{GradationAnalysis, GrainFraction, SieveSize};
+///
+/// assert_eq!(4.75, SieveSize::No4.diameter_mm());
+/// assert_eq!(GrainFraction::Sand, SieveSize::No10.fraction());
+/// assert_eq!("3/4 in", SieveSize::ThreeQuarterInch.to_string());
+///
+/// let stack = SieveSize::standard_stack();
+/// assert_eq!(Some(&SieveSize::No200), stack.last());
+///
+/// let gradation = GradationAnalysis::from_sieve_sizes(vec![(SieveSize::No4, 150.), (SieveSize::No20, 350.), (SieveSize::No200, 400.)], Some(100.));
+/// assert_eq!(Ok(85.), gradation.percent_passing_at(4.75));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SieveSize {
+  /// 3 in, 75 mm.
+  ThreeInch,
+  /// 2 in, 50 mm.
+  TwoInch,
+  /// 1 1/2 in, 37.5 mm.
+  OneAndHalfInch,
+  /// 1 in, 25 mm.
+  OneInch,
+  /// 3/4 in, 19 mm.
+  ThreeQuarterInch,
+  /// 3/8 in, 9.5 mm.
+  ThreeEighthInch,
+  /// #4, 4.75 mm.
+  No4,
+  /// #10, 2 mm.
+  No10,
+  /// #20, 0.85 mm.
+  No20,
+  /// #40, 0.425 mm.
+  No40,
+  /// #60, 0.25 mm.
+  No60,
+  /// #100, 0.15 mm.
+  No100,
+  /// #200, 0.075 mm.
+  No200,
+}
+
+impl SieveSize {
+  /// The standard stack of sieves, ordered from the largest to the smallest.
+  pub fn standard_stack() -> Vec<SieveSize> {
+    vec![
+      SieveSize::ThreeInch,
+      SieveSize::TwoInch,
+      SieveSize::OneAndHalfInch,
+      SieveSize::OneInch,
+      SieveSize::ThreeQuarterInch,
+      SieveSize::ThreeEighthInch,
+      SieveSize::No4,
+      SieveSize::No10,
+      SieveSize::No20,
+      SieveSize::No40,
+      SieveSize::No60,
+      SieveSize::No100,
+      SieveSize::No200,
+    ]
+  }
+
+  /// The opening of the sieve, in millimetres.
+  pub fn diameter_mm(&self) -> f64 {
+    match self {
+      SieveSize::ThreeInch => 75.,
+      SieveSize::TwoInch => 50.,
+      SieveSize::OneAndHalfInch => 37.5,
+      SieveSize::OneInch => 25.,
+      SieveSize::ThreeQuarterInch => 19.,
+      SieveSize::ThreeEighthInch => 9.5,
+      SieveSize::No4 => SIEVE_NO_4,
+      SieveSize::No10 => 2.,
+      SieveSize::No20 => 0.85,
+      SieveSize::No40 => 0.425,
+      SieveSize::No60 => 0.25,
+      SieveSize::No100 => 0.15,
+      SieveSize::No200 => SIEVE_NO_200,
+    }
+  }
+
+  /// The fraction of the soil retained on the sieve.
+  pub fn fraction(&self) -> GrainFraction {
+    if self.diameter_mm() >= SIEVE_NO_4 { GrainFraction::Gravel } else { GrainFraction::Sand }
+  }
+}
+
+impl fmt::Display for SieveSize {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let name = match self {
+      SieveSize::ThreeInch => "3 in",
+      SieveSize::TwoInch => "2 in",
+      SieveSize::OneAndHalfInch => "1 1/2 in",
+      SieveSize::OneInch => "1 in",
+      SieveSize::ThreeQuarterInch => "3/4 in",
+      SieveSize::ThreeEighthInch => "3/8 in",
+      SieveSize::No4 => "#4",
+      SieveSize::No10 => "#10",
+      SieveSize::No20 => "#20",
+      SieveSize::No40 => "#40",
+      SieveSize::No60 => "#60",
+      SieveSize::No100 => "#100",
+      SieveSize::No200 => "#200",
+    };
+
+    write!(f, "{}", name)
+  }
+}
+
+/// The gravel, sand, and fines split of a sample, in percent, as reported on a gradation.
+///
+/// Gravel is retained on the #4 sieve, sand passes the #4 and is retained on the #200, and fines pass the #200, so the three sum to 100.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GrainComposition<T: Float = f64> {
+  gravel_percent: T,
+  sand_percent: T,
+  fines_percent: T,
+}
+
+impl<T: Float> GrainComposition<T> {
+  /// Getter for `gravel_percent`
+  pub fn get_gravel_percent(&self) -> T {
+    self.gravel_percent
+  }
+
+  /// Getter for `sand_percent`
+  pub fn get_sand_percent(&self) -> T {
+    self.sand_percent
+  }
+
+  /// Getter for `fines_percent`
+  pub fn get_fines_percent(&self) -> T {
+    self.fines_percent
+  }
+}
+
+impl<T: Float> fmt::Display for GrainComposition<T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "Gravel: {}%, Sand: {}%, Fines: {}%", self.gravel_percent, self.sand_percent, self.fines_percent)
+  }
+}
+
+/// The deviation of a gradation from the Fuller maximum density curve, built by `GradationAnalysis::fuller_deviation()`.
+///
+/// Each deviation is the measured, less the ideal, percent passing, so positive where the sample is finer than the curve. Both the deviations and the RMS are rounded to one decimal place.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FullerDeviation<T: Float = f64> {
+  deviations: Vec<(T, T)>,
+  rms: T,
+}
+
+impl<T: Float> FullerDeviation<T> {
+  /// Getter for `deviations`, as `(sieve size, deviation)` pairs from the largest to the smallest sieve.
+  pub fn get_deviations(&self) -> &[(T, T)] {
+    &self.deviations
+  }
+
+  /// Getter for `rms`, the root mean square of the deviations.
+  pub fn get_rms(&self) -> T {
+    self.rms
+  }
+}
+
+/// Sieve analysis (gradation) calculation
+///
+/// Sieve sizes are in millimetres, and the masses retained may be in any unit as long as they are consistent.
+///
+/// # Example
+///
+/// ```
+/// use billios::field_test::GradationAnalysis;
+///
+/// let gradation = GradationAnalysis::new(
+///   vec![(19., 0.), (9.5, 50.), (4.75, 100.), (2., 150.), (0.85, 200.), (0.425, 150.), (0.25, 100.), (0.075, 150.)],
+///   Some(100.),
+/// );
+///
+/// assert_eq!(Ok(0.075), gradation.d10());
+/// assert_eq!(Ok(17.38), gradation.coefficient_of_uniformity());
+/// assert_eq!(Ok(1.09), gradation.coefficient_of_curvature());
+/// ```
+///
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GradationAnalysis<T: Float = f64> {
+  sieves: Vec<(T, T)>,
+  pan: Option<T>,
+}
+
+impl<T: Float> Rounding<T> for GradationAnalysis<T> {}
+
+impl<T: Float> GradationAnalysis<T> {
+  /// Create a new instance of `GradationAnalysis`.
+  ///
+  /// # Arguments
+  ///
+  /// - `sieves` - A list of `(sieve size, mass retained)` pairs. They may be given in any order, and are sorted from the largest to the smallest sieve.
+  ///
+  /// - `pan` - An `Option<T>` for the mass retained in the pan. When `None` defaults to `0`.
+  ///
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn new(mut sieves: Vec<(T, T)>, pan: Option<T>) -> Self {
+    sieves.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+    Self { sieves, pan }
+  }
+
+  /// Create a new instance of `GradationAnalysis` from standard `SieveSize`s.
+  ///
+  /// # Arguments
+  ///
+  /// - `sieves` - A list of `(sieve, mass retained)` pairs, in any order.
+  ///
+  /// - `pan` - An `Option<T>` for the mass retained in the pan. When `None` defaults to `0`.
+  ///
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn from_sieve_sizes(sieves: Vec<(SieveSize, T)>, pan: Option<T>) -> Self {
+    Self::new(sieves.into_iter().map(|(sieve, retained)| (T::from_f64(sieve.diameter_mm()), retained)).collect(), pan)
+  }
+
+  /// Create a new, validated, instance of `GradationAnalysis`.
+  ///
+  /// Returns a `ValidationError` if any value is `NaN`, infinite, or negative.
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn try_new(sieves: Vec<(T, T)>, pan: Option<T>) -> Result<Self, ValidationError> {
+    for &(size, retained) in &sieves {
+      ValidationError::check_non_negative("sieve_size", size)?;
+      ValidationError::check_non_negative("mass_retained", retained)?;
+    }
+    ValidationError::check_optional_non_negative("pan", pan)?;
+
+    Ok(Self::new(sieves, pan))
+  }
+
+  /// Getter for `sieves`, sorted from the largest to the smallest sieve.
+  pub fn get_sieves(&self) -> &[(T, T)] {
+    &self.sieves
+  }
+
+  /// Getter for `pan`
+  ///
+  /// If no value is provided, the default is `0`.
+  pub fn get_pan(&self) -> T {
+    self.pan.unwrap_or(T::ZERO)
+  }
+
+  /// The total mass of the sample, including the pan.
+  pub fn total_mass(&self) -> T {
+    self.sieves.iter().map(|&(_, retained)| retained).sum::<T>() + self.get_pan()
+  }
+
+  /// Cumulative percent passing each sieve, unrounded.
+  fn passing(&self) -> Result<Vec<(T, T)>, CalcError> {
+    let total = self.total_mass();
+
+    if total.abs() < T::EPSILON {
+      return Err(CalcError::DivisionByZero);
+    }
+
+    let mut retained = T::ZERO;
+
+    Ok(
+      self.sieves
+        .iter()
+        .map(|&(size, mass)| {
+          retained = retained + mass;
+          (size, (total - retained) / total * T::from_f64(100.))
+        })
+        .collect(),
+    )
+  }
+
+  /// Calculate the cumulative percent passing each sieve.
+  ///
+  /// Returns `(sieve size, percent passing)` pairs, from the largest to the smallest sieve, rounded to one decimal place.
+  ///
+  /// Returns `CalcError::DivisionByZero` if the total mass is less than `T::EPSILON`.
+  pub fn percent_passing(&self) -> Result<Vec<(T, T)>, CalcError> {
+    self.passing()?
+      .into_iter()
+      .map(|(size, passing)| Ok((size, self.round_n(passing, 1)?)))
+      .collect()
+  }
+
+  /// Calculate the percent passing an arbitrary sieve size.
+  ///
+  /// Interpolated linearly against the logarithm of the sieve size, as on a gradation curve.
+  ///
+  /// Returns `CalcError::InsufficientData` if fewer than two sieves were given, and `CalcError::OutOfRange` if `size` is outside the sieves used.
+  pub fn percent_passing_at(&self, size: T) -> Result<T, CalcError> {
+    let passing = self.passing()?;
+
+    if passing.len() < 2 {
+      return Err(CalcError::InsufficientData);
+    }
+
+    let (larger, smaller) = passing
+      .windows(2)
+      .map(|w| (w[0], w[1]))
+      .find(|&(larger, smaller)| size <= larger.0 && size >= smaller.0)
+      .ok_or(CalcError::OutOfRange)?;
+
+    let fraction = (size.ln() - smaller.0.ln()) / (larger.0.ln() - smaller.0.ln());
+    let result = smaller.1 + (larger.1 - smaller.1) * fraction;
+
+    if !result.is_finite() {
+      return Err(CalcError::NonFinite);
+    }
+
+    self.round_n(result, 1)
+  }
+
+  /// Particle diameter for which `percent` of the sample is finer, unrounded.
+  fn diameter_raw(&self, percent: T) -> Result<T, CalcError> {
+    let passing = self.passing()?;
+
+    if passing.len() < 2 {
+      return Err(CalcError::InsufficientData);
+    }
+
+    let points: Vec<(f64, f64)> = passing.iter().map(|&(size, passing)| (size.to_f64(), passing.to_f64())).collect();
+    let result = T::from_f64(log_interp(&points, percent.to_f64()).ok_or(CalcError::OutOfRange)?);
+
+    if !result.is_finite() {
+      return Err(CalcError::NonFinite);
+    }
+
+    Ok(result)
+  }
+
+  /// Calculate the particle diameter for which `percent` of the sample is finer.
+  ///
+  /// Interpolated linearly against the logarithm of the sieve size, by `log_interp()`, and rounded to four decimal places.
+  ///
+  /// Returns `CalcError::InsufficientData` if fewer than two sieves were given, and `CalcError::OutOfRange` if `percent` is outside the percent passing the sieves used.
+  pub fn diameter(&self, percent: T) -> Result<T, CalcError> {
+    self.round_n(self.diameter_raw(percent)?, 4)
+  }
+
+  /// Calculate `D10`, the particle diameter for which 10% of the sample is finer.
+  pub fn d10(&self) -> Result<T, CalcError> {
+    self.diameter(T::from_f64(10.))
+  }
+
+  /// Calculate `D30`, the particle diameter for which 30% of the sample is finer.
+  pub fn d30(&self) -> Result<T, CalcError> {
+    self.diameter(T::from_f64(30.))
+  }
+
+  /// Calculate `D60`, the particle diameter for which 60% of the sample is finer.
+  pub fn d60(&self) -> Result<T, CalcError> {
+    self.diameter(T::from_f64(60.))
+  }
+
+  /// Calculate the coefficient of uniformity, `Cu = D60 / D10`.
+  ///
+  /// Returns `CalcError::DivisionByZero` if `D10` is less than `T::EPSILON`.
+  pub fn coefficient_of_uniformity(&self) -> Result<T, CalcError> {
+    let d10 = self.diameter_raw(T::from_f64(10.))?;
+    let d60 = self.diameter_raw(T::from_f64(60.))?;
+
+    self.round_n(checked_div(d60, d10)?, 2)
+  }
+
+  /// Calculate the coefficient of curvature, `Cc = D30² / (D10 * D60)`.
+  ///
+  /// Returns `CalcError::DivisionByZero` if `D10 * D60` is less than `T::EPSILON`.
+  pub fn coefficient_of_curvature(&self) -> Result<T, CalcError> {
+    let d10 = self.diameter_raw(T::from_f64(10.))?;
+    let d30 = self.diameter_raw(T::from_f64(30.))?;
+    let d60 = self.diameter_raw(T::from_f64(60.))?;
+
+    self.round_n(checked_div(d30.powi(2), d10 * d60)?, 2)
+  }
+
+  /// Calculate the percent of the sample passing the #200 sieve.
+  pub fn percent_fines(&self) -> Result<T, CalcError> {
+    self.percent_passing_at(T::from_f64(SIEVE_NO_200))
+  }
+
+  /// Calculate the gravel, sand, and fines split of the sample.
+  ///
+  /// The percent passing the #4 and #200 sieves is interpolated when either sieve was not used, and each part is rounded to one decimal place.
+  ///
+  /// Returns `CalcError::OutOfRange` if the #4 or #200 sieve is outside the sieves used.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use billios::field_test::GradationAnalysis;
+  ///
+  /// let gradation = GradationAnalysis::new(
+  ///   vec![(19., 0.), (9.5, 50.), (4.75, 100.), (2., 150.), (0.85, 200.), (0.425, 150.), (0.25, 100.), (0.075, 150.)],
+  ///   Some(100.),
+  /// );
+  ///
+  /// let composition = gradation.composition().unwrap();
+  ///
+  /// assert_eq!(15., composition.get_gravel_percent());
+  /// assert_eq!(75., composition.get_sand_percent());
+  /// assert_eq!(10., composition.get_fines_percent());
+  /// assert_eq!("Gravel: 15%, Sand: 75%, Fines: 10%", composition.to_string());
+  /// ```
+  pub fn composition(&self) -> Result<GrainComposition<T>, CalcError> {
+    let passing_no_4 = self.percent_passing_at(T::from_f64(SIEVE_NO_4))?;
+    let passing_no_200 = self.percent_passing_at(T::from_f64(SIEVE_NO_200))?;
+
+    Ok(GrainComposition {
+      gravel_percent: self.round_n(T::from_f64(100.) - passing_no_4, 1)?,
+      sand_percent: self.round_n(passing_no_4 - passing_no_200, 1)?,
+      fines_percent: passing_no_200,
+    })
+  }
+
+  /// Build the `CoarseGrading` used by `classify()`.
+  ///
+  /// `Cu` and `Cc` are left as `None` when they cannot be determined from the sieves used.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use billios::domain::classification::{classify, UscsClass};
+  /// use billios::field_test::GradationAnalysis;
+  ///
+  /// let gradation = GradationAnalysis::new(
+  ///   vec![(19., 0.), (9.5, 50.), (4.75, 100.), (2., 150.), (0.85, 200.), (0.425, 150.), (0.25, 100.), (0.075, 150.)],
+  ///   Some(100.),
+  /// );
+  ///
+  /// let fines = gradation.percent_fines().unwrap();
+  /// let grading = gradation.coarse_grading().unwrap();
+  ///
+  /// assert_eq!(Some(UscsClass::SwSm), classify(fines, 30., 3., Some(grading)));
+  /// ```
+  pub fn coarse_grading(&self) -> Result<CoarseGrading, CalcError> {
+    let composition = self.composition()?;
+
+    Ok(CoarseGrading::new(
+      composition.gravel_percent.to_f64(),
+      composition.sand_percent.to_f64(),
+      self.coefficient_of_uniformity().ok().map(Float::to_f64),
+      self.coefficient_of_curvature().ok().map(Float::to_f64),
+    ))
+  }
+
+  /// Calculate the deviation of each sieve from the Fuller maximum density curve, `p = 100 * (d / D)^0.5`, for the nominal maximum size `max_size`, `D`.
+  ///
+  /// The ideal percent passing a sieve larger than `max_size` is `100`. A lower RMS deviation is closer to the densest grading.
+  ///
+  /// Returns `CalcError::InsufficientData` if no sieves were given, and `CalcError::DivisionByZero` if `max_size` or the total mass is less than `T::EPSILON`.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use billios::field_test::GradationAnalysis;
+  ///
+  /// let aggregate = GradationAnalysis::new(vec![(25., 0.), (12.5, 28.), (4.75, 27.), (2., 17.), (0.425, 14.), (0.075, 9.)], Some(5.));
+  /// let deviation = aggregate.fuller_deviation(25.).unwrap();
+  ///
+  /// assert_eq!(&[(25., 0.), (12.5, 1.3), (4.75, 1.4), (2., -0.3), (0.425, 1.), (0.075, -0.5)], deviation.get_deviations());
+  /// assert_eq!(0.9, deviation.get_rms());
+  /// ```
+  pub fn fuller_deviation(&self, max_size: T) -> Result<FullerDeviation<T>, CalcError> {
+    let passing = self.passing()?;
+
+    if passing.is_empty() {
+      return Err(CalcError::InsufficientData);
+    }
+
+    let hundred = T::from_f64(100.);
+    let mut sum_of_squares = T::ZERO;
+    let mut deviations = Vec::with_capacity(passing.len());
+
+    for (size, measured) in passing {
+      let ideal = hundred * checked_div(size, max_size)?.powf(T::from_f64(FULLER_EXPONENT));
+      let deviation = measured - if ideal > hundred { hundred } else { ideal };
+
+      sum_of_squares = sum_of_squares + deviation.powi(2);
+      deviations.push((size, self.round_n(deviation, 1)?));
+    }
+
+    let rms = (sum_of_squares / T::from_f64(deviations.len() as f64)).sqrt();
+
+    Ok(FullerDeviation { rms: self.round_n(rms, 1)?, deviations })
+  }
+
+  /// Create a `HazenPermeability` estimate from the `d10()` of the sample.
+  ///
+  /// Returns any error from calculating `d10()`.
+  pub fn hazen_permeability(&self, coefficient: Option<T>) -> Result<HazenPermeability<T>, CalcError> {
+    Ok(HazenPermeability::new(self.d10()?, coefficient))
+  }
+}
+
+/// Hazen's estimate of the hydraulic conductivity (permeability) of a soil, `k = C * D10²`.
+///
+/// With `D10` in millimetres and the default `HAZEN_COEFFICIENT` of `1`, `k` is in cm/s. The coefficient commonly ranges from about `0.4` to `1.2`.
+///
+/// The approximation is only valid for clean, uniform sands, with a `D10` of about 0.1 mm to 3 mm and little to no fines. It is an order of magnitude estimate, not a replacement for a permeability test.
+///
+/// # Example
+///
+/// ```
+/// use billios::field_test::{GradationAnalysis, HazenPermeability};
+///
+/// let permeability = HazenPermeability::new(0.2, None);
+///
+/// assert_eq!(Ok(0.04), permeability.calculate());
+/// assert_eq!("Hazen Permeability: 1 * 0.2² = 0.04 cm/s", permeability.to_string());
+///
+/// let gradation = GradationAnalysis::new(vec![(4.75, 0.), (2., 300.), (0.85, 400.), (0.25, 200.), (0.075, 100.)], None);
+///
+/// assert_eq!(Ok(0.25), gradation.d10());
+/// assert_eq!(Ok(0.05), gradation.hazen_permeability(Some(0.8)).unwrap().calculate());
+/// ```
+///
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HazenPermeability<T: Float = f64> {
+  d10: T,
+  coefficient: Option<T>,
+}
+
+impl<T: Float> Rounding<T> for HazenPermeability<T> {}
+
+impl<T: Float> Calculate<T> for HazenPermeability<T> {
+  fn calculate_raw(&self) -> Result<T, CalcError> {
+    Ok(self.get_coefficient() * self.d10.powi(2))
+  }
+
+  fn precision(&self) -> i32 {
+    4
+  }
+
+  fn unit_label(&self) -> &'static str {
+    "cm/s"
+  }
+
+  fn terms(&self) -> Result<Vec<(&'static str, T)>, CalcError> {
+    Ok(vec![("d10", self.d10), ("coefficient", self.get_coefficient())])
+  }
+}
+
+impl<T: Float> HazenPermeability<T> {
+  /// Create a new instance of `HazenPermeability`.
+  ///
+  /// # Arguments
+  ///
+  /// - `d10` - A float that corresponds to the measurement: **D10**, in millimetres, e.g. from `GradationAnalysis::d10()`.
+  ///
+  /// - `coefficient` - An `Option<float>` that when `None` defaults to the constant value `HAZEN_COEFFICIENT`.
+  ///
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn new(d10: T, coefficient: Option<T>) -> Self {
+    Self { d10, coefficient }
+  }
+
+  /// Create a new, validated, instance of `HazenPermeability`.
+  ///
+  /// Returns a `ValidationError` if a value is `NaN`, infinite, or negative.
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn try_new(d10: T, coefficient: Option<T>) -> Result<Self, ValidationError> {
+    ValidationError::check_non_negative("d10", d10)?;
+    ValidationError::check_optional_non_negative("coefficient", coefficient)?;
+
+    Ok(Self::new(d10, coefficient))
+  }
+
+  /// Calculate the estimated hydraulic conductivity, in cm/s, rounded to four decimal places.
+  ///
+  /// Delegates to the `Calculate` implementation.
+  #[must_use = "the result of a calculation should be used, or its error handled"]
+  pub fn calculate(&self) -> Result<T, CalcError> {
+    Calculate::calculate(self)
+  }
+
+  /// Getter for `d10`
+  pub fn get_d10(&self) -> T {
+    self.d10
+  }
+
+  /// Getter for `coefficient`
+  pub fn get_coefficient(&self) -> T {
+    self.coefficient.unwrap_or(T::from_f64(HAZEN_COEFFICIENT))
+  }
+}
+
+impl<T: Float> fmt::Display for HazenPermeability<T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "Hazen Permeability: {} * {}²", self.get_coefficient(), self.d10)?;
+
+    match self.calculate() {
+      Ok(v) => write!(f, " = {} cm/s", v),
+      Err(e) => write!(f, " = [{}]", e),
+    }
+  }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn sample() -> GradationAnalysis {
+    GradationAnalysis::new(
+      vec![(0.075, 150.), (0.25, 100.), (0.425, 150.), (0.85, 200.), (2., 150.), (4.75, 100.), (9.5, 50.), (19., 0.)],
+      Some(100.),
+    )
+  }
+
+  #[test]
+  fn test_percent_passing() {
+    let gradation = sample();
+
+    assert_eq!(1000., gradation.total_mass());
+    assert_eq!(19., gradation.get_sieves()[0].0);
+    assert_eq!(
+      Ok(vec![(19., 100.), (9.5, 95.), (4.75, 85.), (2., 70.), (0.85, 50.), (0.425, 35.), (0.25, 25.), (0.075, 10.)]),
+      gradation.percent_passing()
+    );
+  }
+
+  #[test]
+  fn test_percent_passing_at() {
+    let gradation = sample();
+
+    assert_eq!(Ok(85.), gradation.percent_passing_at(SIEVE_NO_4));
+    assert_eq!(Ok(10.), gradation.percent_fines());
+    assert_eq!(Ok(59.9), gradation.percent_passing_at(1.3));
+    assert_eq!(Err(CalcError::OutOfRange), gradation.percent_passing_at(25.));
+  }
+
+  #[test]
+  fn test_diameters() {
+    let gradation = sample();
+
+    assert_eq!(Ok(0.075), gradation.d10());
+    assert_eq!(Ok(0.326), gradation.d30());
+    assert_eq!(Ok(1.3038), gradation.d60());
+    assert_eq!(Ok(17.38), gradation.coefficient_of_uniformity());
+    assert_eq!(Ok(1.09), gradation.coefficient_of_curvature());
+  }
+
+  #[test]
+  fn test_coarse_grading() {
+    let grading = sample().coarse_grading().unwrap();
+
+    assert_eq!(15., grading.get_percent_gravel());
+    assert_eq!(75., grading.get_percent_sand());
+    assert_eq!(Some(17.38), grading.get_coefficient_of_uniformity());
+    assert_eq!(Some(1.09), grading.get_coefficient_of_curvature());
+  }
+
+  #[test]
+  fn test_composition() {
+    let composition = sample().composition().unwrap();
+
+    assert_eq!(15., composition.get_gravel_percent());
+    assert_eq!(75., composition.get_sand_percent());
+    assert_eq!(10., composition.get_fines_percent());
+
+    // Without a #4 sieve, the percent passing it is interpolated from the 9.5 and 2 mm sieves.
+    let interpolated = GradationAnalysis::new(
+      vec![(19., 0.), (9.5, 50.), (2., 250.), (0.85, 200.), (0.425, 150.), (0.25, 100.), (0.075, 150.)],
+      Some(100.),
+    )
+    .composition()
+    .unwrap();
+
+    assert_eq!(16.1, interpolated.get_gravel_percent());
+    assert_eq!(73.9, interpolated.get_sand_percent());
+    assert_eq!(10., interpolated.get_fines_percent());
+    assert!((interpolated.get_gravel_percent() + interpolated.get_sand_percent() + interpolated.get_fines_percent() - 100.).abs() < 1e-9);
+
+    assert_eq!(Err(CalcError::OutOfRange), GradationAnalysis::new(vec![(2., 100.), (0.075, 100.)], None).composition());
+  }
+
+  #[test]
+  fn test_hazen_permeability() {
+    let permeability = HazenPermeability::new(0.2, None);
+
+    assert_eq!(Ok(0.04), permeability.calculate());
+    assert_eq!(1., permeability.get_coefficient());
+    assert_eq!(Ok(vec![("d10", 0.2), ("coefficient", 1.)]), permeability.terms());
+    assert_eq!(Ok(0.048), HazenPermeability::new(0.2, Some(1.2)).calculate());
+
+    // The sample's D10 is the #200 sieve, too fine for Hazen, but still calculated.
+    assert_eq!(Ok(0.0056), sample().hazen_permeability(None).unwrap().calculate());
+    assert_eq!(Err(CalcError::InsufficientData), GradationAnalysis::new(vec![(4.75, 10.)], None).hazen_permeability(None));
+
+    assert_eq!("coefficient", HazenPermeability::try_new(0.2, Some(-1.)).unwrap_err().field);
+    assert_eq!("Hazen Permeability: 1 * NaN² = [result is NaN or infinite]", HazenPermeability::new(f64::NAN, None).to_string());
+  }
+
+  #[test]
+  fn test_fuller_deviation() {
+    let deviation = sample().fuller_deviation(19.).unwrap();
+
+    assert_eq!(
+      vec![(19., 0.), (9.5, 24.3), (4.75, 35.), (2., 37.6), (0.85, 28.8), (0.425, 20.), (0.25, 13.5), (0.075, 3.7)],
+      deviation.get_deviations()
+    );
+    assert_eq!(24.1, deviation.get_rms());
+
+    // Sieves above the maximum size are ideally all passing.
+    assert_eq!((19., 0.), sample().fuller_deviation(9.5).unwrap().get_deviations()[0]);
+
+    assert_eq!(Err(CalcError::DivisionByZero), sample().fuller_deviation(0.));
+    assert_eq!(Err(CalcError::InsufficientData), GradationAnalysis::<f64>::new(vec![], Some(100.)).fuller_deviation(19.));
+  }
+
+  #[test]
+  fn test_f32() {
+    let gradation: GradationAnalysis<f32> = GradationAnalysis::new(vec![(4.75, 150.), (0.85, 350.), (0.075, 400.)], Some(100.));
+
+    assert_eq!(Ok(vec![(4.75, 85.), (0.85, 50.), (0.075, 10.)]), gradation.percent_passing());
+    assert_eq!(Ok(0.075_f32), gradation.d10());
+  }
+
+  #[test]
+  fn test_errors() {
+    assert_eq!(Err(CalcError::DivisionByZero), GradationAnalysis::new(vec![(4.75, 0.)], None).percent_passing());
+    assert_eq!(Err(CalcError::InsufficientData), GradationAnalysis::new(vec![(4.75, 10.)], None).d10());
+    assert_eq!(Err(CalcError::OutOfRange), sample().diameter(5.));
+  }
+
+  #[test]
+  fn test_sieve_sizes() {
+    let stack = SieveSize::standard_stack();
+
+    assert_eq!(13, stack.len());
+    assert!(stack.windows(2).all(|w| w[0].diameter_mm() > w[1].diameter_mm()));
+    assert_eq!(6, stack.iter().filter(|s| s.fraction() == GrainFraction::Sand).count());
+    assert_eq!(GrainFraction::Gravel, SieveSize::No4.fraction());
+    assert_eq!("#200", SieveSize::No200.to_string());
+
+    let from_sizes = GradationAnalysis::from_sieve_sizes(
+      vec![(SieveSize::No200, 150.), (SieveSize::No60, 100.), (SieveSize::No40, 150.), (SieveSize::No20, 200.), (SieveSize::No10, 150.), (SieveSize::No4, 100.), (SieveSize::ThreeEighthInch, 50.), (SieveSize::ThreeQuarterInch, 0.)],
+      Some(100.),
+    );
+    assert_eq!(sample(), from_sizes);
+  }
+
+  #[test]
+  fn test_try_new() {
+    assert!(GradationAnalysis::try_new(vec![(4.75, 10.), (0.075, 5.)], None).is_ok());
+
+    let error = GradationAnalysis::try_new(vec![(4.75, -10.)], None).unwrap_err();
+    assert_eq!("mass_retained", error.field);
+  }
+}
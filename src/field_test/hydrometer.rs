@@ -0,0 +1,175 @@
+//! Hydrometer
+use crate::domain::errors::{CalcError, ValidationError};
+use crate::domain::traits::Rounding;
+use crate::domain::units::{water_density, water_viscosity};
+use crate::math::float::{checked_div, Float};
+use super::constants::SPECIFIC_GRAVITY;
+
+/// Hydrometer analysis calculation, for a single reading of a 152H hydrometer.
+///
+/// Extends a `GradationAnalysis` below the #200 sieve. The particle diameter is found from Stokes' law, `D = sqrt(30 * η * L / (980 * (Gs - Gw) * t))`,
+/// and the percent finer from `P = a * R / Ws * 100`, where `a = 1.65 * Gs / ((Gs - 1) * 2.65)` corrects for the specific gravity the hydrometer is calibrated to.
+///
+/// The viscosity, `η`, and specific gravity, `Gw`, of water come from the `water_viscosity()` and `water_density()` tables for the test temperature. For Stokes' law on its own, see `math::utilities::stokes_diameter()`.
+///
+/// # Example
+///
+/// ```
+/// use billios::field_test::HydrometerAnalysis;
+///
+/// let reading = HydrometerAnalysis::new(42., 10.1, 2., 20., 50., Some(2.65));
+///
+/// assert_eq!(Ok(0.0306), reading.diameter());
+/// assert_eq!(Ok(84.), reading.percent_finer());
+/// ```
+///
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HydrometerAnalysis<T: Float = f64> {
+  reading: T,
+  effective_depth: T,
+  elapsed_time: T,
+  temperature: T,
+  dry_soil: T,
+  specific_gravity: Option<T>,
+}
+
+impl<T: Float> Rounding<T> for HydrometerAnalysis<T> {}
+
+impl<T: Float> HydrometerAnalysis<T> {
+  /// Create a new instance of `HydrometerAnalysis`.
+  ///
+  /// # Arguments
+  ///
+  /// - `reading` - A float that corresponds to the measurement: **Corrected Hydrometer Reading**, in grams per litre.
+  ///
+  /// - `effective_depth` - A float that corresponds to the measurement: **Effective Depth**, `L`, in centimetres.
+  ///
+  /// - `elapsed_time` - A float that corresponds to the measurement: **Elapsed Time**, `t`, in minutes.
+  ///
+  /// - `temperature` - A float that corresponds to the measurement: **Temperature** of the suspension, in °C.
+  ///
+  /// - `dry_soil` - A float that corresponds to the measurement: **Dry Soil** dispersed, `Ws`, in grams.
+  ///
+  /// - `specific_gravity` - An `Option<T>` that when `None` defaults to the constant value `SPECIFIC_GRAVITY`. This can be modified as needed by setting a `Some()` value.
+  ///
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn new(reading: T, effective_depth: T, elapsed_time: T, temperature: T, dry_soil: T, specific_gravity: Option<T>) -> Self {
+    Self { reading, effective_depth, elapsed_time, temperature, dry_soil, specific_gravity }
+  }
+
+  /// Create a new, validated, instance of `HydrometerAnalysis`.
+  ///
+  /// Returns a `ValidationError` if any value is `NaN`, infinite, or negative.
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn try_new(reading: T, effective_depth: T, elapsed_time: T, temperature: T, dry_soil: T, specific_gravity: Option<T>) -> Result<Self, ValidationError> {
+    ValidationError::check_non_negative("reading", reading)?;
+    ValidationError::check_non_negative("effective_depth", effective_depth)?;
+    ValidationError::check_non_negative("elapsed_time", elapsed_time)?;
+    ValidationError::check_non_negative("temperature", temperature)?;
+    ValidationError::check_non_negative("dry_soil", dry_soil)?;
+    ValidationError::check_optional_non_negative("specific_gravity", specific_gravity)?;
+
+    Ok(Self::new(reading, effective_depth, elapsed_time, temperature, dry_soil, specific_gravity))
+  }
+
+  /// Calculate the particle diameter, in millimetres, rounded to four decimal places.
+  ///
+  /// Returns `CalcError::DivisionByZero` when the absolute value of `elapsed_time`, or of `Gs - Gw`, is less than `T::EPSILON`.
+  pub fn diameter(&self) -> Result<T, CalcError> {
+    let viscosity = T::from_f64(water_viscosity(self.temperature.to_f64()));
+    let specific_gravity_water = T::from_f64(water_density(self.temperature.to_f64()));
+
+    let denominator = T::from_f64(980.) * (self.get_specific_gravity() - specific_gravity_water) * self.elapsed_time;
+
+    let result = checked_div(T::from_f64(30.) * viscosity * self.effective_depth, denominator)?.sqrt();
+
+    if !result.is_finite() {
+      return Err(CalcError::NonFinite);
+    }
+
+    self.round_n(result, 4)
+  }
+
+  /// Calculate the percent finer than the `diameter()`, rounded to one decimal place.
+  ///
+  /// Returns `CalcError::DivisionByZero` when the absolute value of `dry_soil`, or of `Gs - 1`, is less than `T::EPSILON`.
+  pub fn percent_finer(&self) -> Result<T, CalcError> {
+    let specific_gravity = self.get_specific_gravity();
+    let denominator = (specific_gravity - T::ONE) * T::from_f64(2.65) * self.dry_soil;
+
+    let result = checked_div(T::from_f64(1.65) * specific_gravity * self.reading, denominator)? * T::from_f64(100.);
+
+    self.round_n(result, 1)
+  }
+
+  /// Calculate the point on the particle size distribution as `(diameter, percent finer)`.
+  ///
+  /// This is the same form as the `percent_passing()` of a `GradationAnalysis`.
+  pub fn point(&self) -> Result<(T, T), CalcError> {
+    Ok((self.diameter()?, self.percent_finer()?))
+  }
+
+  /// Getter for `reading`
+  pub fn get_reading(&self) -> T {
+    self.reading
+  }
+
+  /// Getter for `effective_depth`
+  pub fn get_effective_depth(&self) -> T {
+    self.effective_depth
+  }
+
+  /// Getter for `elapsed_time`
+  pub fn get_elapsed_time(&self) -> T {
+    self.elapsed_time
+  }
+
+  /// Getter for `temperature`
+  pub fn get_temperature(&self) -> T {
+    self.temperature
+  }
+
+  /// Getter for `dry_soil`
+  pub fn get_dry_soil(&self) -> T {
+    self.dry_soil
+  }
+
+  /// Getter for `specific_gravity`.
+  ///
+  /// If no value is provided, the default is set to the `SPECIFIC_GRAVITY` constant.
+  pub fn get_specific_gravity(&self) -> T {
+    match self.specific_gravity {
+      Some(v) => v,
+      None => T::from_f64(SPECIFIC_GRAVITY),
+    }
+  }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_hydrometer_analysis() {
+    let reading = HydrometerAnalysis::new(42., 10.1, 2., 20., 50., Some(2.65));
+    assert_eq!(Ok(0.0306), reading.diameter());
+    assert_eq!(Ok(84.), reading.percent_finer());
+    assert_eq!(Ok((0.0306, 84.)), reading.point());
+
+    // Later readings settle finer particles.
+    let later = HydrometerAnalysis::new(20., 13.8, 1440., 24., 50., None);
+    assert_eq!(Ok(0.0013), later.diameter());
+    assert_eq!(Ok(39.6), later.percent_finer());
+  }
+
+  #[test]
+  fn test_errors() {
+    assert_eq!(Err(CalcError::DivisionByZero), HydrometerAnalysis::new(42., 10.1, 0., 20., 50., None).diameter());
+    assert_eq!(Err(CalcError::DivisionByZero), HydrometerAnalysis::new(42., 10.1, 2., 20., 0., None).percent_finer());
+    assert_eq!(Err(CalcError::DivisionByZero), HydrometerAnalysis::new(42., 10.1, 2., 20., 50., Some(1.)).percent_finer());
+    assert_eq!("elapsed_time", HydrometerAnalysis::try_new(42., 10.1, -2., 20., 50., None).unwrap_err().field);
+  }
+}
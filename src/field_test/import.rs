@@ -0,0 +1,309 @@
+//! Import
+//!
+//! Build field test calculations from a CSV export. Requires the `csv` feature.
+//!
+//! The CSV must have a header row with the columns `cone_pre_test`, `cone_post_test`, `soil`, `wet_weight`, `dry_weight`, `tare_pan` and `lab_max`, in any order.
+//!
+//! # Example
+//!
+//! ```
+//! use billios::field_test::import::from_csv_reader;
+//!
+//! let data = "\
+//! cone_pre_test,cone_post_test,soil,wet_weight,dry_weight,tare_pan,lab_max
+//! 14.65,8.75,4.65,1600,1575,1400,135.6
+//! ";
+//!
+//! let records = from_csv_reader(data.as_bytes()).unwrap();
+//! assert_eq!(Ok(114.3), records[0].compaction().calculate());
+//! ```
+use std::fmt;
+use std::collections::VecDeque;
+use std::io;
+
+use crate::domain::errors::ValidationError;
+use super::calculations::Compaction;
+use super::report::FieldTestReport;
+
+/// The raw measurements of a single sand cone field test.
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct FieldTestRecord {
+  cone_pre_test: f64,
+  cone_post_test: f64,
+  soil: f64,
+  wet_weight: f64,
+  dry_weight: f64,
+  tare_pan: f64,
+  lab_max: f64,
+}
+
+impl FieldTestRecord {
+  /// Create a new instance of `FieldTestRecord`.
+  pub fn new(cone_pre_test: f64, cone_post_test: f64, soil: f64, wet_weight: f64, dry_weight: f64, tare_pan: f64, lab_max: f64) -> Self {
+    Self { cone_pre_test, cone_post_test, soil, wet_weight, dry_weight, tare_pan, lab_max }
+  }
+
+  /// Check that every measurement is finite and not negative.
+  pub fn validate(&self) -> Result<(), ValidationError> {
+    ValidationError::check_non_negative("cone_pre_test", self.cone_pre_test)?;
+    ValidationError::check_non_negative("cone_post_test", self.cone_post_test)?;
+    ValidationError::check_non_negative("soil", self.soil)?;
+    ValidationError::check_non_negative("wet_weight", self.wet_weight)?;
+    ValidationError::check_non_negative("dry_weight", self.dry_weight)?;
+    ValidationError::check_non_negative("tare_pan", self.tare_pan)?;
+    ValidationError::check_non_negative("lab_max", self.lab_max)
+  }
+
+  /// Build the `FieldTestReport` for this test, with every intermediate calculation.
+  ///
+  /// The default constants are used for the sand in the cone and the sand density.
+  pub fn report(&self) -> FieldTestReport {
+    FieldTestReport::new(self.cone_pre_test, self.cone_post_test, self.soil, self.wet_weight, self.dry_weight, self.tare_pan, self.lab_max)
+  }
+
+  /// Build the `Compaction` for this test, chaining the sand used, wet density, moisture content and dry density calculations.
+  ///
+  /// The default constants are used for the sand in the cone and the sand density.
+  pub fn compaction(&self) -> Compaction {
+    self.report().get_compaction()
+  }
+
+  /// Getter for `cone_pre_test`
+  pub fn get_cone_pre_test(&self) -> f64 {
+    self.cone_pre_test
+  }
+
+  /// Getter for `cone_post_test`
+  pub fn get_cone_post_test(&self) -> f64 {
+    self.cone_post_test
+  }
+
+  /// Getter for `soil`
+  pub fn get_soil(&self) -> f64 {
+    self.soil
+  }
+
+  /// Getter for `wet_weight`
+  pub fn get_wet_weight(&self) -> f64 {
+    self.wet_weight
+  }
+
+  /// Getter for `dry_weight`
+  pub fn get_dry_weight(&self) -> f64 {
+    self.dry_weight
+  }
+
+  /// Getter for `tare_pan`
+  pub fn get_tare_pan(&self) -> f64 {
+    self.tare_pan
+  }
+
+  /// Getter for `lab_max`
+  pub fn get_lab_max(&self) -> f64 {
+    self.lab_max
+  }
+}
+
+/// The reason a row could not be imported.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum ImportErrorKind {
+  /// The row could not be read or parsed.
+  Parse(String),
+  /// The row was parsed, but a value is invalid.
+  Invalid(ValidationError),
+}
+
+impl fmt::Display for ImportErrorKind {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      ImportErrorKind::Parse(message) => write!(f, "{}", message),
+      ImportErrorKind::Invalid(error) => write!(f, "{}", error),
+    }
+  }
+}
+
+/// An error returned when a CSV row cannot be imported.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportError {
+  /// The 1-based line number of the row in the file, counting the header and any blank lines.
+  pub line: u64,
+  /// The reason the row could not be imported.
+  pub kind: ImportErrorKind,
+}
+
+impl fmt::Display for ImportError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "line {}: {}", self.line, self.kind)
+  }
+}
+
+impl std::error::Error for ImportError {}
+
+/// Passes the bytes of a reader through, recording the offset of each line terminator, so the line of a record can be found from its byte offset.
+///
+/// The position `csv` gives a record is where it started reading, before the blank lines it skips, so its line would point above the record. The terminators before a record are counted, and dropped, as each record is read, so only those `csv` has read ahead are kept.
+struct LineTerminators<R> {
+  inner: R,
+  offset: u64,
+  terminators: VecDeque<(u64, u8)>,
+  lines: u64,
+}
+
+impl<R> LineTerminators<R> {
+  fn new(inner: R) -> Self {
+    Self { inner, offset: 0, terminators: VecDeque::new(), lines: 0 }
+  }
+
+  /// The 1-based line of the first byte, at or after `byte`, that is not a line terminator.
+  ///
+  /// Each call must be for a `byte` at or after that of the call before it, as the terminators before it are dropped.
+  fn line(&mut self, byte: u64) -> u64 {
+    let mut byte = byte;
+
+    while let Some((offset, b)) = self.terminators.front().copied() {
+      if offset > byte {
+        break;
+      }
+
+      // A terminator at `byte` ends a blank line, so the record starts after it.
+      if offset == byte {
+        byte += 1;
+      }
+
+      self.terminators.pop_front();
+
+      // A `\r\n` ends one line, the same as a `\n` or a lone `\r`.
+      if b == b'\n' || self.terminators.front() != Some(&(offset + 1, b'\n')) {
+        self.lines += 1;
+      }
+    }
+
+    self.lines + 1
+  }
+}
+
+impl<R: io::Read> io::Read for LineTerminators<R> {
+  fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+    let n = self.inner.read(buf)?;
+
+    for (i, &b) in buf[..n].iter().enumerate() {
+      if b == b'\n' || b == b'\r' {
+        self.terminators.push_back((self.offset + i as u64, b));
+      }
+    }
+
+    self.offset += n as u64;
+
+    Ok(n)
+  }
+}
+
+/// An `ImportError` for a `csv::Error`, at its line, or the first line if it has no position.
+fn parse_error<R: io::Read>(reader: &mut csv::Reader<LineTerminators<R>>, e: csv::Error) -> ImportError {
+  let line = reader.get_mut().line(e.position().map_or(0, |p| p.byte()));
+
+  ImportError { line, kind: ImportErrorKind::Parse(e.to_string()) }
+}
+
+/// Parse every row of a CSV into a `FieldTestRecord`.
+///
+/// Returns an `ImportError` with the line number, in the file, of the first row that is malformed or contains an invalid value.
+pub fn from_csv_reader<R: io::Read>(reader: R) -> Result<Vec<FieldTestRecord>, ImportError> {
+  let mut reader = csv::ReaderBuilder::new().trim(csv::Trim::All).from_reader(LineTerminators::new(reader));
+
+  let headers = reader.headers().cloned();
+  let headers = headers.map_err(|e| parse_error(&mut reader, e))?;
+
+  let mut records = Vec::new();
+  let mut record = csv::StringRecord::new();
+
+  loop {
+    match reader.read_record(&mut record) {
+      Ok(true) => {},
+      Ok(false) => break,
+      Err(e) => return Err(parse_error(&mut reader, e)),
+    }
+
+    let line = reader.get_mut().line(record.position().map_or(0, |p| p.byte()));
+    let parsed: FieldTestRecord = record.deserialize(Some(&headers)).map_err(|e| ImportError { line, kind: ImportErrorKind::Parse(e.to_string()) })?;
+
+    parsed.validate().map_err(|e| ImportError { line, kind: ImportErrorKind::Invalid(e) })?;
+
+    records.push(parsed);
+  }
+
+  Ok(records)
+}
+
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::domain::errors::ValidationErrorKind;
+
+  const HEADER: &str = "cone_pre_test,cone_post_test,soil,wet_weight,dry_weight,tare_pan,lab_max\n";
+
+  #[test]
+  fn test_from_csv_reader() {
+    let data = format!("{}14.65,8.75,4.65,1600,1575,1400,135.6\n14.65, 8.75, 4.65, 1600, 1575, 1400, 150\n", HEADER);
+    let records = from_csv_reader(data.as_bytes()).unwrap();
+
+    assert_eq!(2, records.len());
+    assert_eq!(FieldTestRecord::new(14.65, 8.75, 4.65, 1600., 1575., 1400., 135.6), records[0]);
+
+    let results: Vec<_> = records.iter().map(|r| r.compaction().calculate()).collect();
+    assert_eq!(vec![Ok(114.3), Ok(103.3)], results);
+    assert_eq!(Ok(155.), records[1].report().dry_density());
+  }
+
+  #[test]
+  fn test_from_csv_reader_reordered_columns() {
+    let data = "lab_max,tare_pan,dry_weight,wet_weight,soil,cone_post_test,cone_pre_test\n135.6,1400,1575,1600,4.65,8.75,14.65\n";
+    let records = from_csv_reader(data.as_bytes()).unwrap();
+
+    assert_eq!(Ok(114.3), records[0].compaction().calculate());
+  }
+
+  #[test]
+  fn test_from_csv_reader_errors() {
+    let malformed = format!("{}14.65,8.75,4.65,1600,1575,1400,135.6\n14.65,abc,4.65,1600,1575,1400,135.6\n", HEADER);
+    let error = from_csv_reader(malformed.as_bytes()).unwrap_err();
+    assert_eq!(3, error.line);
+    assert!(matches!(error.kind, ImportErrorKind::Parse(_)));
+    assert!(error.to_string().starts_with("line 3: "));
+
+    let missing = format!("{}14.65,8.75,4.65\n", HEADER);
+    assert_eq!(2, from_csv_reader(missing.as_bytes()).unwrap_err().line);
+
+    // Blank lines are skipped, but still counted.
+    let blank_lines = format!("{}\n14.65,8.75,4.65,1600,1575,1400,135.6\n\n\n14.65,abc,4.65,1600,1575,1400,135.6\n", HEADER);
+    assert_eq!(6, from_csv_reader(blank_lines.as_bytes()).unwrap_err().line);
+
+    let blank_lines = format!("{}\n\n14.65,8.75,4.65,1600,1575,-1400,135.6\n", HEADER);
+    assert_eq!(4, from_csv_reader(blank_lines.as_bytes()).unwrap_err().line);
+
+    let crlf = "cone_pre_test,cone_post_test,soil,wet_weight,dry_weight,tare_pan,lab_max\r\n\r\n14.65,8.75,4.65,1600,1575,1400,135.6\r\n14.65,8.75\r\n";
+
+    // Every line is counted once, however many rows there are.
+    let mut large = String::from(HEADER);
+
+    for i in 0..20_000 {
+      large.push_str("14.65,8.75,4.65,1600,1575,1400,135.6\n");
+
+      if i % 100 == 0 {
+        large.push('\n');
+      }
+    }
+
+    large.push_str("14.65,8.75,4.65,1600,1575,1400,abc\n");
+    assert_eq!(20_202, from_csv_reader(large.as_bytes()).unwrap_err().line);
+    assert_eq!(4, from_csv_reader(crlf.as_bytes()).unwrap_err().line);
+
+    let invalid = format!("{}14.65,8.75,4.65,1600,1575,-1400,135.6\n", HEADER);
+    let error = from_csv_reader(invalid.as_bytes()).unwrap_err();
+    assert_eq!(ImportErrorKind::Invalid(ValidationError::new("tare_pan", ValidationErrorKind::Negative)), error.kind);
+    assert_eq!("line 2: `tare_pan` must not be negative", error.to_string());
+  }
+}
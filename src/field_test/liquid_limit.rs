@@ -0,0 +1,179 @@
+//! Liquid Limit
+use alloc::vec::Vec;
+use crate::domain::errors::{CalcError, ValidationError};
+use crate::domain::traits::{Calculate, Rounding};
+use crate::math::float::{checked_div, Float};
+use super::calculations::PlasticityIndex;
+use super::constants::LIQUID_LIMIT_BLOWS;
+use core::fmt;
+
+/// Liquid limit from the flow curve of a multipoint Casagrande cup test.
+///
+/// Fits the flow line, `w = a + b * log10(N)`, to a series of `(blow count, moisture content)` points by least squares, and reads the liquid limit as the moisture content at `LIQUID_LIMIT_BLOWS`, 25 blows. The moisture contents are percents, the same as the liquid limit of `PlasticityIndex`.
+///
+/// # Example
+///
+/// ```
+/// use billios::field_test::LiquidLimitFlowCurve;
+///
+/// let flow_curve = LiquidLimitFlowCurve::new(vec![(34., 38.2), (27., 40.1), (19., 42.6), (15., 44.)]);
+///
+/// assert_eq!(Ok(41.), flow_curve.calculate());
+/// assert_eq!(Ok(16.3), flow_curve.flow_index());
+///
+/// let plasticity_index = flow_curve.plasticity_index(22.).unwrap();
+/// assert_eq!(Ok(19.), plasticity_index.calculate());
+/// ```
+///
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LiquidLimitFlowCurve<T: Float = f64> {
+  points: Vec<(T, T)>,
+}
+
+impl<T: Float> Rounding<T> for LiquidLimitFlowCurve<T> {}
+
+impl<T: Float> Calculate<T> for LiquidLimitFlowCurve<T> {
+  fn calculate_raw(&self) -> Result<T, CalcError> {
+    let (intercept, slope) = self.fit()?;
+
+    Ok(intercept + slope * T::from_f64(LIQUID_LIMIT_BLOWS).ln() / T::from_f64(10.).ln())
+  }
+
+  fn precision(&self) -> i32 {
+    0
+  }
+
+  fn unit_label(&self) -> &'static str {
+    "%"
+  }
+}
+
+impl<T: Float> LiquidLimitFlowCurve<T> {
+  /// Create a new instance of `LiquidLimitFlowCurve`.
+  ///
+  /// # Arguments
+  ///
+  /// - `points` - A list of `(blow count, moisture content)` pairs from the Casagrande cup, with the moisture content as a percent.
+  ///
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn new(points: Vec<(T, T)>) -> Self {
+    Self { points }
+  }
+
+  /// Create a new, validated, instance of `LiquidLimitFlowCurve`.
+  ///
+  /// Returns a `ValidationError` if any value is `NaN`, infinite, or negative.
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn try_new(points: Vec<(T, T)>) -> Result<Self, ValidationError> {
+    for &(blows, moisture_content) in &points {
+      ValidationError::check_non_negative("blows", blows)?;
+      ValidationError::check_non_negative("moisture_content", moisture_content)?;
+    }
+
+    Ok(Self::new(points))
+  }
+
+  /// Fit the flow line, returning `(a, b)` of `w = a + b * log10(N)`.
+  fn fit(&self) -> Result<(T, T), CalcError> {
+    if self.points.len() < 2 {
+      return Err(CalcError::InsufficientData);
+    }
+
+    let log10 = |blows: T| blows.ln() / T::from_f64(10.).ln();
+
+    let n = T::from_f64(self.points.len() as f64);
+    let mean_x = self.points.iter().map(|&(blows, _)| log10(blows)).sum::<T>() / n;
+    let mean_y = self.points.iter().map(|&(_, w)| w).sum::<T>() / n;
+
+    let (mut sxx, mut sxy) = (T::ZERO, T::ZERO);
+
+    for &(blows, w) in &self.points {
+      let x = log10(blows) - mean_x;
+
+      sxx = sxx + x * x;
+      sxy = sxy + x * (w - mean_y);
+    }
+
+    let slope = checked_div(sxy, sxx)?;
+
+    Ok((mean_y - slope * mean_x, slope))
+  }
+
+  /// Calculate the Liquid Limit, as a percent rounded to the nearest whole number.
+  ///
+  /// Returns `CalcError::InsufficientData` if fewer than two points were given, and `CalcError::DivisionByZero` if every point has the same blow count.
+  ///
+  /// Delegates to the `Calculate` implementation.
+  #[must_use = "the result of a calculation should be used, or its error handled"]
+  pub fn calculate(&self) -> Result<T, CalcError> {
+    Calculate::calculate(self)
+  }
+
+  /// Calculate the flow index, the fall in moisture content over one log cycle of blows, `-b`, rounded to one decimal place.
+  ///
+  /// Returns the same errors as `calculate()`.
+  pub fn flow_index(&self) -> Result<T, CalcError> {
+    self.round_n(-self.fit()?.1, 1)
+  }
+
+  /// Create a `PlasticityIndex` from the rounded liquid limit of the flow curve and the `plastic_limit`, as a percent.
+  ///
+  /// Returns any error from `calculate()`.
+  pub fn plasticity_index(&self, plastic_limit: T) -> Result<PlasticityIndex<T>, CalcError> {
+    Ok(PlasticityIndex::new(self.calculate()?, plastic_limit))
+  }
+
+  /// Getter for `points`
+  pub fn get_points(&self) -> &[(T, T)] {
+    &self.points
+  }
+}
+
+impl<T: Float> fmt::Display for LiquidLimitFlowCurve<T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "Liquid Limit: w at {} blows", LIQUID_LIMIT_BLOWS)?;
+
+    match self.calculate() {
+      Ok(v) => write!(f, " = {}%", v),
+      Err(e) => write!(f, " = [{}]", e),
+    }
+  }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use alloc::string::ToString;
+  use alloc::vec;
+
+  #[test]
+  fn test_liquid_limit_flow_curve() {
+    // Exactly on `w = 60 - 15 * log10(N)`.
+    let exact = LiquidLimitFlowCurve::new(vec![(10., 45.), (100., 30.)]);
+    assert_eq!(Ok(39.), exact.calculate());
+    assert_eq!(Ok(39.03), exact.calculate_with_precision(2));
+    assert_eq!(Ok(15.), exact.flow_index());
+    assert_eq!("Liquid Limit: w at 25 blows = 39%", exact.to_string());
+
+    let measured = LiquidLimitFlowCurve::new(vec![(34., 38.2), (27., 40.1), (19., 42.6), (15., 44.)]);
+    assert_eq!(Ok(40.51), measured.calculate_with_precision(2));
+    assert_eq!(4, measured.get_points().len());
+
+    let plasticity_index = measured.plasticity_index(22.).unwrap();
+    assert_eq!(41., plasticity_index.get_liquid_limit());
+  }
+
+  #[test]
+  fn test_liquid_limit_flow_curve_errors() {
+    assert_eq!(Err(CalcError::InsufficientData), LiquidLimitFlowCurve::new(vec![(25., 40.)]).calculate());
+    assert_eq!(Err(CalcError::InsufficientData), LiquidLimitFlowCurve::new(vec![(25., 40.)]).flow_index());
+    assert_eq!(Err(CalcError::DivisionByZero), LiquidLimitFlowCurve::new(vec![(25., 40.), (25., 42.)]).calculate());
+    assert_eq!(Err(CalcError::NonFinite), LiquidLimitFlowCurve::new(vec![(0., 40.), (25., 42.)]).calculate());
+
+    assert!(LiquidLimitFlowCurve::try_new(vec![(34., 38.2), (15., 44.)]).is_ok());
+    assert_eq!("blows", LiquidLimitFlowCurve::try_new(vec![(-15., 44.)]).unwrap_err().field);
+  }
+}
@@ -1,8 +1,42 @@
+mod acceptance;
+mod bearing;
+mod builders;
 mod calculations;
+mod composite;
 mod constants;
+mod conversions;
+mod gradation;
+mod hydrometer;
+mod liquid_limit;
+mod nuclear;
+mod phase;
+mod proctor;
+mod profile;
+mod report;
+mod settlement;
+mod stress;
 
+pub mod batch;
+#[cfg(feature = "csv")]
+pub mod import;
+pub mod prelude;
 pub mod types;
 
 
+pub use acceptance::*;
+pub use bearing::*;
+pub use builders::*;
 pub use calculations::*;
+pub use composite::*;
 pub use constants::*;
+pub use conversions::*;
+pub use gradation::*;
+pub use hydrometer::*;
+pub use liquid_limit::*;
+pub use nuclear::*;
+pub use phase::*;
+pub use proctor::*;
+pub use profile::*;
+pub use report::*;
+pub use settlement::*;
+pub use stress::*;
@@ -0,0 +1,279 @@
+//! Nuclear
+use crate::domain::errors::{CalcError, ValidationError};
+use crate::domain::traits::Rounding;
+use crate::math::float::{checked_div, Float};
+
+/// The calibration coefficients of a nuclear density gauge.
+///
+/// The density count ratio follows `DCR = A * e^(-B * WD) - C`, and the moisture count ratio `MCR = E * M + F`, where `WD` is the wet density and `M` the mass of water per volume, both in pcf.
+/// The coefficients vary by instrument, so are taken from the calibration sheet of each gauge.
+///
+/// The count ratios are the field counts divided by the standard counts taken with the gauge each day. Set the day's standard counts with `standard_counts()` to convert raw counts with `NuclearGauge::from_counts()`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GaugeCalibration<T: Float = f64> {
+  a: T,
+  b: T,
+  c: T,
+  e: T,
+  f: T,
+  density_standard_count: Option<T>,
+  moisture_standard_count: Option<T>,
+}
+
+impl<T: Float> GaugeCalibration<T> {
+  /// Create a new instance of `GaugeCalibration`.
+  ///
+  /// # Arguments
+  ///
+  /// - `a`, `b`, `c` - The density coefficients, with `b` in 1/pcf.
+  ///
+  /// - `e`, `f` - The moisture coefficients, with `e` in 1/pcf.
+  ///
+  pub fn new(a: T, b: T, c: T, e: T, f: T) -> Self {
+    Self { a, b, c, e, f, density_standard_count: None, moisture_standard_count: None }
+  }
+
+  /// Setter for the day's `density_standard_count` and `moisture_standard_count`.
+  pub fn standard_counts(mut self, density_standard_count: T, moisture_standard_count: T) -> Self {
+    self.density_standard_count = Some(density_standard_count);
+    self.moisture_standard_count = Some(moisture_standard_count);
+    self
+  }
+
+  /// Getter for `a`
+  pub fn get_a(&self) -> T {
+    self.a
+  }
+
+  /// Getter for `b`
+  pub fn get_b(&self) -> T {
+    self.b
+  }
+
+  /// Getter for `c`
+  pub fn get_c(&self) -> T {
+    self.c
+  }
+
+  /// Getter for `e`
+  pub fn get_e(&self) -> T {
+    self.e
+  }
+
+  /// Getter for `f`
+  pub fn get_f(&self) -> T {
+    self.f
+  }
+
+  /// Getter for `density_standard_count`
+  pub fn get_density_standard_count(&self) -> Option<T> {
+    self.density_standard_count
+  }
+
+  /// Getter for `moisture_standard_count`
+  pub fn get_moisture_standard_count(&self) -> Option<T> {
+    self.moisture_standard_count
+  }
+}
+
+/// Nuclear density gauge calculation, from the count ratios of a single reading.
+///
+/// An alternative to the sand cone, the results can be passed on as a `WetDensityChoice::Value()` and `MoistureContentChoice::Value()`.
+///
+/// # Example
+///
+/// ```
+/// use billios::field_test::{DryDensity, GaugeCalibration, NuclearGauge};
+/// use billios::field_test::types::{MoistureContentChoice, WetDensityChoice};
+///
+/// let calibration = GaugeCalibration::new(10., 0.015, 0.2, 0.02, 0.1);
+/// let gauge = NuclearGauge::new(1.3335, 0.34, calibration);
+///
+/// assert_eq!(Ok(125.0022), gauge.wet_density());
+/// assert_eq!(Ok(0.10619266), gauge.moisture_content());
+///
+/// let dry_density = DryDensity::new(
+///   WetDensityChoice::Value(gauge.wet_density().unwrap()),
+///   MoistureContentChoice::Value(gauge.moisture_content().unwrap()),
+/// );
+///
+/// assert_eq!(Ok(113.), dry_density.calculate());
+/// assert_eq!(Ok(113.0022), gauge.dry_density());
+/// ```
+///
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NuclearGauge<T: Float = f64> {
+  density_count_ratio: T,
+  moisture_count_ratio: T,
+  calibration: GaugeCalibration<T>,
+}
+
+impl<T: Float> Rounding<T> for NuclearGauge<T> {}
+
+impl<T: Float> NuclearGauge<T> {
+  /// Create a new instance of `NuclearGauge`.
+  ///
+  /// # Arguments
+  ///
+  /// - `density_count_ratio` - A float that corresponds to the measurement: **Density Count Ratio**, the density count divided by the standard count.
+  ///
+  /// - `moisture_count_ratio` - A float that corresponds to the measurement: **Moisture Count Ratio**, the moisture count divided by the standard count.
+  ///
+  /// - `calibration` - The `GaugeCalibration` of the gauge used.
+  ///
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn new(density_count_ratio: T, moisture_count_ratio: T, calibration: GaugeCalibration<T>) -> Self {
+    Self { density_count_ratio, moisture_count_ratio, calibration }
+  }
+
+  /// Create a new instance of `NuclearGauge` from the raw field counts, divided by the standard counts of the `calibration`.
+  ///
+  /// Returns `CalcError::InsufficientData` if the `calibration` has no standard counts, and `CalcError::DivisionByZero` when the absolute value of a standard count is less than `T::EPSILON`.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use billios::field_test::{GaugeCalibration, NuclearGauge};
+  ///
+  /// let calibration = GaugeCalibration::new(10., 0.015, 0.2, 0.02, 0.1).standard_counts(2800., 650.);
+  /// let gauge = NuclearGauge::from_counts(3733.8, 221., calibration).unwrap();
+  ///
+  /// assert_eq!(Ok(125.0022), gauge.wet_density());
+  /// assert_eq!(Ok(12.), gauge.moisture_density());
+  /// ```
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn from_counts(density_count: T, moisture_count: T, calibration: GaugeCalibration<T>) -> Result<Self, CalcError> {
+    let (Some(density_standard_count), Some(moisture_standard_count)) = (calibration.density_standard_count, calibration.moisture_standard_count) else {
+      return Err(CalcError::InsufficientData);
+    };
+
+    Ok(Self::new(checked_div(density_count, density_standard_count)?, checked_div(moisture_count, moisture_standard_count)?, calibration))
+  }
+
+  /// Create a new, validated, instance of `NuclearGauge`.
+  ///
+  /// Returns a `ValidationError` if a count ratio is `NaN`, infinite, or negative. The calibration coefficients are not checked, as they may be negative.
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn try_new(density_count_ratio: T, moisture_count_ratio: T, calibration: GaugeCalibration<T>) -> Result<Self, ValidationError> {
+    ValidationError::check_non_negative("density_count_ratio", density_count_ratio)?;
+    ValidationError::check_non_negative("moisture_count_ratio", moisture_count_ratio)?;
+
+    Ok(Self::new(density_count_ratio, moisture_count_ratio, calibration))
+  }
+
+  /// Wet density, `WD = ln(A / (DCR + C)) / B`, unrounded.
+  fn wet(&self) -> Result<T, CalcError> {
+    let GaugeCalibration { a, b, c, .. } = self.calibration;
+    let count = self.density_count_ratio + c;
+
+    checked_div(checked_div(a, count)?.ln(), b)
+  }
+
+  /// Water per volume, `M = (MCR - F) / E`, unrounded.
+  fn water(&self) -> Result<T, CalcError> {
+    let GaugeCalibration { e, f, .. } = self.calibration;
+
+    checked_div(self.moisture_count_ratio - f, e)
+  }
+
+  /// Calculate the wet density, in pcf, rounded to four decimal places.
+  ///
+  /// Returns `CalcError::DivisionByZero` when the absolute value of `DCR + C`, or of `B`, is less than `T::EPSILON`, and `CalcError::NonFinite` when `A / (DCR + C)` is not positive.
+  pub fn wet_density(&self) -> Result<T, CalcError> {
+    self.round_n(self.wet()?, 4)
+  }
+
+  /// Calculate the mass of water per volume of soil, in pcf, rounded to four decimal places.
+  ///
+  /// Returns `CalcError::DivisionByZero` when the absolute value of `E` is less than `T::EPSILON`.
+  pub fn moisture_density(&self) -> Result<T, CalcError> {
+    self.round_n(self.water()?, 4)
+  }
+
+  /// Calculate the dry density, `WD - M`, in pcf, rounded to four decimal places.
+  pub fn dry_density(&self) -> Result<T, CalcError> {
+    self.round_n(self.wet()? - self.water()?, 4)
+  }
+
+  /// Calculate the moisture content, `M / (WD - M)`, as a fraction (decimal) rounded to eight decimal places, the same as `MoistureContent`.
+  ///
+  /// Returns `CalcError::DivisionByZero` when the absolute value of the dry density is less than `T::EPSILON`.
+  pub fn moisture_content(&self) -> Result<T, CalcError> {
+    let water = self.water()?;
+    let dry_density = self.wet()? - water;
+
+    self.round_n(checked_div(water, dry_density)?, 8)
+  }
+
+  /// Getter for `density_count_ratio`
+  pub fn get_density_count_ratio(&self) -> T {
+    self.density_count_ratio
+  }
+
+  /// Getter for `moisture_count_ratio`
+  pub fn get_moisture_count_ratio(&self) -> T {
+    self.moisture_count_ratio
+  }
+
+  /// Getter for `calibration`
+  pub fn get_calibration(&self) -> GaugeCalibration<T> {
+    self.calibration
+  }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn calibration() -> GaugeCalibration {
+    GaugeCalibration::new(10., 0.015, 0.2, 0.02, 0.1)
+  }
+
+  #[test]
+  fn test_nuclear_gauge() {
+    let gauge = NuclearGauge::new(1.3335, 0.34, calibration());
+
+    assert_eq!(1.3335, gauge.get_density_count_ratio());
+    assert_eq!(0.34, gauge.get_moisture_count_ratio());
+    assert_eq!(0.015, gauge.get_calibration().get_b());
+    assert_eq!(Ok(125.0022), gauge.wet_density());
+    assert_eq!(Ok(12.), gauge.moisture_density());
+    assert_eq!(Ok(113.0022), gauge.dry_density());
+    assert_eq!(Ok(0.10619266), gauge.moisture_content());
+
+    // A higher count ratio means fewer photons were absorbed, so a lower density.
+    assert!(NuclearGauge::new(1.5, 0.34, calibration()).wet_density().unwrap() < 125.);
+  }
+
+  #[test]
+  fn test_from_counts() {
+    let gauge = NuclearGauge::from_counts(3733.8, 221., calibration().standard_counts(2800., 650.)).unwrap();
+
+    assert_eq!(Some(2800.), gauge.get_calibration().get_density_standard_count());
+    assert!((gauge.get_density_count_ratio() - 1.3335).abs() < 1e-12);
+    assert!((gauge.get_moisture_count_ratio() - 0.34).abs() < 1e-12);
+    assert_eq!(Ok(125.0022), gauge.wet_density());
+    assert_eq!(Ok(0.10619266), gauge.moisture_content());
+
+    // A lower standard count raises the ratio, so lowers the density.
+    let colder = NuclearGauge::from_counts(3733.8, 221., calibration().standard_counts(2750., 650.)).unwrap();
+    assert!(colder.wet_density().unwrap() < 125.);
+
+    assert_eq!(Err(CalcError::InsufficientData), NuclearGauge::from_counts(3733.8, 221., calibration()));
+    assert_eq!(Err(CalcError::DivisionByZero), NuclearGauge::from_counts(3733.8, 221., calibration().standard_counts(2800., 0.)));
+  }
+
+  #[test]
+  fn test_errors() {
+    assert_eq!(Err(CalcError::DivisionByZero), NuclearGauge::new(1.3335, 0.34, GaugeCalibration::new(10., 0., 0.2, 0.02, 0.1)).wet_density());
+    assert_eq!(Err(CalcError::DivisionByZero), NuclearGauge::new(1.3335, 0.34, GaugeCalibration::new(10., 0.015, 0.2, 0., 0.1)).moisture_content());
+    assert_eq!(Err(CalcError::NonFinite), NuclearGauge::new(1.3335, 0.34, GaugeCalibration::new(10., 0.015, -2., 0.02, 0.1)).wet_density());
+
+    assert_eq!("density_count_ratio", NuclearGauge::try_new(-1.3335, 0.34, calibration()).unwrap_err().field);
+    assert_eq!("moisture_count_ratio", NuclearGauge::try_new(1.3335, f64::NAN, calibration()).unwrap_err().field);
+  }
+}
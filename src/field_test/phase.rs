@@ -0,0 +1,346 @@
+//! Phase
+use crate::domain::errors::CalcError;
+use crate::math::float::{checked_div, Float};
+use super::constants::UNIT_WEIGHT_WATER;
+
+/// Phase diagram solver, relating the solids, water and air of a soil.
+///
+/// Set the quantities that are known, then call `solve()` to derive the rest from
+///
+/// - `n = e / (1 + e)`
+/// - `S * e = w * Gs`
+/// - `γd = Gs * γw / (1 + e)`
+/// - `γ = γd * (1 + w) = (Gs + S * e) * γw / (1 + e)`
+///
+/// where `γw` is the `UNIT_WEIGHT_WATER` constant. The moisture content, porosity and saturation are fractions (decimal), and the unit weights are in pcf.
+///
+/// # Sufficient inputs
+///
+/// Three independent quantities are needed. For example,
+///
+/// - `Gs`, `e` (or `n`), and one of `w`, `S` or `γ`.
+/// - `γd`, `Gs`, and one of `w`, `S` or `γ`.
+/// - `γ`, `Gs`, and one of `w`, `S` or `γd`.
+/// - `w`, `S` and `Gs`, as the saturation and moisture give the void ratio.
+/// - `e` (or `n`), `S` and `w`, as the saturation and moisture give the specific gravity.
+/// - `γd`, `e` (or `n`), and one of `w`, `S` or `γ`.
+/// - `γ`, `e` (or `n`) and `S`, for the specific gravity.
+/// - `γd`, `w` and `S`, or `γ`, `γd` and `S`, as the moisture and saturation give the void ratio in terms of `Gs`.
+///
+/// Two quantities related by a single formula, such as `e` and `n`, or `γ` and `γd` with `w`, only count once.
+///
+/// # Example
+///
+/// ```
+/// use billios::field_test::PhaseDiagram;
+///
+/// let solved: PhaseDiagram = PhaseDiagram::new().wet_density(125.).moisture_content(0.15).specific_gravity(2.7).solve().unwrap();
+///
+/// assert!((solved.get_dry_density().unwrap() - 108.6957).abs() < 1e-4);
+/// assert!((solved.get_void_ratio().unwrap() - 0.55).abs() < 1e-4);
+/// assert!((solved.get_saturation().unwrap() - 0.7364).abs() < 1e-4);
+/// ```
+///
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PhaseDiagram<T: Float = f64> {
+  moisture_content: Option<T>,
+  void_ratio: Option<T>,
+  porosity: Option<T>,
+  saturation: Option<T>,
+  wet_density: Option<T>,
+  dry_density: Option<T>,
+  specific_gravity: Option<T>,
+}
+
+impl<T: Float> PhaseDiagram<T> {
+  /// Create a new instance of `PhaseDiagram`, with every quantity unknown.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Setter for `moisture_content`, `w`, as a fraction (decimal).
+  pub fn moisture_content(mut self, moisture_content: T) -> Self {
+    self.moisture_content = Some(moisture_content);
+    self
+  }
+
+  /// Setter for `void_ratio`, `e`.
+  pub fn void_ratio(mut self, void_ratio: T) -> Self {
+    self.void_ratio = Some(void_ratio);
+    self
+  }
+
+  /// Setter for `porosity`, `n`, as a fraction (decimal).
+  pub fn porosity(mut self, porosity: T) -> Self {
+    self.porosity = Some(porosity);
+    self
+  }
+
+  /// Setter for `saturation`, `S`, as a fraction (decimal).
+  pub fn saturation(mut self, saturation: T) -> Self {
+    self.saturation = Some(saturation);
+    self
+  }
+
+  /// Setter for `wet_density`, `γ`, in pcf.
+  pub fn wet_density(mut self, wet_density: T) -> Self {
+    self.wet_density = Some(wet_density);
+    self
+  }
+
+  /// Setter for `dry_density`, `γd`, in pcf.
+  pub fn dry_density(mut self, dry_density: T) -> Self {
+    self.dry_density = Some(dry_density);
+    self
+  }
+
+  /// Setter for `specific_gravity`, `Gs`.
+  pub fn specific_gravity(mut self, specific_gravity: T) -> Self {
+    self.specific_gravity = Some(specific_gravity);
+    self
+  }
+
+  /// Solve for every quantity that is not known.
+  ///
+  /// The known quantities are kept as given, so they are not checked for consistency with each other. The results are unrounded.
+  ///
+  /// Returns `CalcError::InsufficientData` if any quantity cannot be derived from those known, including when a quantity would be divided by zero, or is not finite.
+  pub fn solve(&self) -> Result<Self, CalcError> {
+    let mut solved = *self;
+
+    while solved.step() {}
+
+    if solved.is_solved() {
+      Ok(solved)
+    } else {
+      Err(CalcError::InsufficientData)
+    }
+  }
+
+  /// Returns `true` when every quantity is known.
+  pub fn is_solved(&self) -> bool {
+    self.known() == 7
+  }
+
+  /// The number of quantities that are known.
+  fn known(&self) -> usize {
+    [self.moisture_content, self.void_ratio, self.porosity, self.saturation, self.wet_density, self.dry_density, self.specific_gravity]
+      .iter()
+      .filter(|v| v.is_some())
+      .count()
+  }
+
+  /// Derive each unknown quantity that can be found from those known, returning `true` if any were found.
+  fn step(&mut self) -> bool {
+    let before = *self;
+    let water = T::from_f64(UNIT_WEIGHT_WATER);
+    let PhaseDiagram { moisture_content: w, void_ratio: e, porosity: n, saturation: s, wet_density: wet, dry_density: dry, specific_gravity: gs } = before;
+
+    // n = e / (1 + e)
+    if e.is_none() {
+      self.void_ratio = n.and_then(|n| checked_div(n, T::ONE - n).ok());
+    }
+
+    if n.is_none() {
+      self.porosity = e.and_then(|e| checked_div(e, T::ONE + e).ok());
+    }
+
+    // S * e = w * Gs
+    if s.is_none() {
+      if let (Some(w), Some(gs), Some(e)) = (w, gs, e) {
+        self.saturation = checked_div(w * gs, e).ok();
+      }
+    }
+
+    if w.is_none() {
+      if let (Some(s), Some(e), Some(gs)) = (s, e, gs) {
+        self.moisture_content = checked_div(s * e, gs).ok();
+      }
+    }
+
+    if e.is_none() && self.void_ratio.is_none() {
+      if let (Some(w), Some(gs), Some(s)) = (w, gs, s) {
+        self.void_ratio = checked_div(w * gs, s).ok();
+      }
+    }
+
+    if gs.is_none() {
+      if let (Some(s), Some(e), Some(w)) = (s, e, w) {
+        self.specific_gravity = checked_div(s * e, w).ok();
+      }
+    }
+
+    // γd = Gs * γw / (1 + e)
+    if dry.is_none() {
+      if let (Some(gs), Some(e)) = (gs, e) {
+        self.dry_density = checked_div(gs * water, T::ONE + e).ok();
+      }
+    }
+
+    if e.is_none() && self.void_ratio.is_none() {
+      if let (Some(gs), Some(dry)) = (gs, dry) {
+        self.void_ratio = checked_div(gs * water, dry).ok().map(|v| v - T::ONE);
+      }
+    }
+
+    if gs.is_none() && self.specific_gravity.is_none() {
+      if let (Some(dry), Some(e)) = (dry, e) {
+        self.specific_gravity = Some(dry * (T::ONE + e) / water);
+      }
+    }
+
+    // γ = γd * (1 + w)
+    if wet.is_none() {
+      if let (Some(dry), Some(w)) = (dry, w) {
+        self.wet_density = Some(dry * (T::ONE + w));
+      }
+    }
+
+    if dry.is_none() && self.dry_density.is_none() {
+      if let (Some(wet), Some(w)) = (wet, w) {
+        self.dry_density = checked_div(wet, T::ONE + w).ok();
+      }
+    }
+
+    if w.is_none() && self.moisture_content.is_none() {
+      if let (Some(wet), Some(dry)) = (wet, dry) {
+        self.moisture_content = checked_div(wet, dry).ok().map(|v| v - T::ONE);
+      }
+    }
+
+    // γ = (Gs + S * e) * γw / (1 + e), for the void ratio from the wet density and saturation.
+    if e.is_none() && self.void_ratio.is_none() {
+      if let (Some(wet), Some(gs), Some(s)) = (wet, gs, s) {
+        self.void_ratio = checked_div(gs * water - wet, wet - s * water).ok();
+      }
+    }
+
+    // Gs = γ * (1 + e) / γw - S * e, from the same formula, for the specific gravity.
+    if gs.is_none() && self.specific_gravity.is_none() {
+      if let (Some(wet), Some(e), Some(s)) = (wet, e, s) {
+        self.specific_gravity = Some(wet * (T::ONE + e) / water - s * e);
+      }
+    }
+
+    // Gs = γd * S / (S * γw - γd * w), from e = w * Gs / S in γd = Gs * γw / (1 + e).
+    if gs.is_none() && self.specific_gravity.is_none() {
+      if let (Some(dry), Some(w), Some(s)) = (dry, w, s) {
+        self.specific_gravity = checked_div(dry * s, s * water - dry * w).ok();
+      }
+    }
+
+    self.known() > before.known()
+  }
+
+  /// Getter for `moisture_content`
+  pub fn get_moisture_content(&self) -> Option<T> {
+    self.moisture_content
+  }
+
+  /// Getter for `void_ratio`
+  pub fn get_void_ratio(&self) -> Option<T> {
+    self.void_ratio
+  }
+
+  /// Getter for `porosity`
+  pub fn get_porosity(&self) -> Option<T> {
+    self.porosity
+  }
+
+  /// Getter for `saturation`
+  pub fn get_saturation(&self) -> Option<T> {
+    self.saturation
+  }
+
+  /// Getter for `wet_density`
+  pub fn get_wet_density(&self) -> Option<T> {
+    self.wet_density
+  }
+
+  /// Getter for `dry_density`
+  pub fn get_dry_density(&self) -> Option<T> {
+    self.dry_density
+  }
+
+  /// Getter for `specific_gravity`
+  pub fn get_specific_gravity(&self) -> Option<T> {
+    self.specific_gravity
+  }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const EPSILON: f64 = 1e-9;
+
+  /// A soil with `Gs = 2.7`, `e = 0.5`, and `w = 0.1`.
+  fn expected() -> PhaseDiagram {
+    let dry_density = 2.7 * UNIT_WEIGHT_WATER / 1.5;
+
+    PhaseDiagram::new()
+      .moisture_content(0.1)
+      .void_ratio(0.5)
+      .porosity(0.5 / 1.5)
+      .saturation(0.54)
+      .wet_density(dry_density * 1.1)
+      .dry_density(dry_density)
+      .specific_gravity(2.7)
+  }
+
+  fn assert_solved(solved: PhaseDiagram) {
+    let expected = expected();
+    let pairs = [
+      (solved.get_moisture_content(), expected.get_moisture_content()),
+      (solved.get_void_ratio(), expected.get_void_ratio()),
+      (solved.get_porosity(), expected.get_porosity()),
+      (solved.get_saturation(), expected.get_saturation()),
+      (solved.get_wet_density(), expected.get_wet_density()),
+      (solved.get_dry_density(), expected.get_dry_density()),
+      (solved.get_specific_gravity(), expected.get_specific_gravity()),
+    ];
+
+    for (solved, expected) in pairs {
+      assert!((solved.unwrap() - expected.unwrap()).abs() < EPSILON, "{solved:?} != {expected:?}");
+    }
+  }
+
+  #[test]
+  fn test_solve() {
+    let expected = expected();
+    let (w, e, s, wet, dry, gs) = (0.1, 0.5, 0.54, expected.get_wet_density().unwrap(), expected.get_dry_density().unwrap(), 2.7);
+
+    assert_solved(PhaseDiagram::new().specific_gravity(gs).void_ratio(e).moisture_content(w).solve().unwrap());
+    assert_solved(PhaseDiagram::new().specific_gravity(gs).porosity(e / (1. + e)).saturation(s).solve().unwrap());
+    assert_solved(PhaseDiagram::new().dry_density(dry).specific_gravity(gs).wet_density(wet).solve().unwrap());
+    assert_solved(PhaseDiagram::new().wet_density(wet).moisture_content(w).specific_gravity(gs).solve().unwrap());
+    assert_solved(PhaseDiagram::new().wet_density(wet).saturation(s).specific_gravity(gs).solve().unwrap());
+    assert_solved(PhaseDiagram::new().moisture_content(w).saturation(s).specific_gravity(gs).solve().unwrap());
+    assert_solved(PhaseDiagram::new().dry_density(dry).void_ratio(e).saturation(s).solve().unwrap());
+    assert_solved(PhaseDiagram::new().wet_density(wet).void_ratio(e).saturation(s).solve().unwrap());
+    assert_solved(PhaseDiagram::new().wet_density(wet).porosity(e / (1. + e)).saturation(s).solve().unwrap());
+    assert_solved(PhaseDiagram::new().dry_density(dry).moisture_content(w).saturation(s).solve().unwrap());
+    assert_solved(PhaseDiagram::new().wet_density(wet).dry_density(dry).saturation(s).solve().unwrap());
+    assert_solved(PhaseDiagram::new().void_ratio(e).saturation(s).moisture_content(w).solve().unwrap());
+    assert_solved(expected.solve().unwrap());
+    assert!(PhaseDiagram::new().void_ratio(0.55).saturation(0.7364).moisture_content(0.15).solve().unwrap().is_solved());
+  }
+
+  #[test]
+  fn test_insufficient() {
+    assert_eq!(Err(CalcError::InsufficientData), PhaseDiagram::<f64>::new().solve());
+    assert_eq!(Err(CalcError::InsufficientData), PhaseDiagram::new().void_ratio(0.5).porosity(0.5 / 1.5).specific_gravity(2.7).solve());
+    assert_eq!(Err(CalcError::InsufficientData), PhaseDiagram::new().wet_density(125.).dry_density(110.).moisture_content(0.136).solve());
+
+    // A dry soil has no saturation to find the void ratio from.
+    assert_eq!(Err(CalcError::InsufficientData), PhaseDiagram::new().moisture_content(0.).saturation(0.).specific_gravity(2.7).solve());
+
+    assert!(!PhaseDiagram::new().void_ratio(0.5).is_solved());
+
+    // A NaN cannot be divided through, so nothing is derived from it.
+    assert_eq!(Err(CalcError::InsufficientData), PhaseDiagram::new().specific_gravity(f64::NAN).void_ratio(0.5).moisture_content(0.1).solve());
+  }
+}
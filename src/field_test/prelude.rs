@@ -0,0 +1,22 @@
+//! Prelude
+//!
+//! The calculation structs, the `*Choice` enums, and the traits needed to call them, for a single glob import.
+//!
+//! # Example
+//!
+//! ```
+//! use billios::field_test::prelude::*;
+//!
+//! let wet_density = WetDensity::new(4.65, SandUsedChoice::Constructor(SandUsed::new(14.65, 8.75, None)), None);
+//! let dry_density = DryDensity::new(WetDensityChoice::Constructor(wet_density), MoistureContentChoice::Value(0.1));
+//!
+//! assert_eq!(Ok(161.), dry_density.calculate());
+//! assert_eq!(Ok(0.2), Calculate::calculate(&RockCorrection::new(100., 500.)));
+//! ```
+pub use crate::domain::errors::{CalcError, ValidationError};
+pub use crate::domain::traits::{Calculate, Rounding};
+pub use super::calculations::*;
+pub use super::report::FieldTestReport;
+pub use super::types::{
+  BulkSpecificGravityChoice, DryDensityChoice, MoistureContentChoice, RockCorrectionChoice, SandUsedChoice, SoilType, SpecificGravityChoice, VoidRatioChoice, WetDensityChoice,
+};
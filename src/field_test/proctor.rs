@@ -0,0 +1,352 @@
+//! Proctor
+use alloc::vec;
+use alloc::vec::Vec;
+use crate::domain::errors::{CalcError, ValidationError};
+use crate::domain::traits::{Calculate, Rounding};
+use crate::field_test::types::MoistureContentChoice;
+use crate::math::float::Float;
+
+/// The coefficients `(a, b, c)` of a fitted parabola, with the `mean` and `scale` of the moisture content it is measured in.
+type Fit<T> = ((T, T, T), T, T);
+
+/// Proctor compaction curve
+///
+/// Fits a parabola, `γd = a * w² + b * w + c`, to a series of `(moisture content, dry density)` points by least squares, and finds its peak.
+///
+/// The moisture contents must be percents, e.g. `12.` for 12%, not the fraction (decimal) `MoistureContent` returns, as the optimum moisture is rounded to one decimal place. Use `MoistureContent::calculate_percent()` for the percent.
+///
+/// # Example
+///
+/// ```
+/// use billios::field_test::{Compaction, ProctorCurve};
+/// use billios::field_test::types::DryDensityChoice;
+///
+/// let proctor = ProctorCurve::new(vec![(6., 112.), (8., 118.), (10., 120.), (12., 118.), (14., 112.)]);
+/// assert_eq!(Ok((10., 120.)), proctor.peak());
+///
+/// let compaction = Compaction::new(DryDensityChoice::Value(114.), proctor.max_dry_density().unwrap());
+/// assert_eq!(Ok(95.), compaction.calculate());
+/// ```
+///
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProctorCurve<T: Float = f64> {
+  points: Vec<(T, T)>,
+}
+
+impl<T: Float> Rounding<T> for ProctorCurve<T> {}
+
+impl<T: Float> ProctorCurve<T> {
+  /// Create a new instance of `ProctorCurve`.
+  ///
+  /// # Arguments
+  ///
+  /// - `points` - A list of `(moisture content, dry density)` pairs from the Proctor test, with the moisture content as a percent.
+  ///
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn new(points: Vec<(T, T)>) -> Self {
+    Self { points }
+  }
+
+  /// Create a new, validated, instance of `ProctorCurve`.
+  ///
+  /// Returns a `ValidationError` if any value is `NaN`, infinite, or negative.
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn try_new(points: Vec<(T, T)>) -> Result<Self, ValidationError> {
+    for &(moisture_content, dry_density) in &points {
+      ValidationError::check_non_negative("moisture_content", moisture_content)?;
+      ValidationError::check_non_negative("dry_density", dry_density)?;
+    }
+
+    Ok(Self::new(points))
+  }
+
+  /// Getter for `points`
+  pub fn get_points(&self) -> &[(T, T)] {
+    &self.points
+  }
+
+  /// Fit the parabola, returning `(a, b, c)` with moisture content measured from `mean` in units of `scale`, its standard deviation, along with `mean` and `scale`.
+  ///
+  /// Centring and scaling the moisture content keeps the fit the same whatever its units, e.g. a percent or a fraction.
+  fn fit(&self) -> Result<Fit<T>, CalcError> {
+    if self.points.len() < 3 {
+      return Err(CalcError::InsufficientData);
+    }
+
+    let n = T::from_f64(self.points.len() as f64);
+    let mean = self.points.iter().map(|&(w, _)| w).sum::<T>() / n;
+    let scale = (self.points.iter().map(|&(w, _)| (w - mean) * (w - mean)).sum::<T>() / n).sqrt();
+
+    // Every point has the same moisture content.
+    if scale <= T::EPSILON * mean.abs() {
+      return Err(CalcError::DivisionByZero);
+    }
+
+    let (mut s1, mut s2, mut s3, mut s4) = (T::ZERO, T::ZERO, T::ZERO, T::ZERO);
+    let (mut sy, mut s1y, mut s2y) = (T::ZERO, T::ZERO, T::ZERO);
+
+    for &(w, y) in &self.points {
+      let x = (w - mean) / scale;
+      let x2 = x * x;
+
+      s1 = s1 + x;
+      s2 = s2 + x2;
+      s3 = s3 + x2 * x;
+      s4 = s4 + x2 * x2;
+      sy = sy + y;
+      s1y = s1y + x * y;
+      s2y = s2y + x2 * y;
+    }
+
+    // Solve the normal equations using Cramer's rule.
+    let det = |m: [[T; 3]; 3]| {
+      m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1]) - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+        + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    };
+
+    let matrix = [[s4, s3, s2], [s3, s2, s1], [s2, s1, n]];
+    let determinant = det(matrix);
+
+    // The scaled moisture contents have `s2 = n` and `s4 >= n`, so the determinant is relative to `n³`.
+    if determinant.abs() <= T::EPSILON * n * n * n {
+      return Err(CalcError::DivisionByZero);
+    }
+
+    let a = det([[s2y, s3, s2], [s1y, s2, s1], [sy, s1, n]]) / determinant;
+    let b = det([[s4, s2y, s2], [s3, s1y, s1], [s2, sy, n]]) / determinant;
+    let c = det([[s4, s3, s2y], [s3, s2, s1y], [s2, s1, sy]]) / determinant;
+
+    Ok(((a, b, c), mean, scale))
+  }
+
+  /// Calculate the peak of the fitted curve as `(optimum_moisture, max_dry_density)`.
+  ///
+  /// Both values are rounded to one decimal place.
+  ///
+  /// Returns `CalcError::InsufficientData` if fewer than three points were given, `CalcError::DivisionByZero` if the points do not determine a parabola (e.g. all have the same moisture content), and `CalcError::NotConcave` if the fitted curve has no peak.
+  pub fn peak(&self) -> Result<(T, T), CalcError> {
+    let ((a, b, c), mean, scale) = self.fit()?;
+
+    if a >= T::ZERO || a.abs() < T::EPSILON {
+      return Err(CalcError::NotConcave);
+    }
+
+    let two = T::from_f64(2.);
+    let x = -b / (two * a);
+
+    let optimum_moisture = x * scale + mean;
+    let max_dry_density = a * x * x + b * x + c;
+
+    Ok((self.round_n(optimum_moisture, 1)?, self.round_n(max_dry_density, 1)?))
+  }
+
+  /// Calculate the optimum moisture content, the moisture content at the peak of the fitted curve.
+  pub fn optimum_moisture(&self) -> Result<T, CalcError> {
+    Ok(self.peak()?.0)
+  }
+
+  /// Calculate the maximum dry density, the dry density at the peak of the fitted curve.
+  ///
+  /// This can be used as the `lab_max` of a `Compaction`.
+  pub fn max_dry_density(&self) -> Result<T, CalcError> {
+    Ok(self.peak()?.1)
+  }
+
+  /// Create a `MoistureDeviation` of `field_moisture` from the `optimum_moisture()` of the curve.
+  ///
+  /// Returns any error from finding the peak of the curve.
+  pub fn moisture_deviation(&self, field_moisture: MoistureContentChoice<T>, tolerance: T) -> Result<MoistureDeviation<T>, CalcError> {
+    Ok(MoistureDeviation::new(field_moisture, self.optimum_moisture()?, tolerance))
+  }
+}
+
+/// Whether a field moisture content is wet or dry of optimum, from a `MoistureDeviation`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MoistureCondition {
+  /// More than the tolerance below optimum, so the fill needs wetting.
+  TooDry,
+  /// Within the tolerance of optimum, inclusive.
+  AtOptimum,
+  /// More than the tolerance above optimum, so the fill needs drying.
+  TooWet,
+}
+
+/// Deviation of the field moisture content from optimum, `w_field - w_opt`, in percentage points.
+///
+/// The field moisture content is a fraction, the same as the result of `MoistureContent`, and the optimum is a percent, the same as from `ProctorCurve`. A positive deviation is wet of optimum, and a negative one dry of optimum.
+///
+/// # Example
+///
+/// ```
+/// use billios::field_test::{MoistureCondition, MoistureDeviation};
+/// use billios::field_test::types::MoistureContentChoice;
+///
+/// let deviation = MoistureDeviation::new(MoistureContentChoice::Value(0.125), 10., 2.);
+///
+/// assert_eq!(Ok(2.5), deviation.calculate());
+/// assert_eq!(Ok(MoistureCondition::TooWet), deviation.condition());
+/// ```
+///
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MoistureDeviation<T: Float = f64> {
+  field_moisture: MoistureContentChoice<T>,
+  optimum_moisture: T,
+  tolerance: T,
+}
+
+impl<T: Float> Rounding<T> for MoistureDeviation<T> {}
+
+impl<T: Float> Calculate<T> for MoistureDeviation<T> {
+  fn calculate_raw(&self) -> Result<T, CalcError> {
+    let result = self.get_field_moisture()? * T::from_f64(100.) - self.optimum_moisture;
+
+    Ok(result)
+  }
+
+  fn precision(&self) -> i32 {
+    1
+  }
+
+  fn unit_label(&self) -> &'static str {
+    "%"
+  }
+
+  fn terms(&self) -> Result<Vec<(&'static str, T)>, CalcError> {
+    Ok(vec![
+      ("field_moisture", self.get_field_moisture()?),
+      ("optimum_moisture", self.optimum_moisture),
+      ("tolerance", self.tolerance),
+    ])
+  }
+}
+
+impl<T: Float> MoistureDeviation<T> {
+  /// Create a new instance of `MoistureDeviation`.
+  ///
+  /// # Arguments
+  ///
+  /// - `field_moisture` - A `MoistureContentChoice` for the measurement: **Field Moisture Content**, as a fraction (decimal).
+  ///
+  /// - `optimum_moisture` - A float that corresponds to the measurement: **Optimum Moisture Content**, as a percent.
+  ///
+  /// - `tolerance` - A float for the band either side of optimum, in percentage points, e.g. `2.` for ±2%.
+  ///
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn new(field_moisture: MoistureContentChoice<T>, optimum_moisture: T, tolerance: T) -> Self {
+    Self { field_moisture, optimum_moisture, tolerance }
+  }
+
+  /// Calculate the Moisture Deviation value, in percentage points rounded to one decimal place.
+  ///
+  /// Delegates to the `Calculate` implementation.
+  #[must_use = "the result of a calculation should be used, or its error handled"]
+  pub fn calculate(&self) -> Result<T, CalcError> {
+    Calculate::calculate(self)
+  }
+
+  /// Whether the field moisture is too dry, at optimum, or too wet.
+  ///
+  /// Compares the rounded deviation from `calculate()`, the same value that is reported, so a deviation equal to the `tolerance` is `AtOptimum`.
+  pub fn condition(&self) -> Result<MoistureCondition, CalcError> {
+    let deviation = self.calculate()?;
+    let tolerance = self.tolerance.abs();
+
+    let condition = if deviation > tolerance {
+      MoistureCondition::TooWet
+    } else if deviation < -tolerance {
+      MoistureCondition::TooDry
+    } else {
+      MoistureCondition::AtOptimum
+    };
+
+    Ok(condition)
+  }
+
+  /// Getter for `field_moisture`.
+  ///
+  /// A `Constructor` value is calculated without rounding, so any error from its calculation is returned.
+  pub fn get_field_moisture(&self) -> Result<T, CalcError> {
+    self.field_moisture.resolve()
+  }
+
+  /// Getter for `optimum_moisture`
+  pub fn get_optimum_moisture(&self) -> T {
+    self.optimum_moisture
+  }
+
+  /// Getter for `tolerance`
+  pub fn get_tolerance(&self) -> T {
+    self.tolerance
+  }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_peak() {
+    let exact = ProctorCurve::new(vec![(6., 112.), (8., 118.), (10., 120.), (12., 118.), (14., 112.)]);
+    assert_eq!(Ok((10., 120.)), exact.peak());
+    assert_eq!(Ok(10.), exact.optimum_moisture());
+    assert_eq!(Ok(120.), exact.max_dry_density());
+
+    let measured = ProctorCurve::new(vec![(5.2, 114.1), (7.1, 118.9), (9.3, 121.2), (11.0, 119.8), (13.4, 115.0)]);
+    assert_eq!(Ok((9.4, 121.)), measured.peak());
+  }
+
+  #[test]
+  fn test_f32() {
+    let proctor: ProctorCurve<f32> = ProctorCurve::new(vec![(6., 112.), (8., 118.), (10., 120.), (12., 118.), (14., 112.)]);
+    assert_eq!(Ok((10., 120.)), proctor.peak());
+
+    // Fractional moisture contents fit the same curve, but the optimum is rounded to one decimal place, so use percents.
+    let fractions: ProctorCurve<f32> = ProctorCurve::new(vec![(0.06, 112.), (0.08, 118.), (0.10, 120.), (0.12, 118.), (0.14, 112.)]);
+    assert_eq!(Ok((0.1, 120.)), fractions.peak());
+
+    let measured: ProctorCurve<f32> = ProctorCurve::new(vec![(0.052, 114.1), (0.071, 118.9), (0.093, 121.2), (0.110, 119.8), (0.134, 115.0)]);
+    assert_eq!(Ok(121.), measured.max_dry_density());
+  }
+
+  #[test]
+  fn test_errors() {
+    assert_eq!(Err(CalcError::InsufficientData), ProctorCurve::new(vec![(8., 118.), (10., 120.)]).peak());
+    assert_eq!(Err(CalcError::DivisionByZero), ProctorCurve::new(vec![(10., 118.), (10., 120.), (10., 119.)]).peak());
+    assert_eq!(Err(CalcError::NotConcave), ProctorCurve::new(vec![(6., 120.), (10., 112.), (14., 120.)]).peak());
+    assert_eq!(Err(CalcError::NotConcave), ProctorCurve::new(vec![(6., 112.), (10., 116.), (14., 120.)]).peak());
+  }
+
+  #[test]
+  fn test_moisture_deviation() {
+    let dry = MoistureDeviation::new(MoistureContentChoice::Value(0.07), 10., 2.);
+    assert_eq!(Ok(-3.), dry.calculate());
+    assert_eq!(Ok(MoistureCondition::TooDry), dry.condition());
+
+    let at_optimum = MoistureDeviation::new(MoistureContentChoice::Percent(11.5), 10., 2.);
+    assert_eq!(Ok(1.5), at_optimum.calculate());
+    assert_eq!(Ok(MoistureCondition::AtOptimum), at_optimum.condition());
+
+    // On the edge of the band is at optimum.
+    let edge = MoistureDeviation::new(MoistureContentChoice::Value(0.08), 10., 2.);
+    assert_eq!(Ok(MoistureCondition::AtOptimum), edge.condition());
+    assert_eq!(Ok(MoistureCondition::TooDry), MoistureDeviation::new(MoistureContentChoice::Value(0.08), 10., 1.9).condition());
+
+    let proctor = ProctorCurve::new(vec![(6., 112.), (8., 118.), (10., 120.), (12., 118.), (14., 112.)]);
+    let wet = proctor.moisture_deviation(MoistureContentChoice::Value(0.125), 2.).unwrap();
+    assert_eq!(10., wet.get_optimum_moisture());
+    assert_eq!(Ok(MoistureCondition::TooWet), wet.condition());
+
+    assert_eq!(Err(CalcError::InsufficientData), ProctorCurve::new(vec![(8., 118.)]).moisture_deviation(MoistureContentChoice::Value(0.1), 2.));
+  }
+
+  #[test]
+  fn test_try_new() {
+    assert!(ProctorCurve::try_new(vec![(6., 112.), (10., 120.), (14., 112.)]).is_ok());
+    assert_eq!("dry_density", ProctorCurve::try_new(vec![(6., -112.)]).unwrap_err().field);
+  }
+}
@@ -0,0 +1,103 @@
+//! Profile
+use crate::math::float::Float;
+use super::constants::{SAND_DENSITY, SAND_IN_CONE, SPECIFIC_GRAVITY};
+
+/// A set of calibration values to use in place of the default constants.
+///
+/// Construct one per crew, or per sand cone, and pass it to the `with_profile()` constructors instead of repeating `Some(...)` values.
+///
+/// # Example
+///
+/// ```
+/// use billios::field_test::{CalibrationProfile, SandUsed, WetDensity};
+/// use billios::field_test::types::SandUsedChoice;
+///
+/// let profile = CalibrationProfile::default().sand_in_cone(3.62).sand_density(89.5);
+///
+/// let sand_used = SandUsed::with_profile(14.65, 8.75, &profile);
+/// assert_eq!(Ok(2.28), sand_used.calculate());
+///
+/// let wet_density = WetDensity::with_profile(4.65, SandUsedChoice::Constructor(sand_used), &profile);
+/// assert_eq!(Ok(182.5329), wet_density.calculate());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CalibrationProfile<T: Float = f64> {
+  sand_in_cone: T,
+  sand_density: T,
+  specific_gravity: T,
+}
+
+impl<T: Float> Default for CalibrationProfile<T> {
+  /// A profile using the `SAND_IN_CONE`, `SAND_DENSITY` and `SPECIFIC_GRAVITY` constants.
+  fn default() -> Self {
+    Self::new(T::from_f64(SAND_IN_CONE), T::from_f64(SAND_DENSITY), T::from_f64(SPECIFIC_GRAVITY))
+  }
+}
+
+impl<T: Float> CalibrationProfile<T> {
+  /// Create a new instance of `CalibrationProfile`.
+  ///
+  /// # Arguments
+  ///
+  /// - `sand_in_cone` - A float that corresponds to the measurement: **Sand In Cone**, in pounds (lb).
+  ///
+  /// - `sand_density` - A float that corresponds to the measurement: **Sand Density**, in pounds per cubic foot (pcf).
+  ///
+  /// - `specific_gravity` - A float that corresponds to the measurement: **Specific Gravity** of the soil solids.
+  ///
+  pub fn new(sand_in_cone: T, sand_density: T, specific_gravity: T) -> Self {
+    Self { sand_in_cone, sand_density, specific_gravity }
+  }
+
+  /// Setter for `sand_in_cone`.
+  pub fn sand_in_cone(mut self, sand_in_cone: T) -> Self {
+    self.sand_in_cone = sand_in_cone;
+    self
+  }
+
+  /// Setter for `sand_density`.
+  pub fn sand_density(mut self, sand_density: T) -> Self {
+    self.sand_density = sand_density;
+    self
+  }
+
+  /// Setter for `specific_gravity`.
+  pub fn specific_gravity(mut self, specific_gravity: T) -> Self {
+    self.specific_gravity = specific_gravity;
+    self
+  }
+
+  /// Getter for `sand_in_cone`
+  pub fn get_sand_in_cone(&self) -> T {
+    self.sand_in_cone
+  }
+
+  /// Getter for `sand_density`
+  pub fn get_sand_density(&self) -> T {
+    self.sand_density
+  }
+
+  /// Getter for `specific_gravity`
+  pub fn get_specific_gravity(&self) -> T {
+    self.specific_gravity
+  }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_calibration_profile() {
+    let default: CalibrationProfile = CalibrationProfile::default();
+    assert_eq!(CalibrationProfile::new(SAND_IN_CONE, SAND_DENSITY, SPECIFIC_GRAVITY), default);
+
+    let profile = default.specific_gravity(2.65);
+    assert_eq!(SAND_IN_CONE, profile.get_sand_in_cone());
+    assert_eq!(SAND_DENSITY, profile.get_sand_density());
+    assert_eq!(2.65, profile.get_specific_gravity());
+  }
+}
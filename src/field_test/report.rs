@@ -0,0 +1,545 @@
+//! Report
+use core::fmt;
+
+#[cfg(feature = "json")]
+use alloc::string::String;
+use crate::domain::errors::CalcError;
+use crate::domain::traits::Calculate;
+use crate::field_test::types::{DryDensityChoice, MoistureContentChoice, RockCorrectionChoice, SandUsedChoice, WetDensityChoice};
+use crate::math::float::Float;
+use super::calculations::{Compaction, DryDensity, LabMaxCorrection, MoistureContent, SandUsed, WetDensity};
+use super::proctor::MoistureDeviation;
+
+/// The full sand cone field test pipeline, from the raw measurements to the compaction.
+///
+/// Each step is chained into the next, sand used → wet density → dry density → compaction, and only calculated when requested.
+///
+/// # Example
+///
+/// ```
+/// use billios::field_test::FieldTestReport;
+///
+/// let report = FieldTestReport::new(14.65, 8.75, 4.65, 1600., 1575., 1400., 135.6);
+///
+/// assert_eq!(Ok(2.31), report.sand_used());
+/// assert_eq!(Ok(177.1429), report.wet_density());
+/// assert_eq!(Ok(155.), report.dry_density());
+/// assert_eq!(Ok(114.3), report.compaction());
+/// ```
+///
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FieldTestReport<T: Float = f64> {
+  sand_used: SandUsed<T>,
+  wet_density: WetDensity<T>,
+  moisture_content: MoistureContent<T>,
+  dry_density: DryDensity<T>,
+  compaction: Compaction<T>,
+}
+
+impl<T: Float> FieldTestReport<T> {
+  /// Create a new instance of `FieldTestReport` from the raw measurements.
+  ///
+  /// The default constants are used for the sand in the cone and the sand density, use `from_measurements()` to override them.
+  ///
+  /// # Arguments
+  ///
+  /// - `cone_pre_test` - A float that corresponds to the measurement: **Cone Pre Test**.
+  ///
+  /// - `cone_post_test` - A float that corresponds to the measurement: **Cone Post Test**.
+  ///
+  /// - `soil` - A float that corresponds to the measurement: **Soil**.
+  ///
+  /// - `wet_weight` - A float that corresponds to the measurement: **Wet Weight**.
+  ///
+  /// - `dry_weight` - A float that corresponds to the measurement: **Dry Weight**.
+  ///
+  /// - `tare_pan` - A float that corresponds to the measurement: **Tare Pan**.
+  ///
+  /// - `lab_max` - A float that corresponds to the measurement: **Lab Max**.
+  ///
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn new(cone_pre_test: T, cone_post_test: T, soil: T, wet_weight: T, dry_weight: T, tare_pan: T, lab_max: T) -> Self {
+    let sand_used = SandUsed::new(cone_pre_test, cone_post_test, None);
+    let wet_density = WetDensity::new(soil, SandUsedChoice::Constructor(sand_used), None);
+
+    Self::from_parts(sand_used, wet_density, MoistureContent::new(wet_weight, dry_weight, tare_pan), lab_max)
+  }
+
+  /// Create a new instance of `FieldTestReport` from every raw measurement of the sand cone test, including the cone calibration.
+  ///
+  /// This is the single entry point for the standard test, building the `SandUsed`, `WetDensity`, `MoistureContent`, `DryDensity`, and `Compaction` chain internally.
+  ///
+  /// # Arguments
+  ///
+  /// - `cone_pre_test` - A float that corresponds to the measurement: **Cone Pre Test**.
+  ///
+  /// - `cone_post_test` - A float that corresponds to the measurement: **Cone Post Test**.
+  ///
+  /// - `sand_in_cone` - An `Option<float>` that when `None` defaults to the constant value `SAND_IN_CONE`.
+  ///
+  /// - `soil` - A float that corresponds to the measurement: **Soil**.
+  ///
+  /// - `sand_density` - An `Option<float>` that when `None` defaults to the constant value `SAND_DENSITY`.
+  ///
+  /// - `wet_weight` - A float that corresponds to the measurement: **Wet Weight**.
+  ///
+  /// - `dry_weight` - A float that corresponds to the measurement: **Dry Weight**.
+  ///
+  /// - `tare_pan` - A float that corresponds to the measurement: **Tare Pan**.
+  ///
+  /// - `lab_max` - A float that corresponds to the measurement: **Lab Max**.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use billios::field_test::FieldTestReport;
+  ///
+  /// let report = FieldTestReport::from_measurements(14.65, 8.75, Some(3.6), 4.65, Some(89.5), 1600., 1575., 1400., 135.6);
+  ///
+  /// assert_eq!(Ok(2.3), report.sand_used());
+  /// assert_eq!(Ok(180.9457), report.wet_density());
+  /// assert_eq!(Ok(0.14285714), report.moisture_content());
+  /// assert_eq!(Ok(158.), report.dry_density());
+  /// assert_eq!(Ok(116.8), report.compaction());
+  /// ```
+  #[allow(clippy::too_many_arguments)]
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn from_measurements(
+    cone_pre_test: T,
+    cone_post_test: T,
+    sand_in_cone: Option<T>,
+    soil: T,
+    sand_density: Option<T>,
+    wet_weight: T,
+    dry_weight: T,
+    tare_pan: T,
+    lab_max: T,
+  ) -> Self {
+    let sand_used = SandUsed::new(cone_pre_test, cone_post_test, sand_in_cone);
+    let wet_density = WetDensity::new(soil, SandUsedChoice::Constructor(sand_used), sand_density);
+
+    Self::from_parts(sand_used, wet_density, MoistureContent::new(wet_weight, dry_weight, tare_pan), lab_max)
+  }
+
+  /// Create a new instance of `FieldTestReport` from existing sand used, wet density, and moisture content calculations.
+  ///
+  /// The `wet_density` is used as given, so its `sand_used` and `sand_density` may differ from the defaults.
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn from_parts(sand_used: SandUsed<T>, wet_density: WetDensity<T>, moisture_content: MoistureContent<T>, lab_max: T) -> Self {
+    let dry_density = DryDensity::new(WetDensityChoice::Constructor(wet_density), MoistureContentChoice::Constructor(moisture_content));
+    let compaction = Compaction::new(DryDensityChoice::Constructor(dry_density), lab_max);
+
+    Self { sand_used, wet_density, moisture_content, dry_density, compaction }
+  }
+
+  /// Calculate the Sand Used value.
+  pub fn sand_used(&self) -> Result<T, CalcError> {
+    self.sand_used.calculate()
+  }
+
+  /// Calculate the Wet Density value.
+  pub fn wet_density(&self) -> Result<T, CalcError> {
+    self.wet_density.calculate()
+  }
+
+  /// Calculate the Moisture Content value.
+  pub fn moisture_content(&self) -> Result<T, CalcError> {
+    self.moisture_content.calculate()
+  }
+
+  /// Calculate the Dry Density value.
+  pub fn dry_density(&self) -> Result<T, CalcError> {
+    self.dry_density.calculate()
+  }
+
+  /// Calculate the Compaction value.
+  pub fn compaction(&self) -> Result<T, CalcError> {
+    self.compaction.calculate()
+  }
+
+  /// Create a `MoistureDeviation` of the measured moisture content from `optimum_moisture`, a percent, within `tolerance` percentage points.
+  pub fn moisture_deviation(&self, optimum_moisture: T, tolerance: T) -> MoistureDeviation<T> {
+    MoistureDeviation::new(MoistureContentChoice::Constructor(self.moisture_content), optimum_moisture, tolerance)
+  }
+
+  /// Getter for the `Compaction` calculation, e.g. to use with `field_test::batch`.
+  pub fn get_compaction(&self) -> Compaction<T> {
+    self.compaction
+  }
+
+  /// Calculate every result of the report, along with the measurements and default constants used, as a `ReportSummary`.
+  ///
+  /// Returns the first error from any of the calculations.
+  pub fn summary(&self) -> Result<ReportSummary<T>, CalcError> {
+    Ok(ReportSummary {
+      cone_pre_test_lb: self.sand_used.get_cone_pre_test(),
+      cone_post_test_lb: self.sand_used.get_cone_post_test(),
+      sand_in_cone_lb: self.sand_used.get_sand_in_cone(),
+      sand_used_lb: self.sand_used()?,
+      soil_lb: self.wet_density.get_soil(),
+      sand_density_pcf: self.wet_density.get_sand_density(),
+      wet_density_pcf: self.wet_density()?,
+      moisture_content_percent: self.moisture_content.calculate_percent()?,
+      dry_density_pcf: self.dry_density()?,
+      lab_max_pcf: self.compaction.get_lab_max(),
+      compaction_percent: self.compaction()?,
+    })
+  }
+}
+
+#[cfg(feature = "json")]
+impl<T: Float + serde::Serialize> FieldTestReport<T> {
+  /// Write the `summary()` of the report as pretty printed JSON.
+  ///
+  /// The keys are the fields of `ReportSummary`, in order, each named with its unit. Unlike the derived `Serialize` of the report itself, no `Option` or `*Choice` internals are exposed, so the schema is stable for downstream systems.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use billios::field_test::FieldTestReport;
+  ///
+  /// let report = FieldTestReport::new(14.65, 8.75, 4.65, 1600., 1575., 1400., 135.6);
+  /// let json = report.to_report_json().unwrap();
+  ///
+  /// assert!(json.contains("\"sand_in_cone_lb\": 3.59"));
+  /// assert!(json.contains("\"dry_density_pcf\": 155.0"));
+  /// assert!(json.contains("\"compaction_percent\": 114.3"));
+  /// ```
+  ///
+  /// Returns any error from `summary()`, and `CalcError::NonFinite` if a value of the summary is `NaN` or infinite, rather than writing it as `null`, or if it cannot otherwise be serialized.
+  pub fn to_report_json(&self) -> Result<String, CalcError> {
+    let summary = self.summary()?;
+
+    if !summary.is_finite() {
+      return Err(CalcError::NonFinite);
+    }
+
+    serde_json::to_string_pretty(&summary).map_err(|_| CalcError::NonFinite)
+  }
+}
+
+/// The results of a `FieldTestReport`, with the measurements and default constants used.
+///
+/// Each field is named with its unit, weights in lb, densities in pcf, and percents. Built by `FieldTestReport::summary()`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReportSummary<T: Float = f64> {
+  cone_pre_test_lb: T,
+  cone_post_test_lb: T,
+  sand_in_cone_lb: T,
+  sand_used_lb: T,
+  soil_lb: T,
+  sand_density_pcf: T,
+  wet_density_pcf: T,
+  moisture_content_percent: T,
+  dry_density_pcf: T,
+  lab_max_pcf: T,
+  compaction_percent: T,
+}
+
+impl<T: Float> ReportSummary<T> {
+  /// Whether every value is finite, neither `NaN` nor infinite.
+  #[cfg(feature = "json")]
+  fn is_finite(&self) -> bool {
+    [
+      self.cone_pre_test_lb, self.cone_post_test_lb, self.sand_in_cone_lb, self.sand_used_lb, self.soil_lb, self.sand_density_pcf,
+      self.wet_density_pcf, self.moisture_content_percent, self.dry_density_pcf, self.lab_max_pcf, self.compaction_percent,
+    ]
+    .iter()
+    .all(|v| v.is_finite())
+  }
+
+  /// Getter for `cone_pre_test_lb`
+  pub fn get_cone_pre_test_lb(&self) -> T {
+    self.cone_pre_test_lb
+  }
+
+  /// Getter for `cone_post_test_lb`
+  pub fn get_cone_post_test_lb(&self) -> T {
+    self.cone_post_test_lb
+  }
+
+  /// Getter for `sand_in_cone_lb`
+  pub fn get_sand_in_cone_lb(&self) -> T {
+    self.sand_in_cone_lb
+  }
+
+  /// Getter for `sand_used_lb`
+  pub fn get_sand_used_lb(&self) -> T {
+    self.sand_used_lb
+  }
+
+  /// Getter for `soil_lb`
+  pub fn get_soil_lb(&self) -> T {
+    self.soil_lb
+  }
+
+  /// Getter for `sand_density_pcf`
+  pub fn get_sand_density_pcf(&self) -> T {
+    self.sand_density_pcf
+  }
+
+  /// Getter for `wet_density_pcf`
+  pub fn get_wet_density_pcf(&self) -> T {
+    self.wet_density_pcf
+  }
+
+  /// Getter for `moisture_content_percent`
+  pub fn get_moisture_content_percent(&self) -> T {
+    self.moisture_content_percent
+  }
+
+  /// Getter for `dry_density_pcf`
+  pub fn get_dry_density_pcf(&self) -> T {
+    self.dry_density_pcf
+  }
+
+  /// Getter for `lab_max_pcf`
+  pub fn get_lab_max_pcf(&self) -> T {
+    self.lab_max_pcf
+  }
+
+  /// Getter for `compaction_percent`
+  pub fn get_compaction_percent(&self) -> T {
+    self.compaction_percent
+  }
+}
+
+/// Every intermediate result of the sand cone test, built by `calculate_all_intermediate()`.
+///
+/// Each value is rounded to the precision of its own calculation, but was calculated from the unrounded results before it, the same as a chain of `Constructor` choices.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CalculationTrace<T: Float = f64> {
+  sand_used: T,
+  wet_density: T,
+  moisture_content: T,
+  dry_density: T,
+  lab_max_correction: T,
+  compaction: T,
+}
+
+impl<T: Float> CalculationTrace<T> {
+  /// Getter for `sand_used`
+  pub fn get_sand_used(&self) -> T {
+    self.sand_used
+  }
+
+  /// Getter for `wet_density`
+  pub fn get_wet_density(&self) -> T {
+    self.wet_density
+  }
+
+  /// Getter for `moisture_content`
+  pub fn get_moisture_content(&self) -> T {
+    self.moisture_content
+  }
+
+  /// Getter for `dry_density`
+  pub fn get_dry_density(&self) -> T {
+    self.dry_density
+  }
+
+  /// Getter for `lab_max_correction`
+  pub fn get_lab_max_correction(&self) -> T {
+    self.lab_max_correction
+  }
+
+  /// Getter for `compaction`
+  pub fn get_compaction(&self) -> T {
+    self.compaction
+  }
+}
+
+/// The unrounded and rounded results of a single step.
+fn trace_step<T: Float, C: Calculate<T>>(step: C) -> Result<(T, T), CalcError> {
+  Ok((step.calculate_raw()?, step.calculate()?))
+}
+
+/// Calculate every step of the sand cone test at once, from sand used to the compaction against the rock corrected lab max, to find which input caused an unexpected result.
+///
+/// Each step is calculated once, and its unrounded result passed on to the next. The default constants are used for the sand in the cone, the sand density, and the specific gravity.
+///
+/// Returns the first error from any of the steps.
+///
+/// # Arguments
+///
+/// - `cone_pre_test`, `cone_post_test`, `soil` - The measurements of `SandUsed` and `WetDensity`.
+///
+/// - `wet_weight`, `dry_weight`, `tare_pan` - The measurements of `MoistureContent`.
+///
+/// - `lab_max` - A float that corresponds to the measurement: **Lab Max**, before the rock correction.
+///
+/// - `rock_correction` - A `RockCorrectionChoice` for the measurement: **Rock Correction**, as a fraction (decimal).
+///
+/// # Example
+///
+/// ```
+/// use billios::field_test::calculate_all_intermediate;
+/// use billios::field_test::types::RockCorrectionChoice;
+///
+/// let trace = calculate_all_intermediate(14.65, 8.75, 4.65, 1600., 1575., 1400., 135.6, RockCorrectionChoice::Value(0.2)).unwrap();
+///
+/// assert_eq!(2.31, trace.get_sand_used());
+/// assert_eq!(177.1429, trace.get_wet_density());
+/// assert_eq!(0.14285714, trace.get_moisture_content());
+/// assert_eq!(155., trace.get_dry_density());
+/// assert_eq!(139.7, trace.get_lab_max_correction());
+/// assert_eq!(111., trace.get_compaction());
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn calculate_all_intermediate<T: Float>(
+  cone_pre_test: T,
+  cone_post_test: T,
+  soil: T,
+  wet_weight: T,
+  dry_weight: T,
+  tare_pan: T,
+  lab_max: T,
+  rock_correction: RockCorrectionChoice<T>,
+) -> Result<CalculationTrace<T>, CalcError> {
+  let (sand_used_raw, sand_used) = trace_step(SandUsed::new(cone_pre_test, cone_post_test, None))?;
+  let (wet_density_raw, wet_density) = trace_step(WetDensity::new(soil, SandUsedChoice::Value(sand_used_raw), None))?;
+  let (moisture_content_raw, moisture_content) = trace_step(MoistureContent::new(wet_weight, dry_weight, tare_pan))?;
+  let (dry_density_raw, dry_density) = trace_step(DryDensity::new(WetDensityChoice::Value(wet_density_raw), MoistureContentChoice::Value(moisture_content_raw)))?;
+  let (lab_max_correction_raw, lab_max_correction) = trace_step(LabMaxCorrection::new(rock_correction, lab_max, None))?;
+  let (_, compaction) = trace_step(Compaction::new(DryDensityChoice::Value(dry_density_raw), lab_max_correction_raw))?;
+
+  Ok(CalculationTrace { sand_used, wet_density, moisture_content, dry_density, lab_max_correction, compaction })
+}
+
+impl<T: Float> fmt::Display for FieldTestReport<T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    writeln!(f, "{}", self.sand_used)?;
+    writeln!(f, "{}", self.wet_density)?;
+    writeln!(f, "{}", self.moisture_content)?;
+    writeln!(f, "{}", self.dry_density)?;
+    write!(f, "{}", self.compaction)
+  }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_field_test_report() {
+    let report = FieldTestReport::new(14.65, 8.75, 4.65, 1600., 1575., 1400., 135.6);
+
+    assert_eq!(Ok(2.31), report.sand_used());
+    assert_eq!(Ok(177.1429), report.wet_density());
+    assert_eq!(Ok(0.14285714), report.moisture_content());
+    assert_eq!(Ok(155.), report.dry_density());
+    assert_eq!(Ok(114.3), report.compaction());
+    assert_eq!(Ok(114.3), report.get_compaction().calculate());
+    assert_eq!(Ok(2.3), report.moisture_deviation(12., 2.).calculate());
+  }
+
+  #[test]
+  fn test_from_parts() {
+    let sand_used = SandUsed::new(14.65, 8.75, None);
+    let wet_density = WetDensity::new(4.65, SandUsedChoice::Value(2.31), Some(90.));
+    let report = FieldTestReport::from_parts(sand_used, wet_density, MoistureContent::new(1600., 1575., 1400.), 135.6);
+
+    assert_eq!(Ok(181.1688), report.wet_density());
+  }
+
+  #[test]
+  fn test_from_measurements() {
+    let defaults = FieldTestReport::from_measurements(14.65, 8.75, None, 4.65, None, 1600., 1575., 1400., 135.6);
+    assert_eq!(FieldTestReport::new(14.65, 8.75, 4.65, 1600., 1575., 1400., 135.6), defaults);
+
+    let report = FieldTestReport::from_measurements(14.65, 8.75, Some(3.6), 4.65, Some(89.5), 1600., 1575., 1400., 135.6);
+    let summary = report.summary().unwrap();
+
+    assert_eq!(3.6, summary.get_sand_in_cone_lb());
+    assert_eq!(89.5, summary.get_sand_density_pcf());
+    assert_eq!(Ok(2.3), report.sand_used());
+    assert_eq!(Ok(158.), report.dry_density());
+    assert_eq!(Ok(116.8), report.compaction());
+  }
+
+  #[test]
+  fn test_errors() {
+    let report = FieldTestReport::new(14.65, 8.75, 4.65, 1600., 1575., 1575., 135.6);
+
+    assert_eq!(Ok(177.1429), report.wet_density());
+    assert_eq!(Err(CalcError::DivisionByZero), report.moisture_content());
+    assert_eq!(Err(CalcError::DivisionByZero), report.compaction());
+  }
+
+  #[test]
+  fn test_summary() {
+    let report = FieldTestReport::new(14.65, 8.75, 4.65, 1600., 1575., 1400., 135.6);
+    let summary = report.summary().unwrap();
+
+    assert_eq!(3.59, summary.get_sand_in_cone_lb());
+    assert_eq!(2.31, summary.get_sand_used_lb());
+    assert_eq!(88., summary.get_sand_density_pcf());
+    assert_eq!(177.1429, summary.get_wet_density_pcf());
+    assert_eq!(14.285714, summary.get_moisture_content_percent());
+    assert_eq!(155., summary.get_dry_density_pcf());
+    assert_eq!(135.6, summary.get_lab_max_pcf());
+    assert_eq!(114.3, summary.get_compaction_percent());
+
+    let failing = FieldTestReport::new(14.65, 8.75, 4.65, 1600., 1575., 1575., 135.6);
+    assert_eq!(Err(CalcError::DivisionByZero), failing.summary());
+  }
+
+  #[test]
+  fn test_calculate_all_intermediate() {
+    let report = FieldTestReport::new(14.65, 8.75, 4.65, 1600., 1575., 1400., 135.6);
+    let trace = calculate_all_intermediate(14.65, 8.75, 4.65, 1600., 1575., 1400., 135.6, RockCorrectionChoice::Value(0.)).unwrap();
+
+    // Without rock, the lab max is unchanged, so every step matches the report.
+    assert_eq!(Ok(trace.get_sand_used()), report.sand_used());
+    assert_eq!(Ok(trace.get_wet_density()), report.wet_density());
+    assert_eq!(Ok(trace.get_moisture_content()), report.moisture_content());
+    assert_eq!(Ok(trace.get_dry_density()), report.dry_density());
+    assert_eq!(135.6, trace.get_lab_max_correction());
+    assert_eq!(Ok(trace.get_compaction()), report.compaction());
+
+    let corrected = calculate_all_intermediate(14.65, 8.75, 4.65, 1600., 1575., 1400., 135.6, RockCorrectionChoice::Value(0.2)).unwrap();
+    assert_eq!(139.7, corrected.get_lab_max_correction());
+    assert_eq!(111., corrected.get_compaction());
+
+    assert_eq!(Err(CalcError::DivisionByZero), calculate_all_intermediate(14.65, 8.75, 4.65, 1600., 1575., 1575., 135.6, RockCorrectionChoice::Value(0.2)));
+  }
+
+  #[cfg(feature = "json")]
+  #[test]
+  fn test_to_report_json() {
+    let report = FieldTestReport::new(14.65, 8.75, 4.65, 1600., 1575., 1400., 135.6);
+    let json: serde_json::Value = serde_json::from_str(&report.to_report_json().unwrap()).unwrap();
+
+    let keys: Vec<&str> = json.as_object().unwrap().keys().map(|k| k.as_str()).collect();
+    assert_eq!(11, keys.len());
+    assert_eq!(2.31, json["sand_used_lb"]);
+    assert_eq!(177.1429, json["wet_density_pcf"]);
+    assert_eq!(114.3, json["compaction_percent"]);
+  }
+
+  #[cfg(feature = "json")]
+  #[test]
+  fn test_to_report_json_errors() {
+    assert_eq!(Err(CalcError::DivisionByZero), FieldTestReport::new(14.65, 8.75, 4.65, 1600., 1575., 1400., 0.).to_report_json());
+    assert_eq!(Err(CalcError::NonFinite), FieldTestReport::new(14.65, 8.75, 4.65, 1600., 1575., 1400., f64::NAN).to_report_json());
+
+    let summary = FieldTestReport::new(14.65, 8.75, 4.65, 1600., 1575., 1400., 135.6).summary().unwrap();
+    assert!(summary.is_finite());
+    assert!(!ReportSummary { cone_pre_test_lb: f64::INFINITY, ..summary }.is_finite());
+  }
+
+  #[test]
+  fn test_display() {
+    let report = FieldTestReport::new(14.65, 8.75, 4.65, 1600., 1575., 1400., 135.6);
+    let display = report.to_string();
+
+    assert_eq!(5, display.lines().count());
+    assert!(display.starts_with("Sand Used: 14.65 - (8.75 + 3.59) = 2.31 lb\n"));
+    assert!(display.ends_with("Compaction: 155 / 135.6 * 100 = 114.3%"));
+  }
+}
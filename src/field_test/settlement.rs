@@ -0,0 +1,292 @@
+//! Settlement
+use alloc::vec;
+use alloc::vec::Vec;
+use crate::domain::errors::{CalcError, ValidationError};
+use crate::domain::traits::{Calculate, Rounding};
+use crate::field_test::types::VoidRatioChoice;
+use crate::math::float::{checked_div, Float};
+use core::fmt;
+
+/// Primary consolidation settlement calculation, for a clay layer.
+///
+/// A normally consolidated clay settles along its virgin compression line, `Sc = Cc * H / (1 + e0) * log10((σ0 + Δσ) / σ0)`.
+///
+/// An overconsolidated clay recompresses with `Cr` up to its preconsolidation stress, `σp`, then follows `Cc` beyond it,
+/// `Sc = Cr * H / (1 + e0) * log10(σp / σ0) + Cc * H / (1 + e0) * log10((σ0 + Δσ) / σp)`. When the final stress does not reach `σp` only the first term applies, with `σ0 + Δσ` in place of `σp`.
+///
+/// The settlement is in the same length unit as the thickness, `H`, and the stresses can be in any unit, as only their ratios are used.
+///
+/// # Example
+///
+/// ```
+/// use billios::field_test::Settlement;
+/// use billios::field_test::types::VoidRatioChoice;
+///
+/// let normally_consolidated = Settlement::new(0.3, VoidRatioChoice::Value(0.9), 10., 1000., 1000.);
+/// assert_eq!(Ok(0.4753), normally_consolidated.calculate());
+///
+/// let overconsolidated = Settlement::overconsolidated(0.3, 0.05, 1500., VoidRatioChoice::Value(0.9), 10., 1000., 1000.);
+/// assert_eq!(Ok(0.2436), overconsolidated.calculate());
+/// ```
+///
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Settlement<T: Float = f64> {
+  compression_index: T,
+  history: StressHistory<T>,
+  void_ratio: VoidRatioChoice<T>,
+  thickness: T,
+  initial_stress: T,
+  stress_increase: T,
+}
+
+/// The stress history of the clay a `Settlement` is calculated for.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum StressHistory<T: Float> {
+  #[default]
+  NormallyConsolidated,
+  Overconsolidated { recompression_index: T, preconsolidation_stress: T },
+}
+
+impl<T: Float> Rounding<T> for Settlement<T> {}
+
+impl<T: Float> Calculate<T> for Settlement<T> {
+  fn calculate_raw(&self) -> Result<T, CalcError> {
+    let void_ratio = self.void_ratio.resolve()?;
+
+    // The stress ratios below all divide by the initial stress, or the larger yield stress.
+    if self.initial_stress.abs() < T::EPSILON {
+      return Err(CalcError::DivisionByZero);
+    }
+
+    let ten = T::from_f64(10.);
+    let log10 = |x: T| x.ln() / ten.ln();
+
+    let strain = checked_div(self.thickness, T::ONE + void_ratio)?;
+    let final_stress = self.initial_stress + self.stress_increase;
+
+    let result = match self.history {
+      StressHistory::NormallyConsolidated => self.compression_index * strain * log10(final_stress / self.initial_stress),
+      StressHistory::Overconsolidated { recompression_index, preconsolidation_stress } => {
+        // A preconsolidation stress at or below the initial stress is normally consolidated.
+        let yield_stress = if preconsolidation_stress > self.initial_stress { preconsolidation_stress } else { self.initial_stress };
+
+        if final_stress <= yield_stress {
+          recompression_index * strain * log10(final_stress / self.initial_stress)
+        } else {
+          recompression_index * strain * log10(yield_stress / self.initial_stress) + self.compression_index * strain * log10(final_stress / yield_stress)
+        }
+      }
+    };
+
+    Ok(result)
+  }
+
+  fn precision(&self) -> i32 {
+    4
+  }
+
+  fn terms(&self) -> Result<Vec<(&'static str, T)>, CalcError> {
+    let mut terms = vec![
+      ("compression_index", self.compression_index),
+      ("void_ratio", self.void_ratio.resolve()?),
+      ("thickness", self.thickness),
+      ("initial_stress", self.initial_stress),
+      ("stress_increase", self.stress_increase),
+    ];
+
+    if let StressHistory::Overconsolidated { recompression_index, preconsolidation_stress } = self.history {
+      terms.push(("recompression_index", recompression_index));
+      terms.push(("preconsolidation_stress", preconsolidation_stress));
+    }
+
+    Ok(terms)
+  }
+}
+
+impl<T: Float> Settlement<T> {
+  /// Create a new instance of `Settlement` for a normally consolidated clay.
+  ///
+  /// # Arguments
+  ///
+  /// - `compression_index` - A float that corresponds to the measurement: **Compression Index**, `Cc`.
+  ///
+  /// - `void_ratio` - A `VoidRatioChoice` for the measurement: **Initial Void Ratio**, `e0`. This offers the ability to use either a value (float), or pass an existing `VoidRatio::new()` constructor.
+  ///
+  /// - `thickness` - A float that corresponds to the measurement: **Thickness** of the clay layer, `H`.
+  ///
+  /// - `initial_stress` - A float that corresponds to the measurement: **Initial Effective Stress** at the middle of the layer, `σ0`.
+  ///
+  /// - `stress_increase` - A float that corresponds to the measurement: **Stress Increase** at the middle of the layer, `Δσ`.
+  ///
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn new(compression_index: T, void_ratio: VoidRatioChoice<T>, thickness: T, initial_stress: T, stress_increase: T) -> Self {
+    Self { compression_index, history: StressHistory::NormallyConsolidated, void_ratio, thickness, initial_stress, stress_increase }
+  }
+
+  /// Create a new instance of `Settlement` for an overconsolidated clay.
+  ///
+  /// Takes the same arguments as `new()`, along with
+  ///
+  /// - `recompression_index` - A float that corresponds to the measurement: **Recompression Index**, `Cr`.
+  ///
+  /// - `preconsolidation_stress` - A float that corresponds to the measurement: **Preconsolidation Stress**, `σp`, in the same unit as the other stresses.
+  ///
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn overconsolidated(compression_index: T, recompression_index: T, preconsolidation_stress: T, void_ratio: VoidRatioChoice<T>, thickness: T, initial_stress: T, stress_increase: T) -> Self {
+    let history = StressHistory::Overconsolidated { recompression_index, preconsolidation_stress };
+
+    Self { compression_index, history, void_ratio, thickness, initial_stress, stress_increase }
+  }
+
+  /// Create a new, validated, instance of `Settlement` for a normally consolidated clay.
+  ///
+  /// Returns a `ValidationError` if a value is `NaN`, infinite, or negative. `Constructor` values are not checked, use their own `try_new()` to validate them.
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn try_new(compression_index: T, void_ratio: VoidRatioChoice<T>, thickness: T, initial_stress: T, stress_increase: T) -> Result<Self, ValidationError> {
+    ValidationError::check_non_negative("compression_index", compression_index)?;
+
+    if let VoidRatioChoice::Value(v) = void_ratio {
+      ValidationError::check_non_negative("void_ratio", v)?;
+    }
+
+    ValidationError::check_non_negative("thickness", thickness)?;
+    ValidationError::check_non_negative("initial_stress", initial_stress)?;
+    ValidationError::check_non_negative("stress_increase", stress_increase)?;
+
+    Ok(Self::new(compression_index, void_ratio, thickness, initial_stress, stress_increase))
+  }
+
+  /// Calculate the Settlement value.
+  ///
+  /// Returns `CalcError::DivisionByZero` when the absolute value of `1 + e0`, or of `initial_stress`, is less than `T::EPSILON`.
+  ///
+  /// Delegates to the `Calculate` implementation.
+  #[must_use = "the result of a calculation should be used, or its error handled"]
+  pub fn calculate(&self) -> Result<T, CalcError> {
+    Calculate::calculate(self)
+  }
+
+  /// Returns `true` when the final stress passes the preconsolidation stress, or the clay is normally consolidated, so the settlement includes the virgin compression line.
+  pub fn is_virgin_compression(&self) -> bool {
+    match self.history {
+      StressHistory::NormallyConsolidated => true,
+      StressHistory::Overconsolidated { preconsolidation_stress, .. } => self.initial_stress + self.stress_increase > preconsolidation_stress,
+    }
+  }
+
+  /// Getter for `compression_index`
+  pub fn get_compression_index(&self) -> T {
+    self.compression_index
+  }
+
+  /// Getter for `recompression_index`, `None` for a normally consolidated clay.
+  pub fn get_recompression_index(&self) -> Option<T> {
+    match self.history {
+      StressHistory::NormallyConsolidated => None,
+      StressHistory::Overconsolidated { recompression_index, .. } => Some(recompression_index),
+    }
+  }
+
+  /// Getter for `preconsolidation_stress`, `None` for a normally consolidated clay.
+  pub fn get_preconsolidation_stress(&self) -> Option<T> {
+    match self.history {
+      StressHistory::NormallyConsolidated => None,
+      StressHistory::Overconsolidated { preconsolidation_stress, .. } => Some(preconsolidation_stress),
+    }
+  }
+
+  /// Getter for `void_ratio`
+  ///
+  /// A `Constructor` value is calculated without rounding, so any error from its calculation is returned.
+  pub fn get_void_ratio(&self) -> Result<T, CalcError> {
+    self.void_ratio.resolve()
+  }
+
+  /// Getter for `thickness`
+  pub fn get_thickness(&self) -> T {
+    self.thickness
+  }
+
+  /// Getter for `initial_stress`
+  pub fn get_initial_stress(&self) -> T {
+    self.initial_stress
+  }
+
+  /// Getter for `stress_increase`
+  pub fn get_stress_increase(&self) -> T {
+    self.stress_increase
+  }
+}
+
+impl<T: Float> fmt::Display for Settlement<T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let history = match self.history {
+      StressHistory::NormallyConsolidated => "normally consolidated",
+      StressHistory::Overconsolidated { .. } => "overconsolidated",
+    };
+
+    write!(f, "Settlement ({}): {} to {}", history, self.initial_stress, self.initial_stress + self.stress_increase)?;
+
+    match self.calculate() {
+      Ok(v) => write!(f, " = {}", v),
+      Err(e) => write!(f, " = [{}]", e),
+    }
+  }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::field_test::VoidRatio;
+  use crate::field_test::types::DryDensityChoice;
+
+  #[test]
+  fn test_normally_consolidated() {
+    let settlement = Settlement::new(0.3, VoidRatioChoice::Value(0.9), 10., 1000., 1000.);
+
+    assert_eq!(Ok(0.4753), settlement.calculate());
+    assert_eq!(None, settlement.get_recompression_index());
+    assert!(settlement.is_virgin_compression());
+    assert_eq!("Settlement (normally consolidated): 1000 to 2000 = 0.4753", settlement.to_string());
+    assert_eq!(5, settlement.terms().unwrap().len());
+  }
+
+  #[test]
+  fn test_overconsolidated() {
+    // Passing the preconsolidation stress.
+    let settlement = Settlement::overconsolidated(0.3, 0.05, 1500., VoidRatioChoice::Value(0.9), 10., 1000., 1000.);
+    assert_eq!(Ok(0.2436), settlement.calculate());
+    assert_eq!(Some(1500.), settlement.get_preconsolidation_stress());
+    assert!(settlement.is_virgin_compression());
+    assert_eq!(7, settlement.terms().unwrap().len());
+
+    // Staying below it, only recompression.
+    let settlement = Settlement::overconsolidated(0.3, 0.05, 2500., VoidRatioChoice::Value(0.9), 10., 1000., 1000.);
+    assert_eq!(Ok(0.0792), settlement.calculate());
+    assert!(!settlement.is_virgin_compression());
+
+    // A preconsolidation stress below the initial stress is normally consolidated.
+    let settlement = Settlement::overconsolidated(0.3, 0.05, 800., VoidRatioChoice::Value(0.9), 10., 1000., 1000.);
+    assert_eq!(Settlement::new(0.3, VoidRatioChoice::Value(0.9), 10., 1000., 1000.).calculate(), settlement.calculate());
+  }
+
+  #[test]
+  fn test_void_ratio_constructor() {
+    let void_ratio = VoidRatio::new(DryDensityChoice::Value(90.), None);
+    let settlement = Settlement::new(0.3, VoidRatioChoice::Constructor(void_ratio), 10., 1000., 1000.);
+
+    assert_eq!(void_ratio.calculate_raw(), settlement.get_void_ratio());
+    assert!(settlement.calculate().unwrap() > 0.);
+  }
+
+  #[test]
+  fn test_errors() {
+    assert_eq!(Err(CalcError::DivisionByZero), Settlement::new(0.3, VoidRatioChoice::Value(0.9), 10., 0., 1000.).calculate());
+    assert_eq!(Err(CalcError::DivisionByZero), Settlement::new(0.3, VoidRatioChoice::Value(-1.), 10., 1000., 1000.).calculate());
+    assert_eq!("thickness", Settlement::try_new(0.3, VoidRatioChoice::Value(0.9), -10., 1000., 1000.).unwrap_err().field);
+  }
+}
@@ -0,0 +1,209 @@
+//! Stress
+use alloc::vec::Vec;
+use crate::domain::errors::{CalcError, ValidationError};
+use crate::domain::traits::Rounding;
+use crate::math::float::Float;
+use super::constants::UNIT_WEIGHT_WATER;
+use super::types::WetDensityChoice;
+
+/// Vertical (overburden) stress calculation, through a profile of soil layers.
+///
+/// Layer thicknesses and depths are in feet, and unit weights in pcf, so stresses are in psf. Layers below the water table should use their saturated unit weight.
+///
+/// # Example
+///
+/// ```
+/// use billios::field_test::{VerticalStress, WetDensity};
+/// use billios::field_test::types::SandUsedChoice;
+///
+/// let fill = WetDensity::new(2.5, SandUsedChoice::Value(2.), None);
+///
+/// let profile = VerticalStress::new(vec![(5., fill.into()), (10., 125.0.into())], Some(8.));
+///
+/// assert_eq!(Ok(1425.), profile.total_stress(12.));
+/// assert_eq!(Ok(249.6), profile.pore_pressure(12.));
+/// assert_eq!(Ok(1175.4), profile.effective_stress(12.));
+/// ```
+///
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VerticalStress<T: Float = f64> {
+  layers: Vec<(T, WetDensityChoice<T>)>,
+  water_table: Option<T>,
+}
+
+impl<T: Float> Rounding<T> for VerticalStress<T> {}
+
+impl<T: Float> VerticalStress<T> {
+  /// Create a new instance of `VerticalStress`.
+  ///
+  /// # Arguments
+  ///
+  /// - `layers` - A list of `(thickness, unit weight)` pairs, from the surface down. The unit weight is a `WetDensityChoice`, so either a value (float), or an existing `WetDensity::new()` constructor.
+  ///
+  /// - `water_table` - An `Option<T>` for the depth to the water table. When `None` the water table is below every layer, so there is no pore pressure.
+  ///
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn new(layers: Vec<(T, WetDensityChoice<T>)>, water_table: Option<T>) -> Self {
+    Self { layers, water_table }
+  }
+
+  /// Create a new, validated, instance of `VerticalStress`.
+  ///
+  /// Returns a `ValidationError` if a value is `NaN`, infinite, or negative. `Constructor` values are not checked, use their own `try_new()` to validate them.
+  #[must_use = "constructing a calculation does not calculate it, did you forget to call calculate()?"]
+  pub fn try_new(layers: Vec<(T, WetDensityChoice<T>)>, water_table: Option<T>) -> Result<Self, ValidationError> {
+    for &(thickness, unit_weight) in &layers {
+      ValidationError::check_non_negative("thickness", thickness)?;
+
+      if let WetDensityChoice::Value(v) = unit_weight {
+        ValidationError::check_non_negative("unit_weight", v)?;
+      }
+    }
+    ValidationError::check_optional_non_negative("water_table", water_table)?;
+
+    Ok(Self::new(layers, water_table))
+  }
+
+  /// Getter for `layers`
+  pub fn get_layers(&self) -> &[(T, WetDensityChoice<T>)] {
+    &self.layers
+  }
+
+  /// Getter for `water_table`
+  pub fn get_water_table(&self) -> Option<T> {
+    self.water_table
+  }
+
+  /// The total thickness of the layers.
+  pub fn thickness(&self) -> T {
+    self.layers.iter().map(|&(thickness, _)| thickness).sum()
+  }
+
+  /// Returns `CalcError::OutOfRange` if `depth` is negative, or below the bottom of the layers.
+  fn check_depth(&self, depth: T) -> Result<(), CalcError> {
+    if depth.is_nan() || depth < T::ZERO || depth > self.thickness() {
+      return Err(CalcError::OutOfRange);
+    }
+
+    Ok(())
+  }
+
+  /// Total stress, unrounded.
+  fn total(&self, depth: T) -> Result<T, CalcError> {
+    self.check_depth(depth)?;
+
+    let mut top = T::ZERO;
+    let mut stress = T::ZERO;
+
+    for &(thickness, unit_weight) in &self.layers {
+      if top >= depth {
+        break;
+      }
+
+      let bottom = top + thickness;
+      let within = if bottom < depth { thickness } else { depth - top };
+
+      stress = stress + within * unit_weight.resolve()?;
+      top = bottom;
+    }
+
+    Ok(stress)
+  }
+
+  /// Pore pressure, unrounded.
+  fn pore(&self, depth: T) -> Result<T, CalcError> {
+    self.check_depth(depth)?;
+
+    match self.water_table {
+      Some(water_table) if depth > water_table => Ok((depth - water_table) * T::from_f64(UNIT_WEIGHT_WATER)),
+      _ => Ok(T::ZERO),
+    }
+  }
+
+  /// Calculate the total vertical stress at `depth`, rounded to one decimal place.
+  ///
+  /// Returns `CalcError::OutOfRange` if `depth` is negative, or below the bottom of the layers, and any error from calculating a `Constructor` unit weight.
+  pub fn total_stress(&self, depth: T) -> Result<T, CalcError> {
+    self.round_n(self.total(depth)?, 1)
+  }
+
+  /// Calculate the pore water pressure at `depth`, rounded to one decimal place.
+  ///
+  /// The pore pressure is hydrostatic below the water table, and `0` above it.
+  ///
+  /// Returns `CalcError::OutOfRange` if `depth` is negative, or below the bottom of the layers.
+  pub fn pore_pressure(&self, depth: T) -> Result<T, CalcError> {
+    self.round_n(self.pore(depth)?, 1)
+  }
+
+  /// Calculate the effective vertical stress at `depth`, the total stress less the pore pressure, rounded to one decimal place.
+  ///
+  /// Returns `CalcError::OutOfRange` if `depth` is negative, or below the bottom of the layers, and any error from calculating a `Constructor` unit weight.
+  pub fn effective_stress(&self, depth: T) -> Result<T, CalcError> {
+    self.round_n(self.total(depth)? - self.pore(depth)?, 1)
+  }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::field_test::WetDensity;
+  use crate::field_test::types::SandUsedChoice;
+
+  fn profile() -> VerticalStress {
+    VerticalStress::new(vec![(5., 110.0.into()), (10., 125.0.into())], Some(8.))
+  }
+
+  #[test]
+  fn test_stresses() {
+    let profile = profile();
+
+    assert_eq!(15., profile.thickness());
+    assert_eq!(Ok(0.), profile.total_stress(0.));
+    assert_eq!(Ok(550.), profile.total_stress(5.));
+    assert_eq!(Ok(1425.), profile.total_stress(12.));
+    assert_eq!(Ok(1800.), profile.total_stress(15.));
+
+    assert_eq!(Ok(0.), profile.pore_pressure(5.));
+    assert_eq!(Ok(249.6), profile.pore_pressure(12.));
+
+    assert_eq!(Ok(550.), profile.effective_stress(5.));
+    assert_eq!(Ok(1175.4), profile.effective_stress(12.));
+    assert_eq!(Ok(1363.2), profile.effective_stress(15.));
+  }
+
+  #[test]
+  fn test_no_water_table() {
+    let profile = VerticalStress::new(vec![(5., 110.0.into()), (10., 125.0.into())], None);
+
+    assert_eq!(Ok(0.), profile.pore_pressure(15.));
+    assert_eq!(profile.total_stress(12.), profile.effective_stress(12.));
+  }
+
+  #[test]
+  fn test_constructor() {
+    let wet_density = WetDensity::new(4.65, SandUsedChoice::Value(2.31), None);
+    let profile = VerticalStress::new(vec![(2., wet_density.into())], None);
+
+    assert_eq!(Ok(354.3), profile.total_stress(2.));
+  }
+
+  #[test]
+  fn test_errors() {
+    let profile = profile();
+
+    assert_eq!(Err(CalcError::OutOfRange), profile.total_stress(16.));
+    assert_eq!(Err(CalcError::OutOfRange), profile.pore_pressure(-1.));
+    assert_eq!(Err(CalcError::OutOfRange), profile.effective_stress(f64::NAN));
+
+    let failing = VerticalStress::new(vec![(2., WetDensity::new(4.65, SandUsedChoice::Value(0.), None).into())], None);
+    assert_eq!(Err(CalcError::DivisionByZero), failing.total_stress(1.));
+
+    assert_eq!("thickness", VerticalStress::try_new(vec![(-2., 110.0.into())], None).unwrap_err().field);
+    assert_eq!("unit_weight", VerticalStress::try_new(vec![(2., (-110.0).into())], None).unwrap_err().field);
+    assert_eq!("water_table", VerticalStress::try_new(vec![(2., 110.0.into())], Some(f64::NAN)).unwrap_err().field);
+  }
+}
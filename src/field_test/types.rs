@@ -1,33 +1,301 @@
 //! Types
+//!
+//! Each `*Choice` enum accepts either a known value or a constructor to calculate it from, and converts from both with `into()`.
+//!
+//! A `Value` can also be parsed from a string, either as a plain float, `"0.2"`, or prefixed, `"value:0.2"`.
+//!
+//! ```
+//! use billios::field_test::{DryDensity, WetDensity};
+//! use billios::field_test::types::WetDensityChoice;
+//!
+//! let wet_density = WetDensity::new(4.65, 2.31.into(), None);
+//! let dry_density = DryDensity::new(wet_density.into(), 0.1428571.into());
+//!
+//! assert_eq!(Ok(155.), dry_density.calculate());
+//! assert_eq!(Ok(177.), WetDensityChoice::from(177.).resolve());
+//! ```
 
 use super::calculations::*;
+use crate::domain::errors::{CalcError, ParseChoiceError};
+use crate::domain::traits::Calculate;
+use crate::domain::types::Percentage;
+use crate::math::float::Float;
+use core::str::FromStr;
 
-#[derive(Debug, Clone, Copy)]
-pub enum SandUsedChoice {
-  Value(f64),
-  Constructor(SandUsed),
+/// Implements `resolve()` and the `From` conversions for a `*Choice` enum.
+///
+/// Any variants beyond `Value` and `Constructor` are listed after the constructor, with the expression they resolve to.
+macro_rules! impl_choice {
+  ($choice:ident, $constructor:ident $(, $variant:ident($v:ident) => $resolve:expr)*) => {
+    impl<T: Float> $choice<T> {
+      /// Resolve the choice to a value.
+      ///
+      /// Returns the `Value` as is, or the result of calling `calculate_raw()` on the `Constructor`, so chained calculations are only rounded once.
+      pub fn resolve(&self) -> Result<T, CalcError> {
+        match self {
+          $choice::Value(v) => Ok(*v),
+          $choice::Constructor(c) => c.calculate_raw(),
+          $($choice::$variant($v) => Ok($resolve),)*
+        }
+      }
+
+      /// Resolve the choice to a value, rounding a `Constructor` to its default `precision()`.
+      ///
+      /// Used where the value is shown, rather than calculated with.
+      pub fn resolve_rounded(&self) -> Result<T, CalcError> {
+        match self {
+          $choice::Value(v) => Ok(*v),
+          $choice::Constructor(c) => c.calculate(),
+          $($choice::$variant($v) => Ok($resolve),)*
+        }
+      }
+    }
+
+    impl<T: Float> FromStr for $choice<T> {
+      type Err = ParseChoiceError;
+
+      /// Parse a `Value` from a plain float, or one prefixed with `value:`.
+      ///
+      /// Returns `ParseChoiceError::Constructor` for a string prefixed with `constructor:`, and `ParseChoiceError::Float` if the value is not a valid float.
+      fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        if s.starts_with("constructor:") {
+          return Err(ParseChoiceError::Constructor);
+        }
+
+        let value = s.strip_prefix("value:").unwrap_or(s).trim();
+
+        Ok($choice::Value(T::from_f64(value.parse::<f64>()?)))
+      }
+    }
+
+    /// Defaults to `Value(0)`.
+    impl<T: Float> Default for $choice<T> {
+      fn default() -> Self {
+        $choice::Value(T::ZERO)
+      }
+    }
+
+    impl<T: Float> From<T> for $choice<T> {
+      fn from(value: T) -> Self {
+        $choice::Value(value)
+      }
+    }
+
+    impl<T: Float> From<$constructor<T>> for $choice<T> {
+      fn from(constructor: $constructor<T>) -> Self {
+        $choice::Constructor(constructor)
+      }
+    }
+  };
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SandUsedChoice<T: Float = f64> {
+  Value(T),
+  Constructor(SandUsed<T>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WetDensityChoice<T: Float = f64> {
+  Value(T),
+  Constructor(WetDensity<T>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MoistureContentChoice<T: Float = f64> {
+  Value(T),
+  Constructor(MoistureContent<T>),
+  /// A moisture content given as a percent, e.g. `14.3`, which resolves to the fraction `0.143`.
+  Percent(T),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DryDensityChoice<T: Float = f64> {
+  Value(T),
+  Constructor(DryDensity<T>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RockCorrectionChoice<T: Float = f64> {
+  Value(T),
+  Constructor(RockCorrection<T>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum VoidRatioChoice<T: Float = f64> {
+  Value(T),
+  Constructor(VoidRatio<T>),
 }
 
-#[derive(Debug, Clone, Copy)]
-pub enum WetDensityChoice {
-  Value(f64),
-  Constructor(WetDensity),
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SpecificGravityChoice<T: Float = f64> {
+  Value(T),
+  Constructor(SpecificGravity<T>),
+  /// The typical specific gravity of a `SoilType`, from `typical_specific_gravity()`.
+  SoilType(SoilType),
 }
 
-#[derive(Debug, Clone, Copy)]
-pub enum MoistureContentChoice {
-  Value(f64),
-  Constructor(MoistureContent),
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BulkSpecificGravityChoice<T: Float = f64> {
+  Value(T),
+  Constructor(BulkSpecificGravity<T>),
 }
 
-#[derive(Debug, Clone, Copy)]
-pub enum DryDensityChoice {
-  Value(f64),
-  Constructor(DryDensity),
+/// The predominant mineral, or organic content, of a soil, for when its specific gravity has not been measured.
+///
+/// The specific gravities are typical values, representative of the range reported for each type, not measured ones. A soil is a mix of minerals, so use a measured `SpecificGravity` where the result matters.
+///
+/// # Example
+///
+/// ```
+/// use billios::field_test::LabMaxCorrection;
+/// use billios::field_test::types::{RockCorrectionChoice, SoilType};
+///
+/// assert_eq!(2.72, SoilType::Calcite.typical_specific_gravity());
+///
+/// let lab_max_correction = LabMaxCorrection::new(RockCorrectionChoice::Value(0.2), 135.6, Some(SoilType::Quartz.into()));
+///
+/// assert_eq!(Ok(2.65), lab_max_correction.get_specific_gravity());
+/// assert_eq!(Ok(139.3), lab_max_correction.calculate());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SoilType {
+  /// Quartz sand and silt, `2.65`.
+  Quartz,
+  /// Feldspar, `2.6`.
+  Feldspar,
+  /// Calcite, such as limestone or shell sand, `2.72`.
+  Calcite,
+  /// Dolomite, `2.85`.
+  Dolomite,
+  /// Mica, `2.8`.
+  Mica,
+  /// Kaolinite clay, `2.62`.
+  Kaolinite,
+  /// Illite clay, `2.8`.
+  Illite,
+  /// Montmorillonite clay, `2.7`.
+  Montmorillonite,
+  /// Organic silt or clay, `2.3`. The specific gravity falls as the organic content rises.
+  Organic,
+  /// Peat, `1.5`.
+  Peat,
 }
 
-#[derive(Debug, Clone, Copy)]
-pub enum RockCorrectionChoice {
-  Value(f64),
-  Constructor(RockCorrection),
-}
\ No newline at end of file
+impl SoilType {
+  /// The typical specific gravity of the soil type, a representative value rather than a measured one.
+  pub fn typical_specific_gravity(&self) -> f64 {
+    match self {
+      SoilType::Quartz => 2.65,
+      SoilType::Feldspar => 2.6,
+      SoilType::Calcite => 2.72,
+      SoilType::Dolomite => 2.85,
+      SoilType::Mica => 2.8,
+      SoilType::Kaolinite => 2.62,
+      SoilType::Illite => 2.8,
+      SoilType::Montmorillonite => 2.7,
+      SoilType::Organic => 2.3,
+      SoilType::Peat => 1.5,
+    }
+  }
+}
+
+/// A `Value` of the fraction.
+impl<T: Float> From<Percentage<T>> for MoistureContentChoice<T> {
+  fn from(percentage: Percentage<T>) -> Self {
+    MoistureContentChoice::Value(percentage.as_fraction())
+  }
+}
+
+/// A `Value` of the fraction.
+impl<T: Float> From<Percentage<T>> for RockCorrectionChoice<T> {
+  fn from(percentage: Percentage<T>) -> Self {
+    RockCorrectionChoice::Value(percentage.as_fraction())
+  }
+}
+
+impl<T: Float> From<SoilType> for SpecificGravityChoice<T> {
+  fn from(soil_type: SoilType) -> Self {
+    SpecificGravityChoice::SoilType(soil_type)
+  }
+}
+
+impl_choice!(SandUsedChoice, SandUsed);
+impl_choice!(WetDensityChoice, WetDensity);
+impl_choice!(MoistureContentChoice, MoistureContent, Percent(p) => *p / T::from_f64(100.));
+impl_choice!(DryDensityChoice, DryDensity);
+impl_choice!(RockCorrectionChoice, RockCorrection);
+impl_choice!(VoidRatioChoice, VoidRatio);
+impl_choice!(SpecificGravityChoice, SpecificGravity, SoilType(s) => T::from_f64(s.typical_specific_gravity()));
+impl_choice!(BulkSpecificGravityChoice, BulkSpecificGravity);
+
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_resolve() {
+    assert_eq!(Ok(2.31), SandUsedChoice::Value(2.31).resolve());
+    assert_eq!(SandUsed::new(14.65, 8.75, None).calculate_raw(), SandUsedChoice::Constructor(SandUsed::new(14.65, 8.75, None)).resolve());
+    assert_eq!(Ok(2.31), SandUsedChoice::Constructor(SandUsed::new(14.65, 8.75, None)).resolve_rounded());
+    assert_eq!(Err(CalcError::DivisionByZero), RockCorrectionChoice::Constructor(RockCorrection::new(100., 0.)).resolve());
+    assert_eq!(Ok(0.145), MoistureContentChoice::Percent(14.5).resolve());
+    assert_eq!(Ok(0.145), MoistureContentChoice::Percent(14.5).resolve_rounded());
+    assert_eq!(Ok(2.65), SpecificGravityChoice::SoilType(SoilType::Quartz).resolve());
+    assert_eq!(Ok(1.5_f32), SpecificGravityChoice::<f32>::SoilType(SoilType::Peat).resolve_rounded());
+  }
+
+  #[test]
+  fn test_soil_type() {
+    assert_eq!(2.72, SoilType::Calcite.typical_specific_gravity());
+    assert!(SoilType::Organic.typical_specific_gravity() < SoilType::Quartz.typical_specific_gravity());
+
+    let choice: SpecificGravityChoice = SoilType::Dolomite.into();
+    assert_eq!(SpecificGravityChoice::SoilType(SoilType::Dolomite), choice);
+
+    let lab_max_correction = LabMaxCorrection::new(RockCorrectionChoice::Value(0.2), 135.6, None);
+    assert_eq!(
+      LabMaxCorrection::new(RockCorrectionChoice::Value(0.2), 135.6, Some(SpecificGravityChoice::Value(2.85))).calculate(),
+      lab_max_correction.with_specific_gravity(SoilType::Dolomite).calculate()
+    );
+  }
+
+  #[test]
+  fn test_from() {
+    let value: MoistureContentChoice = 0.25.into();
+    assert!(matches!(value, MoistureContentChoice::Value(v) if v == 0.25));
+
+    let constructor: RockCorrectionChoice = RockCorrection::new(100., 500.).into();
+    assert!(matches!(constructor, RockCorrectionChoice::Constructor(_)));
+    assert_eq!(Ok(0.2), constructor.resolve());
+  }
+
+  #[test]
+  fn test_default() {
+    assert_eq!(RockCorrectionChoice::Value(0.), RockCorrectionChoice::default());
+    assert_eq!(Ok(0_f32), WetDensityChoice::<f32>::default().resolve());
+  }
+
+  #[test]
+  fn test_from_str() {
+    assert_eq!(Ok(RockCorrectionChoice::Value(0.2)), "0.2".parse());
+    assert_eq!(Ok(RockCorrectionChoice::Value(0.2)), " value: 0.2 ".parse());
+    assert_eq!(Ok(SandUsedChoice::Value(2.31_f32)), "2.31".parse());
+    assert_eq!(Err(ParseChoiceError::Constructor), "constructor:100,500".parse::<RockCorrectionChoice>());
+    assert!(matches!("abc".parse::<WetDensityChoice>(), Err(ParseChoiceError::Float(_))));
+    assert!(matches!("value:".parse::<WetDensityChoice>(), Err(ParseChoiceError::Float(_))));
+  }
+}
@@ -17,6 +17,15 @@
 //! cargo add billios
 //! ```
 //!
+//! ## Features
+//!
+//! - `std` - Enabled by default. Disable it with `default-features = false` to use the crate in `no_std` environments, which only requires `alloc`. Rounding and the other floating point methods are then provided by software fallbacks.
+//! - `approx` - Implement `AbsDiffEq`, `RelativeEq` and `UlpsEq` from the `approx` crate for `CalcResult`, so `calculate_detailed()` can be checked with `assert_relative_eq!` and the other `approx` macros. Works without `std`.
+//! - `csv` - Import `FieldTestRecord`s from a CSV export with `field_test::import::from_csv_reader()`. Enables `serde` and `std`.
+//! - `json` - Write a `FieldTestReport` as JSON with a fixed, labelled schema, using `to_report_json()`. Enables `serde`.
+//! - `rayon` - Parallel batch calculations, `par_calculate_all()` and `try_par_calculate_all()`, in `field_test::batch`. Enables `std`.
+//! - `serde` - Derive `Serialize` and `Deserialize` for the calculation structs and `*Choice` enums. Enables `std`.
+//!
 //! ## Code Examples
 //!
 //! ### Example - Sand Used
@@ -24,6 +33,7 @@
 //! Using this formula only requeres a couple lines of code.
 //!
 //! Simply call the `new()` method with the desired values, then call `calculate()` to retrieve the result.
+//! Every `calculate()` returns a `Result`, so errors such as a failed rounding are returned rather than causing a panic.
 //!
 //! #### Use with constant `sand_in_cone` values:
 //!
@@ -33,11 +43,41 @@
 //! let sand_used = SandUsed::new(14.65, 8.75, None);
 //! let result = sand_used.calculate();
 //!
-//! assert_eq!(2.31, result);
+//! assert_eq!(Ok(2.31), result);
+//! ```
+//!
+//! #### Use with `f32`:
+//!
+//! Every calculation is generic over the `Float` trait, and defaults to `f64`.
+//!
+//! ```
+//! use billios::field_test::*;
+//!
+//! let sand_used: SandUsed<f32> = SandUsed::new(14.65, 8.75, None);
+//!
+//! assert_eq!(Ok(2.31_f32), sand_used.calculate());
 //! ```
+//!
+//! #### Use with the prelude:
+//!
+//! The calculations, `*Choice` enums, and traits can be imported all at once from `billios::prelude`.
+//!
+//! ```
+//! use billios::prelude::*;
+//!
+//! let dry_density = DryDensity::new(WetDensityChoice::Value(177.1429), MoistureContentChoice::Value(0.1));
+//!
+//! assert_eq!(Ok(161.), dry_density.calculate());
+//! ```
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
 
 pub mod domain;
 
 pub mod math;
 
-pub mod field_test;
\ No newline at end of file
+pub mod field_test;
+
+pub mod prelude;
\ No newline at end of file
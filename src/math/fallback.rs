@@ -0,0 +1,333 @@
+//! Fallback
+//!
+//! Software implementations of the `f64` methods that `core` does not provide, used by `Float` when the `std` feature is disabled.
+//!
+//! `f32` values are computed as `f64` and converted back.
+
+const SIGN: u64 = 1 << 63;
+const LN_2: f64 = core::f64::consts::LN_2;
+const SQRT_2: f64 = core::f64::consts::SQRT_2;
+
+/// `ln(2)` split so that `k * LN_2_HI` is exact for the exponents of an `f64`.
+const LN_2_HI: f64 = f64::from_bits(0x3fe6_2e42_fee0_0000);
+const LN_2_LO: f64 = f64::from_bits(0x3dea_39ef_3579_3c76);
+
+/// `π / 2` split so that `k * PI_2_HI` is exact for the multiples used to reduce a trigonometric argument.
+const PI_2_HI: f64 = f64::from_bits(0x3ff9_21fb_5440_0000);
+const PI_2_LO: f64 = f64::from_bits(0x3dd0_b461_1a62_6331);
+
+/// Values at least this large have no fractional part.
+const INTEGRAL: f64 = 4_503_599_627_370_496.;
+
+/// Copy the sign of `sign` on to `value`, so e.g. `-0.3` truncates to `-0`.
+fn with_sign(value: f64, sign: f64) -> f64 {
+  f64::from_bits((value.to_bits() & !SIGN) | (sign.to_bits() & SIGN))
+}
+
+/// Round towards zero.
+pub(crate) fn trunc(x: f64) -> f64 {
+  if x.is_nan() || x.abs() >= INTEGRAL {
+    return x;
+  }
+
+  with_sign((x as i64) as f64, x)
+}
+
+pub(crate) fn floor(x: f64) -> f64 {
+  let t = trunc(x);
+
+  if t > x { t - 1. } else { t }
+}
+
+pub(crate) fn ceil(x: f64) -> f64 {
+  let t = trunc(x);
+
+  if t < x { t + 1. } else { t }
+}
+
+pub(crate) fn round(x: f64) -> f64 {
+  let t = trunc(x);
+
+  if (x - t).abs() >= 0.5 { t + with_sign(1., x) } else { t }
+}
+
+pub(crate) fn round_ties_even(x: f64) -> f64 {
+  let t = trunc(x);
+  let difference = (x - t).abs();
+  let odd = trunc(t / 2.) * 2. != t;
+
+  if difference > 0.5 || (difference == 0.5 && odd) { t + with_sign(1., x) } else { t }
+}
+
+/// Exponentiation by squaring.
+pub(crate) fn powi(x: f64, n: i32) -> f64 {
+  let mut base = x;
+  let mut exponent = n.unsigned_abs();
+  let mut result = 1.;
+
+  while exponent > 0 {
+    if exponent & 1 == 1 {
+      result *= base;
+    }
+
+    base *= base;
+    exponent >>= 1;
+  }
+
+  if n < 0 { 1. / result } else { result }
+}
+
+/// `e^(n * ln(x))`, with the sign of a negative `x` restored for an integer `n`.
+pub(crate) fn powf(x: f64, n: f64) -> f64 {
+  if n == 0. {
+    return 1.;
+  }
+
+  if x.is_nan() || n.is_nan() {
+    return f64::NAN;
+  }
+
+  let integer = trunc(n) == n;
+
+  if x < 0. && !integer {
+    return f64::NAN;
+  }
+
+  // Exact for small integer powers and square roots, as with `std`.
+  if integer && n.abs() <= i32::MAX as f64 {
+    return powi(x, n as i32);
+  }
+
+  if n == 0.5 {
+    return sqrt(x);
+  }
+
+  let magnitude = exp(n * ln(x.abs()));
+  let odd = integer && trunc(n / 2.) * 2. != n;
+
+  if x < 0. && odd { -magnitude } else { magnitude }
+}
+
+/// Newton's method, starting from an estimate that halves the exponent.
+pub(crate) fn sqrt(x: f64) -> f64 {
+  if x.is_nan() || x < 0. {
+    return f64::NAN;
+  }
+
+  if x == 0. || x.is_infinite() {
+    return x;
+  }
+
+  // Scale subnormals into the normal range, so the estimate below is close enough to converge.
+  if x < f64::MIN_POSITIVE {
+    return sqrt(x * powi(2., 54)) / powi(2., 27);
+  }
+
+  let mut y = f64::from_bits((x.to_bits() >> 1) + (1023 << 51));
+
+  for _ in 0..6 {
+    y = 0.5 * (y + x / y);
+  }
+
+  y
+}
+
+/// Split `x` into `m * 2^e`, with `m` between `sqrt(1/2)` and `sqrt(2)`, then sum the series `ln(m) = 2 * atanh((m - 1) / (m + 1))`.
+pub(crate) fn ln(x: f64) -> f64 {
+  if x.is_nan() || x < 0. {
+    return f64::NAN;
+  }
+
+  if x == 0. {
+    return f64::NEG_INFINITY;
+  }
+
+  if x.is_infinite() {
+    return x;
+  }
+
+  let mut m = x;
+  let mut e = 0;
+
+  // Scale subnormals into the normal range before reading the exponent.
+  if m < f64::MIN_POSITIVE {
+    m *= powi(2., 54);
+    e -= 54;
+  }
+
+  let bits = m.to_bits();
+  e += ((bits >> 52) & 0x7ff) as i32 - 1023;
+  m = f64::from_bits((bits & !(0x7ff << 52)) | (1023 << 52));
+
+  if m > SQRT_2 {
+    m /= 2.;
+    e += 1;
+  }
+
+  let s = (m - 1.) / (m + 1.);
+  let s2 = s * s;
+  let mut term = s;
+  let mut sum = 0.;
+
+  for k in 0..30 {
+    sum += term / (2 * k + 1) as f64;
+    term *= s2;
+  }
+
+  2. * sum + e as f64 * LN_2
+}
+
+/// Reduce `x` to `k * ln(2) + r`, then sum the Taylor series of `e^r`.
+pub(crate) fn exp(x: f64) -> f64 {
+  if x.is_nan() {
+    return x;
+  }
+
+  if x > 709.8 {
+    return f64::INFINITY;
+  }
+
+  if x < -745.2 {
+    return 0.;
+  }
+
+  let k = round(x / LN_2);
+  let r = (x - k * LN_2_HI) - k * LN_2_LO;
+
+  let mut term = 1.;
+  let mut sum = 1.;
+
+  for n in 1..30 {
+    term *= r / n as f64;
+    sum += term;
+  }
+
+  // Scale in two steps, as `2^k` alone can overflow or underflow where the result does not.
+  let half = (k / 2.) as i32;
+
+  sum * powi(2., half) * powi(2., k as i32 - half)
+}
+
+/// Reduce `x` to `k * π / 2 + r`, sum the Taylor series of `sin(r)` and `cos(r)`, then pick the quadrant from `k`.
+fn sin_cos(x: f64) -> (f64, f64) {
+  if !x.is_finite() {
+    return (f64::NAN, f64::NAN);
+  }
+
+  let k = round(x / (PI_2_HI + PI_2_LO));
+  let r = (x - k * PI_2_HI) - k * PI_2_LO;
+  let r2 = r * r;
+
+  let mut sin_term = r;
+  let mut cos_term = 1.;
+  let mut sin = r;
+  let mut cos = 1.;
+
+  for n in 1..15 {
+    sin_term *= -r2 / ((2 * n) * (2 * n + 1)) as f64;
+    cos_term *= -r2 / ((2 * n - 1) * (2 * n)) as f64;
+    sin += sin_term;
+    cos += cos_term;
+  }
+
+  match (k as i64).rem_euclid(4) {
+    1 => (cos, -sin),
+    2 => (-sin, -cos),
+    3 => (-cos, sin),
+    _ => (sin, cos),
+  }
+}
+
+pub(crate) fn sin(x: f64) -> f64 {
+  sin_cos(x).0
+}
+
+pub(crate) fn cos(x: f64) -> f64 {
+  sin_cos(x).1
+}
+
+pub(crate) fn tan(x: f64) -> f64 {
+  let (sin, cos) = sin_cos(x);
+
+  sin / cos
+}
+
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_rounding() {
+    assert_eq!(2., trunc(2.7));
+    assert_eq!(-2., trunc(-2.7));
+    assert!(trunc(-0.3).is_sign_negative());
+    assert_eq!(2., floor(2.7));
+    assert_eq!(-3., floor(-2.7));
+    assert_eq!(3., ceil(2.1));
+    assert_eq!(-2., ceil(-2.7));
+    assert_eq!(3., round(2.5));
+    assert_eq!(-3., round(-2.5));
+    assert_eq!(2., round(2.4));
+    assert_eq!(2., round_ties_even(2.5));
+    assert_eq!(4., round_ties_even(3.5));
+    assert_eq!(-2., round_ties_even(-2.5));
+    assert_eq!(1e300, round(1e300));
+  }
+
+  #[test]
+  fn test_powi() {
+    assert_eq!(1000., powi(10., 3));
+    assert_eq!(0.01, powi(10., -2));
+    assert_eq!(1., powi(10., 0));
+  }
+
+  #[test]
+  fn test_powf() {
+    assert!((powf(2., 0.5) - 2_f64.sqrt()).abs() <= 1e-15);
+    assert!((powf(10., 1.5) - 10_f64.powf(1.5)).abs() <= 1e-13);
+    assert!((powf(-2., 3.) + 8.).abs() <= 1e-14);
+    assert!((powf(-2., -2.) - 0.25).abs() <= 1e-15);
+    assert!(powf(-2., 0.5).is_nan());
+    assert_eq!(0., powf(0., 2.));
+    assert_eq!(f64::INFINITY, powf(0., -1.));
+    assert_eq!(1., powf(f64::NAN, 0.));
+  }
+
+  #[test]
+  fn test_sqrt() {
+    assert_eq!(3., sqrt(9.));
+
+    for x in [2., 0.5, 1e-300, 5e-324, 1e300] {
+      assert!((sqrt(x) - x.sqrt()).abs() <= f64::EPSILON * x.sqrt());
+    }
+
+    assert!(sqrt(-1.).is_nan());
+  }
+
+  #[test]
+  fn test_ln_exp() {
+    for x in [1e-300, 0.075, 0.5, 1., 2., 4.75, 1e10, 1e300] {
+      assert!((ln(x) - x.ln()).abs() <= 1e-15 * x.ln().abs().max(1.));
+    }
+
+    for x in [-700., -1., -0.5, 0., 0.5, 1., 2.5, 700.] {
+      assert!((exp(x) - x.exp()).abs() <= 1e-14 * x.exp());
+    }
+
+    assert_eq!(f64::NEG_INFINITY, ln(0.));
+    assert_eq!(f64::INFINITY, exp(1000.));
+  }
+  #[test]
+  fn test_sin_cos_tan() {
+    for x in [-10., -3., -1., -0.5, 0., 0.1, 0.5235987755982988, 1., 1.5, 2., 3., 4.5, 6., 100.] {
+      assert!((sin(x) - x.sin()).abs() <= 1e-15);
+      assert!((cos(x) - x.cos()).abs() <= 1e-15);
+      assert!((tan(x) - x.tan()).abs() <= 1e-14 * x.tan().abs().max(1.));
+    }
+
+    assert!(sin(f64::INFINITY).is_nan());
+    assert!(cos(f64::NAN).is_nan());
+  }
+}
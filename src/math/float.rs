@@ -0,0 +1,327 @@
+//! Float
+//!
+//! A small floating point abstraction so calculations can be performed with either `f32` or `f64`.
+//!
+//! # Example
+//!
+//! ```
+//! use billios::field_test::SandUsed;
+//!
+//! let sand_used: SandUsed<f32> = SandUsed::new(14.65, 8.75, None);
+//! assert_eq!(Ok(2.31_f32), sand_used.calculate());
+//! ```
+use crate::domain::errors::CalcError;
+use alloc::format;
+use alloc::string::String;
+use core::cmp::Ordering;
+use core::fmt;
+use core::iter::Sum;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+#[cfg(not(feature = "std"))]
+use super::fallback;
+
+/// A floating point type that calculations can be performed with.
+///
+/// Implemented for `f32` and `f64`.
+pub trait Float:
+  Copy
+  + Default
+  + PartialEq
+  + PartialOrd
+  + fmt::Debug
+  + fmt::Display
+  + Add<Output = Self>
+  + Sub<Output = Self>
+  + Mul<Output = Self>
+  + Div<Output = Self>
+  + Neg<Output = Self>
+  + Sum
+  + 'static
+{
+  /// The value `0`.
+  const ZERO: Self;
+  /// The value `1`.
+  const ONE: Self;
+  /// The machine epsilon of the type, used as the threshold for division by zero.
+  const EPSILON: Self;
+
+  /// Convert from an `f64`, losing precision if necessary.
+  fn from_f64(value: f64) -> Self;
+
+  /// Convert to an `f64`.
+  fn to_f64(self) -> f64;
+
+  /// Returns `true` if the value is `NaN`.
+  fn is_nan(self) -> bool;
+
+  /// Returns `true` if the value is positive or negative infinity.
+  fn is_infinite(self) -> bool;
+
+  /// Returns `true` if the value is neither infinite nor `NaN`.
+  fn is_finite(self) -> bool;
+
+  /// The absolute value.
+  fn abs(self) -> Self;
+
+  /// Round to the nearest integer, with halves rounded away from zero.
+  fn round(self) -> Self;
+
+  /// Round to the nearest integer, with halves rounded to the nearest even integer.
+  fn round_ties_even(self) -> Self;
+
+  /// The smallest integer greater than or equal to the value.
+  fn ceil(self) -> Self;
+
+  /// The largest integer less than or equal to the value.
+  fn floor(self) -> Self;
+
+  /// Raise the value to an integer power.
+  fn powi(self, n: i32) -> Self;
+
+  /// Raise the value to a floating point power.
+  ///
+  /// A negative value raised to a non-integer power is `NaN`.
+  fn powf(self, n: Self) -> Self;
+
+  /// The natural logarithm.
+  fn ln(self) -> Self;
+
+  /// `e` raised to the power of the value.
+  fn exp(self) -> Self;
+
+  /// The square root.
+  fn sqrt(self) -> Self;
+
+  /// The sine, of a value in radians.
+  fn sin(self) -> Self;
+
+  /// The cosine, of a value in radians.
+  fn cos(self) -> Self;
+
+  /// The tangent, of a value in radians.
+  fn tan(self) -> Self;
+
+  /// A total ordering, used for sorting.
+  fn total_cmp(&self, other: &Self) -> Ordering;
+}
+
+/// Calls the inherent method with `std`, or the `fallback` implementation without it.
+macro_rules! std_or_fallback {
+  ($t:ty, $method:ident($value:expr $(, $arg:expr)*)) => {{
+    #[cfg(feature = "std")]
+    let result = <$t>::$method($value $(, $arg)*);
+
+    #[cfg(not(feature = "std"))]
+    let result = fallback::$method($value as f64 $(, $arg)*) as $t;
+
+    result
+  }};
+}
+
+macro_rules! impl_float {
+  ($t:ty) => {
+    impl Float for $t {
+      const ZERO: Self = 0.;
+      const ONE: Self = 1.;
+      const EPSILON: Self = <$t>::EPSILON;
+
+      fn from_f64(value: f64) -> Self {
+        value as $t
+      }
+
+      fn to_f64(self) -> f64 {
+        self as f64
+      }
+
+      fn is_nan(self) -> bool {
+        <$t>::is_nan(self)
+      }
+
+      fn is_infinite(self) -> bool {
+        <$t>::is_infinite(self)
+      }
+
+      fn is_finite(self) -> bool {
+        <$t>::is_finite(self)
+      }
+
+      fn abs(self) -> Self {
+        <$t>::abs(self)
+      }
+
+      fn round(self) -> Self {
+        std_or_fallback!($t, round(self))
+      }
+
+      fn round_ties_even(self) -> Self {
+        std_or_fallback!($t, round_ties_even(self))
+      }
+
+      fn ceil(self) -> Self {
+        std_or_fallback!($t, ceil(self))
+      }
+
+      fn floor(self) -> Self {
+        std_or_fallback!($t, floor(self))
+      }
+
+      fn powi(self, n: i32) -> Self {
+        std_or_fallback!($t, powi(self, n))
+      }
+
+      fn powf(self, n: Self) -> Self {
+        #[cfg(feature = "std")]
+        let result = <$t>::powf(self, n);
+
+        #[cfg(not(feature = "std"))]
+        let result = fallback::powf(self as f64, n as f64) as $t;
+
+        result
+      }
+
+      fn ln(self) -> Self {
+        std_or_fallback!($t, ln(self))
+      }
+
+      fn exp(self) -> Self {
+        std_or_fallback!($t, exp(self))
+      }
+
+      fn sqrt(self) -> Self {
+        std_or_fallback!($t, sqrt(self))
+      }
+
+      fn sin(self) -> Self {
+        std_or_fallback!($t, sin(self))
+      }
+
+      fn cos(self) -> Self {
+        std_or_fallback!($t, cos(self))
+      }
+
+      fn tan(self) -> Self {
+        std_or_fallback!($t, tan(self))
+      }
+
+      fn total_cmp(&self, other: &Self) -> Ordering {
+        <$t>::total_cmp(self, other)
+      }
+    }
+  };
+}
+
+impl_float!(f32);
+impl_float!(f64);
+
+/// Returns `true` if `a` and `b` differ by no more than `epsilon`.
+///
+/// `NaN` is never approximately equal to anything, and infinities are only equal to themselves.
+///
+/// # Example
+///
+/// ```
+/// use billios::math::float::approx_eq;
+///
+/// assert!(approx_eq(0.1 + 0.2, 0.3, 1e-9));
+/// assert!(!approx_eq(114.3, 114.4, 0.01));
+/// ```
+pub fn approx_eq<T: Float>(a: T, b: T, epsilon: T) -> bool {
+  if a.is_infinite() || b.is_infinite() {
+    return a == b;
+  }
+
+  (a - b).abs() <= epsilon
+}
+
+/// Format `value` with exactly `decimals` decimal places, keeping trailing zeros, e.g. `114.30` for reports that show the precision used.
+///
+/// The `value` is expected to be rounded already, e.g. by `calculate()`, as formatting to fewer decimal places rounds halfway values to even rather than with `RoundingMode::HalfAwayFromZero`. Negative zero is shown as zero, as a result that rounds to zero has no sign on a report.
+///
+/// # Example
+///
+/// ```
+/// use billios::math::float::format_result;
+///
+/// assert_eq!("114.30", format_result(114.3, 2));
+/// assert_eq!("2.3100", format_result(2.31, 4));
+/// assert_eq!("114", format_result(114.3, 0));
+/// assert_eq!("0.0", format_result(-0.04, 1));
+/// ```
+pub fn format_result(value: f64, decimals: u32) -> String {
+  let formatted = format!("{:.*}", decimals as usize, value);
+
+  match formatted.strip_prefix('-') {
+    Some(unsigned) if unsigned.bytes().all(|b| b == b'0' || b == b'.') => String::from(unsigned),
+    _ => formatted,
+  }
+}
+
+/// Divide `numerator` by `denominator`, the one place the calculations guard a division.
+///
+/// Returns `CalcError::DivisionByZero` when the absolute value of `denominator` is less than `T::EPSILON`, and `CalcError::NonFinite` if the quotient is `NaN` or infinite, e.g. from a `NaN` input, or the denominator is infinite, which would hide the infinite input behind a quotient of zero.
+pub(crate) fn checked_div<T: Float>(numerator: T, denominator: T) -> Result<T, CalcError> {
+  if denominator.abs() < T::EPSILON {
+    return Err(CalcError::DivisionByZero);
+  }
+
+  let result = numerator / denominator;
+
+  if !result.is_finite() || denominator.is_infinite() {
+    return Err(CalcError::NonFinite);
+  }
+
+  Ok(result)
+}
+
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn halve<T: Float>(value: T) -> T {
+    value / T::from_f64(2.)
+  }
+
+  #[test]
+  fn test_float() {
+    assert_eq!(1.25_f32, halve(2.5_f32));
+    assert_eq!(1.25_f64, halve(2.5_f64));
+    assert_eq!(f32::EPSILON, <f32 as Float>::EPSILON);
+    assert_eq!(2.5, Float::to_f64(2.5_f32));
+  }
+
+  #[test]
+  fn test_approx_eq() {
+    assert!(approx_eq(2.31, 2.3100001, 1e-6));
+    assert!(approx_eq(2.31_f32, 2.3100001, 1e-6));
+    assert!(!approx_eq(2.31, 2.32, 1e-6));
+    assert!(!approx_eq(f64::NAN, f64::NAN, 1.));
+    assert!(approx_eq(f64::INFINITY, f64::INFINITY, 0.));
+    assert!(!approx_eq(f64::INFINITY, f64::MAX, f64::MAX));
+  }
+
+  #[test]
+  fn test_format_result() {
+    assert_eq!("114.30", format_result(114.3, 2));
+    assert_eq!("0.20000000", format_result(0.2, 8));
+    assert_eq!("-3.0", format_result(-3., 1));
+    assert_eq!("0.00", format_result(-0., 2));
+    assert_eq!("115", format_result(114.6, 0));
+    assert_eq!("NaN", format_result(f64::NAN, 2));
+  }
+
+  #[test]
+  fn test_checked_div() {
+    assert_eq!(Ok(0.2), checked_div(100., 500.));
+    assert_eq!(Ok(0.25_f32), checked_div(1., 4.));
+    assert_eq!(Err(CalcError::DivisionByZero), checked_div(100., 0.));
+    assert_eq!(Err(CalcError::DivisionByZero), checked_div(100., -f64::EPSILON / 2.));
+    assert_eq!(Err(CalcError::DivisionByZero), checked_div(0., 0.));
+    assert_eq!(Err(CalcError::NonFinite), checked_div(f64::NAN, 500.));
+    assert_eq!(Err(CalcError::NonFinite), checked_div(100., f64::NAN));
+    assert_eq!(Err(CalcError::NonFinite), checked_div(f64::MAX, 0.5));
+    assert_eq!(Err(CalcError::NonFinite), checked_div(100., f64::INFINITY));
+  }
+}
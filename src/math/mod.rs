@@ -1 +1,5 @@
+#[cfg(not(feature = "std"))]
+mod fallback;
+pub mod float;
+pub mod stats;
 pub mod utilities;
\ No newline at end of file
@@ -0,0 +1,241 @@
+//! Stats
+//!
+//! Summaries over a set of calculated results, such as the `Compaction` of every test in a lot.
+use alloc::vec::Vec;
+use super::float::Float;
+
+/// Group values into histogram bins of `bin_width`, returning the lower bound of each bin with the number of values in it.
+///
+/// Bins are half-open intervals, `[lower, lower + bin_width)`, aligned to multiples of `bin_width`, so the same value always falls in the same bin. A value exactly on a boundary belongs to the bin above it.
+///
+/// Only bins containing a value are returned, sorted by their lower bound. Values that are `NaN` or infinite are skipped, and an empty `Vec` is returned if `bin_width` is not positive and finite.
+///
+/// # Example
+///
+/// ```
+/// use billios::math::stats::bin_results;
+///
+/// let compaction = [94.2, 95., 96.8, 97.1, 99.9, 101.5];
+///
+/// assert_eq!(vec![(94., 2), (96., 2), (98., 1), (100., 1)], bin_results(&compaction, 2.));
+/// ```
+pub fn bin_results(values: &[f64], bin_width: f64) -> Vec<(f64, usize)> {
+  if !bin_width.is_finite() || bin_width <= 0. {
+    return Vec::new();
+  }
+
+  let mut indices: Vec<f64> = values
+    .iter()
+    .filter(|v| v.is_finite())
+    .map(|&v| bin_index(v, bin_width))
+    .collect();
+
+  indices.sort_unstable_by(f64::total_cmp);
+
+  let (steps, scale) = decimal_steps(bin_width);
+  let mut bins: Vec<(f64, usize)> = Vec::new();
+
+  for index in indices {
+    let lower = index * steps / scale;
+
+    match bins.last_mut() {
+      Some((last, count)) if *last == lower => *count += 1,
+      _ => bins.push((lower, 1)),
+    }
+  }
+
+  bins
+}
+
+/// The bin of `value`, `floor(value / bin_width)`, with a quotient within rounding error of a whole number snapped to it, so a value on a boundary that is not exact in binary, e.g. `0.7` with a width of `0.1`, is not put in the bin below.
+fn bin_index(value: f64, bin_width: f64) -> f64 {
+  let quotient = value / bin_width;
+  let nearest = Float::round(quotient);
+
+  if Float::abs(quotient - nearest) <= 1e-9 * Float::abs(nearest).max(1.) {
+    return nearest;
+  }
+
+  Float::floor(quotient)
+}
+
+/// `bin_width` as a whole number of `steps` over a power of ten `scale`, e.g. `(1., 10.)` for `0.1`, so a bound `index * steps / scale` is the closest float to the decimal, and has no noise such as `0.30000000000000004`.
+///
+/// A width with more than 15 decimal places, e.g. `1 / 3`, is returned as itself over `1.`.
+fn decimal_steps(bin_width: f64) -> (f64, f64) {
+  let mut scale = 1.;
+
+  for _ in 0..=15 {
+    let steps = bin_width * scale;
+
+    if Float::abs(steps - Float::round(steps)) <= 1e-9 * steps {
+      return (Float::round(steps), scale);
+    }
+
+    scale *= 10.;
+  }
+
+  (bin_width, 1.)
+}
+
+/// The mean of the values, or `None` if there are none.
+pub fn mean(values: &[f64]) -> Option<f64> {
+  if values.is_empty() {
+    return None;
+  }
+
+  Some(values.iter().sum::<f64>() / values.len() as f64)
+}
+
+/// The sample standard deviation of the values, or `None` if there are fewer than two.
+///
+/// Uses Welford's algorithm, which avoids the cancellation of summing squares for large, closely grouped values such as densities.
+///
+/// # Example
+///
+/// ```
+/// use billios::math::stats::{mean, std_dev};
+///
+/// let compaction = [96., 98.5, 95., 99.5];
+///
+/// assert_eq!(Some(97.25), mean(&compaction));
+/// assert!((std_dev(&compaction).unwrap() - 2.1016).abs() < 1e-4);
+/// ```
+pub fn std_dev(values: &[f64]) -> Option<f64> {
+  if values.len() < 2 {
+    return None;
+  }
+
+  let mut mean = 0.;
+  let mut sum_of_squares = 0.;
+
+  for (i, &value) in values.iter().enumerate() {
+    let delta = value - mean;
+    mean += delta / (i + 1) as f64;
+    sum_of_squares += delta * (value - mean);
+  }
+
+  Some(Float::sqrt(sum_of_squares / (values.len() - 1) as f64))
+}
+
+/// The smallest of the values, or `None` if there are none.
+///
+/// A `NaN` is ignored, unless every value is `NaN`.
+pub fn min(values: &[f64]) -> Option<f64> {
+  values.iter().copied().reduce(f64::min)
+}
+
+/// The largest of the values, or `None` if there are none.
+///
+/// A `NaN` is ignored, unless every value is `NaN`.
+pub fn max(values: &[f64]) -> Option<f64> {
+  values.iter().copied().reduce(f64::max)
+}
+
+/// The `p`th percentile of the values, from `0` to `100`, or `None` if there are none or `p` is outside that range.
+///
+/// Interpolates linearly between the closest ranks, so the 50th percentile is the median, `0` is the `min()` and `100` the `max()`.
+///
+/// # Example
+///
+/// ```
+/// use billios::math::stats::percentile;
+///
+/// let compaction = [96., 98.5, 95., 99.5];
+///
+/// assert_eq!(Some(97.25), percentile(&compaction, 50.));
+/// assert_eq!(Some(99.5), percentile(&compaction, 100.));
+/// ```
+pub fn percentile(values: &[f64], p: f64) -> Option<f64> {
+  if values.is_empty() || !(0. ..=100.).contains(&p) {
+    return None;
+  }
+
+  let mut sorted = values.to_vec();
+  sorted.sort_unstable_by(f64::total_cmp);
+
+  let rank = p / 100. * (sorted.len() - 1) as f64;
+  let lower = Float::floor(rank);
+  let index = lower as usize;
+
+  match sorted.get(index + 1) {
+    Some(&above) => Some(sorted[index] + (above - sorted[index]) * (rank - lower)),
+    None => Some(sorted[index]),
+  }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_bin_results() {
+    assert_eq!(vec![(90., 1), (95., 3), (100., 1)], bin_results(&[99.9, 95., 92.5, 97.4, 100.], 5.));
+
+    // Half-open, so a boundary value is in the bin above.
+    assert_eq!(vec![(0., 1), (1., 2)], bin_results(&[0.999, 1., 1.5], 1.));
+
+    assert_eq!(vec![(-10., 1), (0., 1)], bin_results(&[-0.5, 0.5], 10.));
+
+    // Boundaries that are not exact in binary are in their own bin, with clean bounds.
+    assert_eq!(vec![(0.3, 1), (0.7, 1), (95.1, 1)], bin_results(&[95.1, 0.3, 0.7], 0.1));
+    assert_eq!(vec![(0.2, 2), (0.3, 2), (0.6, 1)], bin_results(&[0.2, 0.29999, 0.3, 0.39999, 0.6], 0.1));
+    assert_eq!(vec![(-0.3, 1), (0.1, 1)], bin_results(&[-0.3, 0.1], 0.1));
+    assert_eq!(vec![(0.25, 1), (0.5, 1)], bin_results(&[0.25, 0.74], 0.25));
+    assert_eq!(vec![(100., 1)], bin_results(&[f64::NAN, 104.2, f64::INFINITY], 5.));
+  }
+
+  #[test]
+  fn test_bin_results_empty() {
+    assert!(bin_results(&[], 5.).is_empty());
+    assert!(bin_results(&[95., 100.], 0.).is_empty());
+    assert!(bin_results(&[95., 100.], -5.).is_empty());
+    assert!(bin_results(&[95., 100.], f64::NAN).is_empty());
+  }
+
+  #[test]
+  fn test_mean() {
+    assert_eq!(Some(97.25), mean(&[96., 98.5, 95., 99.5]));
+    assert_eq!(Some(114.3), mean(&[114.3]));
+    assert_eq!(None, mean(&[]));
+  }
+
+  #[test]
+  fn test_std_dev() {
+    assert_eq!(Some(2.), std_dev(&[2., 4., 4., 4., 5., 5., 7., 9.]).map(|v| v * (7_f64 / 8.).sqrt()));
+    assert_eq!(Some(0.), std_dev(&[114.3, 114.3]));
+    assert_eq!(None, std_dev(&[114.3]));
+
+    // Closely grouped large values, where summing squares loses precision.
+    let offset = [1e9 + 4., 1e9 + 7., 1e9 + 13., 1e9 + 16.];
+    assert_eq!(Some(30_f64.sqrt()), std_dev(&offset));
+  }
+
+  #[test]
+  fn test_min_max() {
+    let values = [96.2, 98.4, f64::NAN, 95.1, 99.3];
+
+    assert_eq!(Some(95.1), min(&values));
+    assert_eq!(Some(99.3), max(&values));
+    assert!(min(&[f64::NAN]).unwrap().is_nan());
+    assert_eq!(None, min(&[]));
+    assert_eq!(None, max(&[]));
+  }
+
+  #[test]
+  fn test_percentile() {
+    let values = [99.5, 95., 98.5, 96.];
+
+    assert_eq!(Some(95.), percentile(&values, 0.));
+    assert_eq!(Some(99.5), percentile(&values, 100.));
+    assert_eq!(Some(97.25), percentile(&values, 50.));
+    assert!((percentile(&values, 10.).unwrap() - 95.3).abs() < 1e-9);
+    assert_eq!(Some(114.3), percentile(&[114.3], 90.));
+
+    assert_eq!(None, percentile(&[], 50.));
+    assert_eq!(None, percentile(&values, 101.));
+    assert_eq!(None, percentile(&values, f64::NAN));
+  }
+}
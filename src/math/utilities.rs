@@ -1,4 +1,5 @@
 //! Utilities
+use super::float::Float;
 
 /// Collection of functions related to getting a power of a number.
 pub struct GetNumPower;
@@ -6,19 +7,120 @@ pub struct GetNumPower;
 impl GetNumPower {
   /// Simple function for getting the power of a number given a base.
   ///
-  /// Base defaults to base 10.
-  pub fn power_n(power: u32, base: Option<u32>) -> u32 {
-    let base = match base { Some(v) => v, None => 10 };
+  /// Base defaults to base 10. Returns `None` if the result overflows a `u32`, use `power_u64()` for larger results.
+  pub fn power_n(power: u32, base: Option<u32>) -> Option<u32> {
+    let base = base.unwrap_or(10);
 
-    base.pow(power)
+    base.checked_pow(power)
   }
 
   /// Get the power of a number base 10.
-  pub fn power_10(power: u32) -> u32 {
-    let base: u32 = 10;
+  ///
+  /// Returns `None` if the result overflows a `u32`, from a `power` of `10` up.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use billios::math::utilities::GetNumPower;
+  ///
+  /// assert_eq!(Some(1_000_000_000), GetNumPower::power_10(9));
+  /// assert_eq!(None, GetNumPower::power_10(10));
+  /// assert_eq!(Some(10_000_000_000), GetNumPower::power_u64(10, None));
+  /// ```
+  pub fn power_10(power: u32) -> Option<u32> {
+    Self::power_n(power, None)
+  }
+
+  /// Get the power of a number given a base, the same as `power_n()` with a `u64`.
+  ///
+  /// Base defaults to base 10. Returns `None` if the result overflows a `u64`.
+  pub fn power_u64(power: u32, base: Option<u64>) -> Option<u64> {
+    let base = base.unwrap_or(10);
+
+    base.checked_pow(power)
+  }
+
+  /// Get a base raised to a fractional (floating point) power.
+  ///
+  /// A negative base is only defined for an integer power, otherwise the result is `NaN`.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use billios::math::utilities::GetNumPower;
+  ///
+  /// assert_eq!(3., GetNumPower::power_f64(9., 0.5));
+  /// assert_eq!(-8., GetNumPower::power_f64(-2., 3.));
+  /// assert!(GetNumPower::power_f64(-8., 1. / 3.).is_nan());
+  /// ```
+  pub fn power_f64(base: f64, exp: f64) -> f64 {
+    Float::powf(base, exp)
+  }
+}
+
+/// Get the diameter of a sphere settling through water at `velocity`, from Stokes' law, `D = sqrt(30 * η * v / (980 * (Gs - Gw)))`.
+///
+/// The units are those of a hydrometer analysis: the `viscosity`, `η`, of water in poise (g/(cm·s)), the `velocity`, `v`, in centimetres per minute, and the diameter, `D`, in millimetres. The specific gravities of the solids, `gs`, and of water, `gw`, are dimensionless.
+///
+/// The result is infinite if `gs` equals `gw`, and `NaN` if the solids are lighter than water.
+///
+/// # Example
+///
+/// ```
+/// use billios::domain::units::{water_density, water_viscosity};
+/// use billios::math::utilities::{stokes_diameter, stokes_velocity};
+///
+/// // 10.1 cm of fall in 2 minutes, at 20°C.
+/// let diameter = stokes_diameter(water_viscosity(20.), 2.65, water_density(20.), 10.1 / 2.);
+///
+/// assert!((diameter - 0.0306).abs() < 5e-5);
+/// assert!((stokes_velocity(water_viscosity(20.), 2.65, water_density(20.), diameter) - 5.05).abs() < 1e-9);
+/// ```
+pub fn stokes_diameter(viscosity: f64, gs: f64, gw: f64, velocity: f64) -> f64 {
+  GetNumPower::power_f64(30. * viscosity * velocity / (980. * (gs - gw)), 0.5)
+}
+
+/// Get the velocity at which a sphere of `diameter` settles through water, from Stokes' law, `v = 980 * (Gs - Gw) * D² / (30 * η)`.
+///
+/// The inverse of `stokes_diameter()`, in the same units, with the velocity in centimetres per minute.
+pub fn stokes_velocity(viscosity: f64, gs: f64, gw: f64, diameter: f64) -> f64 {
+  980. * (gs - gw) * GetNumPower::power_f64(diameter, 2.) / (30. * viscosity)
+}
+
+/// Read the size at `x` percent passing from a gradation curve of `(size, percent passing)` `points`.
+///
+/// Interpolated linearly between the two points either side of `x`, against the logarithm of the size, as on a gradation curve plotted on a log scale. The points can be in either order, e.g. from the largest to the smallest sieve, and where two points have the same percent passing the size of the second is used. This is how `GradationAnalysis` finds `D10`, `D30`, and `D60`.
+///
+/// Returns `None` if fewer than two points are given, or `x` is outside the percents passing, and a `NaN` result if a size is not positive.
+///
+/// # Example
+///
+/// ```
+/// use billios::math::utilities::log_interp;
+///
+/// let curve = [(4.75, 100.), (2., 80.), (0.425, 40.), (0.075, 5.)];
+///
+/// // Half way from 40% to 80% is half way between the sizes on a log scale, not 1.2125 mm.
+/// let d60 = log_interp(&curve, 60.).unwrap();
+/// assert!((d60 - 0.85_f64.sqrt()).abs() < 1e-12);
+///
+/// assert_eq!(None, log_interp(&curve, 2.));
+/// ```
+pub fn log_interp(points: &[(f64, f64)], x: f64) -> Option<f64> {
+  let (first, second) = points
+    .windows(2)
+    .map(|w| (w[0], w[1]))
+    .find(|&(a, b)| x >= a.1.min(b.1) && x <= a.1.max(b.1))?;
+
+  let range = first.1 - second.1;
 
-    base.pow(power)
+  if range.abs() < f64::EPSILON {
+    return Some(second.0);
   }
+
+  let fraction = (x - second.1) / range;
+
+  Some(Float::exp(Float::ln(second.0) + (Float::ln(first.0) - Float::ln(second.0)) * fraction))
 }
 
 
@@ -29,13 +131,81 @@ mod tests {
 
   #[test]
   fn test_get_power() {
-    assert_eq!(100, GetNumPower::power_n(2, Some(10)));
-    assert_eq!(100, GetNumPower::power_n(2, None));
+    assert_eq!(Some(100), GetNumPower::power_n(2, Some(10)));
+    assert_eq!(Some(100), GetNumPower::power_n(2, None));
+    assert_eq!(Some(2_147_483_648), GetNumPower::power_n(31, Some(2)));
+    assert_eq!(None, GetNumPower::power_n(32, Some(2)));
   }
 
   #[test]
   fn test_get_power_10() {
-    assert_eq!(100, GetNumPower::power_10(2));
-    assert_eq!(1000, GetNumPower::power_10(3));
+    assert_eq!(Some(100), GetNumPower::power_10(2));
+    assert_eq!(Some(1000), GetNumPower::power_10(3));
+    assert_eq!(Some(1), GetNumPower::power_10(0));
+    assert_eq!(Some(1_000_000_000), GetNumPower::power_10(9));
+    assert_eq!(None, GetNumPower::power_10(10));
+  }
+
+  #[test]
+  fn test_get_power_u64() {
+    assert_eq!(Some(10_000_000_000), GetNumPower::power_u64(10, None));
+    assert_eq!(Some(10_000_000_000_000_000_000), GetNumPower::power_u64(19, None));
+    assert_eq!(None, GetNumPower::power_u64(20, None));
+    assert_eq!(Some(1 << 63), GetNumPower::power_u64(63, Some(2)));
+    assert_eq!(None, GetNumPower::power_u64(64, Some(2)));
+  }
+
+  #[test]
+  fn test_get_power_f64() {
+    assert_eq!(100., GetNumPower::power_f64(10., 2.));
+    assert_eq!(0.5, GetNumPower::power_f64(4., -0.5));
+    assert!((GetNumPower::power_f64(10., 1.5) - 31.6227766).abs() < 1e-7);
+    assert_eq!(0.25, GetNumPower::power_f64(-2., -2.));
+    assert!(GetNumPower::power_f64(-2., 0.5).is_nan());
+  }
+
+  #[test]
+  fn test_log_interp() {
+    // Worked by hand, from the largest to the smallest sieve.
+    let curve = [(4.75, 100.), (2., 80.), (0.425, 40.), (0.075, 5.)];
+
+    // D10 is 5/35 of the way from 0.075 to 0.425 mm on a log scale, exp(ln 0.075 + (ln 0.425 - ln 0.075) / 7).
+    assert!((log_interp(&curve, 10.).unwrap() - 0.0960903).abs() < 1e-7);
+    // D30 is 25/35 of the way, and D60 the geometric mean of 0.425 and 2 mm.
+    assert!((log_interp(&curve, 30.).unwrap() - 0.2589122).abs() < 1e-7);
+    assert!((log_interp(&curve, 60.).unwrap() - 0.9219544).abs() < 1e-7);
+
+    // The ends of the curve are inclusive.
+    assert!((log_interp(&curve, 100.).unwrap() - 4.75).abs() < 1e-12);
+    assert!((log_interp(&curve, 5.).unwrap() - 0.075).abs() < 1e-12);
+    assert_eq!(None, log_interp(&curve, 100.1));
+    assert_eq!(None, log_interp(&curve, 4.9));
+    assert_eq!(None, log_interp(&curve, f64::NAN));
+
+    // The same curve from the smallest sieve.
+    let mut reversed = curve;
+    reversed.reverse();
+    assert!((log_interp(&reversed, 60.).unwrap() - 0.9219544).abs() < 1e-7);
+
+    // A sieve that retained nothing, the size of the second is read.
+    assert_eq!(Some(2.), log_interp(&[(4.75, 100.), (2., 100.), (0.075, 5.)], 100.));
+
+    assert_eq!(None, log_interp(&[(2., 80.)], 80.));
+    assert_eq!(None, log_interp(&[], 80.));
+    assert!(log_interp(&[(2., 80.), (0., 0.)], 40.).unwrap().is_nan());
   }
-}
\ No newline at end of file
+
+  #[test]
+  fn test_stokes() {
+    // A 0.075 mm (#200) particle of quartz in water at 20°C, 1.002 cP.
+    let velocity = stokes_velocity(0.01002, 2.65, 0.99821, 0.075);
+    assert!((velocity - 30.2911).abs() < 1e-4);
+    assert!((stokes_diameter(0.01002, 2.65, 0.99821, velocity) - 0.075).abs() < 1e-12);
+
+    // Four times the diameter settles sixteen times as fast.
+    assert!((stokes_velocity(0.01002, 2.65, 0.99821, 0.3) / velocity - 16.).abs() < 1e-9);
+
+    assert!(stokes_diameter(0.01002, 1., 1., 5.).is_infinite());
+    assert!(stokes_diameter(0.01002, 0.9, 1., 5.).is_nan());
+  }
+}
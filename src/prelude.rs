@@ -0,0 +1,19 @@
+//! Prelude
+//!
+//! Everything in `field_test::prelude`, along with the `Float` trait and common `domain::types`, for a single glob import.
+//!
+//! # Example
+//!
+//! ```
+//! use billios::prelude::*;
+//!
+//! fn compaction<T: Float>(dry_density: T, lab_max: T) -> Result<T, CalcError> {
+//!   Compaction::new(DryDensityChoice::Value(dry_density), lab_max).calculate()
+//! }
+//!
+//! assert_eq!(Ok(114.3), compaction(155., 135.6));
+//! assert_eq!(Ok(Percentage::from_percent(114.3)), Compaction::new(DryDensityChoice::Value(155.), 135.6).percentage());
+//! ```
+pub use crate::domain::types::{CalcResult, Percentage, RoundingMode};
+pub use crate::field_test::prelude::*;
+pub use crate::math::float::Float;